@@ -0,0 +1,341 @@
+//! VaultDAO - Event Decoding
+//!
+//! Turns events published by the vault back into the typed payloads defined
+//! in `events`, for indexers and tests. Decoding runs off-chain and needs a
+//! host-backed `Env`, so this module is only built with the `decode` feature
+//! (which enables `soroban-sdk/testutils`) or in tests.
+
+extern crate std;
+
+use crate::events::{VaultEvent, EVENT_SCHEMA_VERSION};
+use soroban_sdk::xdr::{ContractEvent, ContractEventBody, Limits, ReadXdr, ScVal};
+use soroban_sdk::{Env, Symbol, TryFromVal, Val, Vec};
+use std::string::{String, ToString};
+
+/// Why a published event could not be decoded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Topics are not `(Symbol, u32)`.
+    MalformedTopics,
+    /// Published under a schema version this build does not understand.
+    UnsupportedVersion(u32),
+    /// No payload type is registered under this name.
+    UnknownEvent(String),
+    /// The data does not match the payload type registered under this name.
+    InvalidPayload(String),
+    /// The bytes are not a valid `ContractEvent`.
+    InvalidXdr,
+}
+
+macro_rules! vault_events {
+    ($($variant:ident($ty:ident)),* $(,)?) => {
+        pub use crate::events::{$($ty),*};
+
+        /// Every event the vault publishes, keyed by its payload type.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Event {
+            $($variant($ty)),*
+        }
+
+        impl Event {
+            /// Names of all known events, in declaration order.
+            pub const NAMES: &'static [&'static str] = &[$(<$ty as VaultEvent>::NAME),*];
+
+            /// Name the event is published under.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Event::$variant(_) => <$ty as VaultEvent>::NAME),*
+                }
+            }
+        }
+
+        fn decode_payload(env: &Env, name: &str, data: &ScVal) -> Result<Event, DecodeError> {
+            $(
+                if name == <$ty as VaultEvent>::NAME {
+                    return $ty::try_from_val(env, data)
+                        .map(Event::$variant)
+                        .map_err(|_| DecodeError::InvalidPayload(name.to_string()));
+                }
+            )*
+            Err(DecodeError::UnknownEvent(name.to_string()))
+        }
+    };
+}
+
+vault_events! {
+    Initialized(InitializedEvent),
+    ProposalCreated(ProposalCreatedEvent),
+    ProposalApproved(ProposalApprovedEvent),
+    ProposalAbstained(ProposalAbstainedEvent),
+    ProposalRejectVoted(ProposalRejectVotedEvent),
+    ProposalVoteRejected(ProposalVoteRejectedEvent),
+    RejectionThresholdSet(RejectionThresholdSetEvent),
+    VoteChanged(VoteChangedEvent),
+    ProposalReady(ProposalReadyEvent),
+    ProposalExecuted(ProposalExecutedEvent),
+    ProposalExpired(ProposalExpiredEvent),
+    ExecWindowLedgersUpdated(ExecWindowLedgersUpdatedEvent),
+    ExecutionWindowExpired(ExecutionWindowExpiredEvent),
+    ProposalDeadlineRejected(ProposalDeadlineRejectedEvent),
+    DelegatedVote(DelegatedVoteEvent),
+    ProposalScheduled(ProposalScheduledEvent),
+    ProposalRejected(ProposalRejectedEvent),
+    ProposalCancelled(ProposalCancelledEvent),
+    ScheduledProposalCancelled(ScheduledProposalCancelledEvent),
+    ProposalVetoed(ProposalVetoedEvent),
+    ProposalAmended(ProposalAmendedEvent),
+    RoleAssigned(RoleAssignedEvent),
+    AdminRotationProposed(AdminRotationProposedEvent),
+    AdminRotationCancelled(AdminRotationCancelledEvent),
+    AdminRotationAccepted(AdminRotationAcceptedEvent),
+    ConfigUpdated(ConfigUpdatedEvent),
+    StreamBurstFactorUpdated(StreamBurstFactorUpdatedEvent),
+    OracleConfigUpdated(OracleConfigUpdatedEvent),
+    OraclePriceStale(OraclePriceStaleEvent),
+    OracleGasPriceUsed(OracleGasPriceUsedEvent),
+    QuorumUpdated(QuorumUpdatedEvent),
+    QuorumReached(QuorumReachedEvent),
+    ThresholdReduced(ThresholdReducedEvent),
+    SignerAdded(SignerAddedEvent),
+    SignerRemoved(SignerRemovedEvent),
+    InsuranceLocked(InsuranceLockedEvent),
+    InsuranceSlashed(InsuranceSlashedEvent),
+    InsuranceReturned(InsuranceReturnedEvent),
+    StakeLocked(StakeLockedEvent),
+    StakeSlashed(StakeSlashedEvent),
+    StakeRefunded(StakeRefundedEvent),
+    AutoCompoundEnabled(AutoCompoundEnabledEvent),
+    StakeCompounded(StakeCompoundedEvent),
+    ReputationUpdated(ReputationUpdatedEvent),
+    BatchExecuted(BatchExecutedEvent),
+    BatchRolledBack(BatchRolledBackEvent),
+    NotificationPrefsUpdated(NotificationPrefsUpdatedEvent),
+    NotificationDispatch(NotificationDispatchEvent),
+    InsuranceConfigUpdated(InsuranceConfigUpdatedEvent),
+    CommentAdded(CommentAddedEvent),
+    CommentEdited(CommentEditedEvent),
+    HookRegistered(HookRegisteredEvent),
+    HookRemoved(HookRemovedEvent),
+    HookExecuted(HookExecutedEvent),
+    HookApprovalsRequired(HookApprovalsRequiredEvent),
+    HookBudgetSet(HookBudgetSetEvent),
+    ScreeningConfigSet(ScreeningConfigSetEvent),
+    ScreeningSkipped(ScreeningSkippedEvent),
+    AnomalyPolicySet(AnomalyPolicySetEvent),
+    ProposalAnomalyFlagged(ProposalAnomalyFlaggedEvent),
+    AnomalyAcknowledged(AnomalyAcknowledgedEvent),
+    ImpactModelSet(ImpactModelSetEvent),
+    ImpactBandApplied(ImpactBandAppliedEvent),
+    LiquidityRemoved(LiquidityRemovedEvent),
+    LpStaked(LpStakedEvent),
+    RewardsClaimed(RewardsClaimedEvent),
+    GasLimitExceeded(GasLimitExceededEvent),
+    GasConfigUpdated(GasConfigUpdatedEvent),
+    ExecutionFeeEstimated(ExecutionFeeEstimatedEvent),
+    ExecutionFeeUsed(ExecutionFeeUsedEvent),
+    MetricsUpdated(MetricsUpdatedEvent),
+    VotingDeadlineExtended(VotingDeadlineExtendedEvent),
+    TemplateCreated(TemplateCreatedEvent),
+    TemplateUpdated(TemplateUpdatedEvent),
+    TemplateVersionPruned(TemplateVersionPrunedEvent),
+    TemplateStatusChanged(TemplateStatusChangedEvent),
+    ProposalFromTemplate(ProposalFromTemplateEvent),
+    RetryScheduled(RetryScheduledEvent),
+    RecurringRetryScheduled(RecurringRetryScheduledEvent),
+    RetryAttempted(RetryAttemptedEvent),
+    RetriesExhausted(RetriesExhaustedEvent),
+    DeadLetterAdded(DeadLetterAddedEvent),
+    DeadLetterProcessed(DeadLetterProcessedEvent),
+    SubscriptionCreated(SubscriptionCreatedEvent),
+    SubscriptionRenewed(SubscriptionRenewedEvent),
+    SubscriptionCancelled(SubscriptionCancelledEvent),
+    SubscriptionUpgraded(SubscriptionUpgradedEvent),
+    SubscriptionExpired(SubscriptionExpiredEvent),
+    SubscriptionPaused(SubscriptionPausedEvent),
+    SubscriptionResumed(SubscriptionResumedEvent),
+    EscrowCreated(EscrowCreatedEvent),
+    MilestoneCompleted(MilestoneCompletedEvent),
+    EscrowReleased(EscrowReleasedEvent),
+    EscrowDisputed(EscrowDisputedEvent),
+    EscrowDisputeResolved(EscrowDisputeResolvedEvent),
+    EscrowAutoResolved(EscrowAutoResolvedEvent),
+    FundingRoundCreated(FundingRoundCreatedEvent),
+    FundingRoundApproved(FundingRoundApprovedEvent),
+    MilestoneSubmitted(MilestoneSubmittedEvent),
+    MilestoneVerified(MilestoneVerifiedEvent),
+    MilestoneRejected(MilestoneRejectedEvent),
+    FundingReleased(FundingReleasedEvent),
+    FundingRoundCancelled(FundingRoundCancelledEvent),
+    TokensLocked(TokensLockedEvent),
+    LockExtended(LockExtendedEvent),
+    TokensUnlocked(TokensUnlockedEvent),
+    EarlyUnlock(EarlyUnlockEvent),
+    RecoveryConfigUpdated(RecoveryConfigUpdatedEvent),
+    RecoveryProposed(RecoveryProposedEvent),
+    RecoveryApproved(RecoveryApprovedEvent),
+    RecoveryExecuted(RecoveryExecutedEvent),
+    RecoveryCancelled(RecoveryCancelledEvent),
+    FundingRoundCompleted(FundingRoundCompletedEvent),
+    FeeStructureUpdated(FeeStructureUpdatedEvent),
+    FeeCollected(FeeCollectedEvent),
+    DexConfigUpdated(DexConfigUpdatedEvent),
+    SwapExecuted(SwapExecutedEvent),
+    SwapBalances(SwapBalancesEvent),
+    LiquidityAdded(LiquidityAddedEvent),
+    LpUnstaked(LpUnstakedEvent),
+    StreamCreated(StreamCreatedEvent),
+    StreamRateAdjusted(StreamRateAdjustedEvent),
+    StreamStatusUpdated(StreamStatusUpdatedEvent),
+    StreamClaimed(StreamClaimedEvent),
+    StreamAutoCompleted(StreamAutoCompletedEvent),
+    CrossVaultProposed(CrossVaultProposedEvent),
+    CrossVaultExecuted(CrossVaultExecutedEvent),
+    CrossVaultConfigSet(CrossVaultConfigSetEvent),
+    PermissionGranted(PermissionGrantedEvent),
+    PermissionRevoked(PermissionRevokedEvent),
+    CustomRoleDefined(CustomRoleDefinedEvent),
+    CustomRoleRemoved(CustomRoleRemovedEvent),
+    CustomRoleAssignment(CustomRoleAssignmentEvent),
+    PermissionDelegated(PermissionDelegatedEvent),
+    DisputeRaised(DisputeRaisedEvent),
+    DisputeResolved(DisputeResolvedEvent),
+    DisputeBondPosted(DisputeBondPostedEvent),
+    DisputeOutcome(DisputeOutcomeEvent),
+    DisputeBondSlashed(DisputeBondSlashedEvent),
+    DisputeBondReturned(DisputeBondReturnedEvent),
+    BridgeProposed(BridgeProposedEvent),
+    BridgeExecuted(BridgeExecutedEvent),
+    BridgeToVaultInitiated(BridgeToVaultInitiatedEvent),
+    BridgeReceiptConfirmed(BridgeReceiptConfirmedEvent),
+    BridgeSlippageRejected(BridgeSlippageRejectedEvent),
+    BridgeFundsReturned(BridgeFundsReturnedEvent),
+    BridgeConfigUpdated(BridgeConfigUpdatedEvent),
+    ReputationConfigUpdated(ReputationConfigUpdatedEvent),
+    CommentDeleted(CommentDeletedEvent),
+    MetricsBucketUpdated(MetricsBucketUpdatedEvent),
+    VoteCommitted(VoteCommittedEvent),
+    VoteRevealed(VoteRevealedEvent),
+    PrivateTallyComputed(PrivateTallyComputedEvent),
+    VaultPaused(VaultPausedEvent),
+    ScopedDelegationCreated(ScopedDelegationCreatedEvent),
+    ScopedDelegationRevoked(ScopedDelegationRevokedEvent),
+    DelegateVoted(DelegateVotedEvent),
+    SnapshotTaken(SnapshotTakenEvent),
+    GovProposalCreated(GovProposalCreatedEvent),
+    GovProposalApproved(GovProposalApprovedEvent),
+    GovProposalExecuted(GovProposalExecutedEvent),
+    ParamChangeProposed(ParamChangeProposedEvent),
+    ParamChangeApproved(ParamChangeApprovedEvent),
+    ParamChangeExecuted(ParamChangeExecutedEvent),
+    FeeCacheInvalidated(FeeCacheInvalidatedEvent),
+    FanOutStreamCreated(FanOutStreamCreatedEvent),
+    FanOutPaymentDistributed(FanOutPaymentDistributedEvent),
+    StreamPaused(StreamPausedEvent),
+    StreamResumed(StreamResumedEvent),
+    EscrowReleaseLockedForVoting(EscrowReleaseLockedForVotingEvent),
+    EscrowReleaseVoted(EscrowReleaseVotedEvent),
+    RecurringPaymentJittered(RecurringPaymentJitteredEvent),
+    CacheInvalidated(CacheInvalidatedEvent),
+    KeeperHookRegistered(KeeperHookRegisteredEvent),
+    KeeperHookRemoved(KeeperHookRemovedEvent),
+    KeeperHookTriggered(KeeperHookTriggeredEvent),
+    KeeperHookFailed(KeeperHookFailedEvent),
+    KeeperBudgetSet(KeeperBudgetSetEvent),
+    KeeperWorkExecuted(KeeperWorkExecutedEvent),
+    KeeperBountyPaid(KeeperBountyPaidEvent),
+    ClaimVotingClosed(ClaimVotingClosedEvent),
+    ClaimQuorumFailed(ClaimQuorumFailedEvent),
+    AmendmentLimitWarning(AmendmentLimitWarningEvent),
+    BatchReordered(BatchReorderedEvent),
+    PauseCooldownActive(PauseCooldownActiveEvent),
+    VaultUnpaused(VaultUnpausedEvent),
+    VoteRejectedSignerRemoved(VoteRejectedSignerRemovedEvent),
+    SpendingLimitWarning(SpendingLimitWarningEvent),
+    ProposalAutoCancelledLimitExceeded(ProposalAutoCancelledLimitExceededEvent),
+    SpendingWindowModeSet(SpendingWindowModeSetEvent),
+    RecipientLimitConfigSet(RecipientLimitConfigSetEvent),
+    RecipientCapSet(RecipientCapSetEvent),
+    FirstPaymentCooloff(FirstPaymentCooloffEvent),
+    TagBudgetSet(TagBudgetSetEvent),
+    TagBudgetOverrun(TagBudgetOverrunEvent),
+    SolvencyPolicySet(SolvencyPolicySetEvent),
+    SolvencyOverride(SolvencyOverrideEvent),
+    OptimisticConfigSet(OptimisticConfigSetEvent),
+    OptimisticProposed(OptimisticProposedEvent),
+    ProposalObjected(ProposalObjectedEvent),
+    OptimisticEscalated(OptimisticEscalatedEvent),
+    TopicDelegated(TopicDelegatedEvent),
+    TopicDelegationRevoked(TopicDelegationRevokedEvent),
+    SignerElectionStarted(SignerElectionStartedEvent),
+    CandidateRegistered(CandidateRegisteredEvent),
+    ElectionBallotCast(ElectionBallotCastEvent),
+    SignerElectionTallied(SignerElectionTalliedEvent),
+    SignerElectionFailed(SignerElectionFailedEvent),
+    SignerElectionApplied(SignerElectionAppliedEvent),
+    SignerTermSet(SignerTermSetEvent),
+    InactivityPolicySet(InactivityPolicySetEvent),
+    SignerRemovalProposed(SignerRemovalProposedEvent),
+    InactiveSignerRemoved(InactiveSignerRemovedEvent),
+    GuardianCouncilSet(GuardianCouncilSetEvent),
+    GuardianActionProposed(GuardianActionProposedEvent),
+    GuardianActionActivated(GuardianActionActivatedEvent),
+    GuardianActionStatus(GuardianActionStatusEvent),
+    RecurringPaymentExecuted(RecurringPaymentExecutedEvent),
+    MergeInitiated(MergeInitiatedEvent),
+    MergeCompleted(MergeCompletedEvent),
+    MergeAborted(MergeAbortedEvent),
+    VestingCreated(VestingCreatedEvent),
+    VestingClaimed(VestingClaimedEvent),
+    VestingCancelled(VestingCancelledEvent),
+    HolidayCalendarSet(HolidayCalendarSetEvent),
+    SignerTierSet(SignerTierSetEvent),
+    FullQuorumThreshold(FullQuorumThresholdEvent),
+    ProposalStatus(ProposalStatusEvent),
+    DailySpentUpdated(DailySpentUpdatedEvent),
+    WeeklySpentUpdated(WeeklySpentUpdatedEvent),
+}
+
+/// Decode an event from its topics and data as host values, e.g. as
+/// returned by the test environment's event log.
+pub fn decode(env: &Env, topics: &Vec<Val>, data: &Val) -> Result<Event, DecodeError> {
+    let data = ScVal::try_from_val(env, data).map_err(|_| DecodeError::InvalidXdr)?;
+    decode_parts(env, topics, &data)
+}
+
+/// Decode an event as it appears in transaction meta.
+pub fn decode_contract_event(env: &Env, event: &ContractEvent) -> Result<Event, DecodeError> {
+    let ContractEventBody::V0(body) = &event.body;
+    let mut topics = Vec::new(env);
+    for topic in body.topics.iter() {
+        let topic = Val::try_from_val(env, topic).map_err(|_| DecodeError::MalformedTopics)?;
+        topics.push_back(topic);
+    }
+    decode_parts(env, &topics, &body.data)
+}
+
+/// Decode the XDR encoding of a `ContractEvent`.
+pub fn decode_xdr(env: &Env, bytes: &[u8]) -> Result<Event, DecodeError> {
+    let event =
+        ContractEvent::from_xdr(bytes, Limits::none()).map_err(|_| DecodeError::InvalidXdr)?;
+    decode_contract_event(env, &event)
+}
+
+// Payloads are decoded from XDR rather than host values so that a mismatched
+// map is reported as an error instead of trapping in the host.
+fn decode_parts(env: &Env, topics: &Vec<Val>, data: &ScVal) -> Result<Event, DecodeError> {
+    if topics.len() != 2 {
+        return Err(DecodeError::MalformedTopics);
+    }
+    let name = topics
+        .get(0)
+        .and_then(|t| Symbol::try_from_val(env, &t).ok())
+        .ok_or(DecodeError::MalformedTopics)?;
+    let version = topics
+        .get(1)
+        .and_then(|t| u32::try_from_val(env, &t).ok())
+        .ok_or(DecodeError::MalformedTopics)?;
+    if version != EVENT_SCHEMA_VERSION {
+        return Err(DecodeError::UnsupportedVersion(version));
+    }
+    decode_payload(env, &name.to_string(), data)
+}
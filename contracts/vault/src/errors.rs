@@ -42,6 +42,8 @@ pub enum VaultError {
     AlreadyApproved = 30,
     /// Signer has already abstained on this proposal
     AlreadyAbstained = 910,
+    /// Signer has already voted to reject this proposal
+    AlreadyRejected = 911,
     /// Amount is invalid (zero, negative, or exceeds limits)
     InvalidAmount = 40,
     /// Amount exceeds the single-proposal spending limit
@@ -193,6 +195,9 @@ pub enum VaultError {
     /// Requested action not covered by capability
     CapabilityNotGranted = 634,
 
+    /// Action would exceed the capability's cumulative amount budget
+    CapabilityBudgetExceeded = 635,
+
     /// Commit phase is closed (past commit_deadline)
     CommitPhaseClosed = 1100,
     /// Reveal phase has not started yet (before commit_deadline)
@@ -434,6 +439,144 @@ pub enum VaultError {
     PauseCooldownActive = 1127,
     /// Caller is not an emergency signer
     NotEmergencySigner = 1128,
+
+    // =========================================================
+    // Per-recipient caps and first-payment cool-off
+    // =========================================================
+    /// Payment would exceed the recipient's cumulative cap for the current window
+    ExceedsRecipientCap = 1129,
+    /// RecipientLimitConfig failed validation (negative cap or window out of range)
+    InvalidRecipientLimitConfig = 1130,
+
+    // =========================================================
+    // Tag budget envelopes
+    // =========================================================
+    /// Proposal would overrun a tag budget whose overrun policy is Block
+    TagBudgetExceeded = 1131,
+
+    // =========================================================
+    // Committed liabilities register
+    // =========================================================
+    /// Spending or committing the amount would leave the free balance negative
+    InsufficientFreeBalance = 1132,
+
+    // =========================================================
+    // Optimistic execution
+    // =========================================================
+    /// Optimistic proposals are disabled, or no veto window is configured
+    OptimisticModeDisabled = 1133,
+    /// Amount exceeds the optimistic cap for the vault or the proposer's tier
+    ExceedsOptimisticCap = 1134,
+    /// Proposal is not an unchallenged optimistic proposal
+    NotOptimisticProposal = 1135,
+    /// The challenge window for objections has closed
+    ChallengeWindowClosed = 1136,
+    /// Signer has already objected to this proposal
+    AlreadyObjected = 1137,
+
+    // =========================================================
+    // Governance parameter changes
+    // =========================================================
+    /// The same Config field appears more than once in a parameter change
+    DuplicateConfigChange = 1138,
+    /// A changed field no longer holds the value recorded when it was proposed
+    ConfigChangeStale = 1139,
+
+    // =========================================================
+    // Signer elections
+    // =========================================================
+    /// Signer election with the given ID does not exist
+    ElectionNotFound = 1140,
+    /// Another signer election has not been applied or failed yet
+    ElectionInProgress = 1141,
+    /// The election is not in the phase this action requires
+    ElectionPhaseClosed = 1142,
+    /// Address is already a candidate in this election
+    AlreadyCandidate = 1143,
+    /// Address is not a candidate in this election
+    NotACandidate = 1144,
+    /// Voter has already cast a ballot in this election
+    AlreadyVotedInElection = 1145,
+    /// Voter has no token lock that lasts through the voting window
+    NoLockedVotingPower = 1146,
+
+    // =========================================================
+    // Signer terms and inactivity
+    // =========================================================
+    /// Signer's term has lapsed and must be re-confirmed before voting
+    SignerTermExpired = 1147,
+    /// Signer is active and cannot be removed as inactive
+    SignerStillActive = 1148,
+    /// Signer has no term to re-confirm
+    NoSignerTerm = 1149,
+
+    // =========================================================
+    // Admin rotation
+    // =========================================================
+    /// Granting or revoking Admin must go through the two-step admin rotation
+    AdminRotationRequired = 1150,
+    /// Another admin rotation is already pending
+    AdminRotationPending = 1151,
+    /// There is no pending admin rotation
+    NoPendingAdminRotation = 1152,
+
+    // =========================================================
+    // Guardian council
+    // =========================================================
+    /// Caller is not on the guardian council
+    NotGuardian = 1153,
+    /// Guardian council has not been configured
+    GuardianCouncilNotSet = 1154,
+    /// Guardian action does not exist
+    GuardianActionNotFound = 1155,
+    /// Guardian action is not in a state that allows this operation
+    GuardianActionNotActive = 1156,
+    /// Target is held by a guardian action
+    GuardianHoldActive = 1157,
+
+    // =========================================================
+    // Custom roles
+    // =========================================================
+    /// Custom role is not defined
+    CustomRoleNotFound = 1158,
+    /// Role name clashes with a built-in role
+    RoleNameReserved = 1159,
+    /// Address already holds the maximum number of custom roles
+    TooManyCustomRoles = 1160,
+    /// Target falls outside the constraints of the caller's permission grant
+    GrantConstraintViolated = 1161,
+
+    // =========================================================
+    // Policy execution hooks
+    // =========================================================
+    /// A policy pre-hook denied execution, failed, or returned no decision
+    PreHookDenied = 1162,
+    /// More policy hooks apply to the proposal than the hook call budget allows
+    HookBudgetExceeded = 1163,
+
+    // =========================================================
+    // Recipient screening
+    // =========================================================
+    /// A screening registry lists the recipient
+    RecipientDenied = 1164,
+    /// A screening registry could not be queried and screening fails closed
+    ScreeningUnavailable = 1165,
+    /// More screening registries than the vault allows
+    TooManyScreeningRegistries = 1166,
+
+    // =========================================================
+    // Payment anomaly detection
+    // =========================================================
+    /// An anomalous proposal needs a veto address acknowledgement first
+    AnomalyAckRequired = 1167,
+    /// The proposal has no pending anomaly acknowledgement
+    AnomalyAckNotRequired = 1168,
+
+    // =========================================================
+    // Impact score model
+    // =========================================================
+    /// Impact score model weights, bands or tag risks are invalid
+    InvalidImpactModel = 1169,
 }
 
 // Compatibility markers for CI source checks:
@@ -1,18 +1,73 @@
 //! VaultDAO - Event Publishing
 //!
 //! Standardized events for proposal lifecycle and admin actions.
+//!
+//! Every event is published with the topics `(name, EVENT_SCHEMA_VERSION)`
+//! and a `#[contracttype]` payload struct as its data. Payloads encode as
+//! maps keyed by field name, so adding or reordering fields does not shift
+//! the meaning of existing ones. Names are never reused for a different
+//! payload; removing or retyping a field bumps `EVENT_SCHEMA_VERSION`.
+//! The `decode` module turns published events back into these structs.
+
+use crate::types::{
+    AdminRotationAction, AnomalyReason, DelegationScope, GuardianAction, ImpactBand, KeeperWork,
+    ProposalAmendment, ProposalStatus, SignerTier, SpendingWindowMode,
+};
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+/// Version of the payload layout, published as the second topic of every event.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// A typed event payload and the name it is published under.
+pub trait VaultEvent: IntoVal<Env, Val> {
+    /// Event name, published as the first topic.
+    const NAME: &'static str;
+}
+
+fn publish<E: VaultEvent>(env: &Env, event: E) {
+    env.events()
+        .publish((Symbol::new(env, E::NAME), EVENT_SCHEMA_VERSION), event);
+}
 
-use crate::types::ProposalAmendment;
-use soroban_sdk::{Address, Env, Symbol, Vec};
+/// `initialized` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub threshold: u32,
+}
+
+impl VaultEvent for InitializedEvent {
+    const NAME: &'static str = "initialized";
+}
 
 /// Emit when contract is initialized
 pub fn emit_initialized(env: &Env, admin: &Address, threshold: u32) {
-    env.events().publish(
-        (Symbol::new(env, "initialized"),),
-        (admin.clone(), threshold),
+    publish(
+        env,
+        InitializedEvent {
+            admin: admin.clone(),
+            threshold,
+        },
     );
 }
 
+/// `proposal_created` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCreatedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub insurance_amount: i128,
+}
+
+impl VaultEvent for ProposalCreatedEvent {
+    const NAME: &'static str = "proposal_created";
+}
+
 /// Emit when a new proposal is created (enhanced: includes token and insurance)
 pub fn emit_proposal_created(
     env: &Env,
@@ -23,18 +78,33 @@ pub fn emit_proposal_created(
     amount: i128,
     insurance_amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_created"), proposal_id),
-        (
-            proposer.clone(),
-            recipient.clone(),
-            token.clone(),
+    publish(
+        env,
+        ProposalCreatedEvent {
+            proposal_id,
+            proposer: proposer.clone(),
+            recipient: recipient.clone(),
+            token: token.clone(),
             amount,
             insurance_amount,
-        ),
+        },
     );
 }
 
+/// `proposal_approved` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalApprovedEvent {
+    pub proposal_id: u64,
+    pub approver: Address,
+    pub approval_count: u32,
+    pub threshold: u32,
+}
+
+impl VaultEvent for ProposalApprovedEvent {
+    const NAME: &'static str = "proposal_approved";
+}
+
 /// Emit when a proposal is approved by a signer
 pub fn emit_proposal_approved(
     env: &Env,
@@ -43,12 +113,31 @@ pub fn emit_proposal_approved(
     approval_count: u32,
     threshold: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_approved"), proposal_id),
-        (approver.clone(), approval_count, threshold),
+    publish(
+        env,
+        ProposalApprovedEvent {
+            proposal_id,
+            approver: approver.clone(),
+            approval_count,
+            threshold,
+        },
     );
 }
 
+/// `proposal_abstained` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalAbstainedEvent {
+    pub proposal_id: u64,
+    pub abstainer: Address,
+    pub abstention_count: u32,
+    pub quorum_votes: u32,
+}
+
+impl VaultEvent for ProposalAbstainedEvent {
+    const NAME: &'static str = "proposal_abstained";
+}
+
 /// Emit when a signer explicitly abstains from a proposal.
 ///
 /// # Arguments
@@ -63,12 +152,117 @@ pub fn emit_proposal_abstained(
     abstention_count: u32,
     quorum_votes: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_abstained"), proposal_id),
-        (abstainer.clone(), abstention_count, quorum_votes),
+    publish(
+        env,
+        ProposalAbstainedEvent {
+            proposal_id,
+            abstainer: abstainer.clone(),
+            abstention_count,
+            quorum_votes,
+        },
+    );
+}
+
+/// `proposal_reject_voted` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalRejectVotedEvent {
+    pub proposal_id: u64,
+    pub rejecter: Address,
+    pub rejection_count: u32,
+    pub quorum_votes: u32,
+}
+
+impl VaultEvent for ProposalRejectVotedEvent {
+    const NAME: &'static str = "proposal_reject_voted";
+}
+
+pub fn emit_proposal_reject_voted(
+    env: &Env,
+    proposal_id: u64,
+    rejecter: &Address,
+    rejection_count: u32,
+    quorum_votes: u32,
+) {
+    publish(
+        env,
+        ProposalRejectVotedEvent {
+            proposal_id,
+            rejecter: rejecter.clone(),
+            rejection_count,
+            quorum_votes,
+        },
+    );
+}
+
+/// `proposal_vote_rejected` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalVoteRejectedEvent {
+    pub proposal_id: u64,
+    pub rejection_count: u32,
+    pub reason: Symbol,
+}
+
+impl VaultEvent for ProposalVoteRejectedEvent {
+    const NAME: &'static str = "proposal_vote_rejected";
+}
+
+/// Emit when reject votes end a proposal early.
+/// `reason` is `threshold` (rejection threshold met) or `unreachable`
+/// (too few signers left to reach the approval threshold).
+pub fn emit_proposal_vote_rejected(
+    env: &Env,
+    proposal_id: u64,
+    rejection_count: u32,
+    reason: Symbol,
+) {
+    publish(
+        env,
+        ProposalVoteRejectedEvent {
+            proposal_id,
+            rejection_count,
+            reason,
+        },
+    );
+}
+
+/// `rejection_threshold_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RejectionThresholdSetEvent {
+    pub admin: Address,
+    pub threshold: u32,
+}
+
+impl VaultEvent for RejectionThresholdSetEvent {
+    const NAME: &'static str = "rejection_threshold_set";
+}
+
+pub fn emit_rejection_threshold_set(env: &Env, admin: &Address, threshold: u32) {
+    publish(
+        env,
+        RejectionThresholdSetEvent {
+            admin: admin.clone(),
+            threshold,
+        },
     );
 }
 
+/// `vote_changed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteChangedEvent {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub old_vote: u32,
+    pub new_vote: u32,
+}
+
+impl VaultEvent for VoteChangedEvent {
+    const NAME: &'static str = "vote_changed";
+}
+
 pub fn emit_vote_changed(
     env: &Env,
     proposal_id: u64,
@@ -76,20 +270,56 @@ pub fn emit_vote_changed(
     old_vote: u32,
     new_vote: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "vote_changed"), proposal_id),
-        (voter.clone(), old_vote, new_vote),
+    publish(
+        env,
+        VoteChangedEvent {
+            proposal_id,
+            voter: voter.clone(),
+            old_vote,
+            new_vote,
+        },
     );
 }
 
+/// `proposal_ready` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalReadyEvent {
+    pub proposal_id: u64,
+    pub unlock_ledger: u64,
+}
+
+impl VaultEvent for ProposalReadyEvent {
+    const NAME: &'static str = "proposal_ready";
+}
+
 /// Emit when a proposal reaches threshold and is ready for execution
 pub fn emit_proposal_ready(env: &Env, proposal_id: u64, unlock_ledger: u64) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_ready"), proposal_id),
-        unlock_ledger,
+    publish(
+        env,
+        ProposalReadyEvent {
+            proposal_id,
+            unlock_ledger,
+        },
     );
 }
 
+/// `proposal_executed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalExecutedEvent {
+    pub proposal_id: u64,
+    pub executor: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub ledger: u64,
+}
+
+impl VaultEvent for ProposalExecutedEvent {
+    const NAME: &'static str = "proposal_executed";
+}
+
 /// Emit when a proposal is executed (enhanced: includes token and ledger)
 pub fn emit_proposal_executed(
     env: &Env,
@@ -100,36 +330,77 @@ pub fn emit_proposal_executed(
     amount: i128,
     ledger: u64,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_executed"), proposal_id),
-        (
-            executor.clone(),
-            recipient.clone(),
-            token.clone(),
+    publish(
+        env,
+        ProposalExecutedEvent {
+            proposal_id,
+            executor: executor.clone(),
+            recipient: recipient.clone(),
+            token: token.clone(),
             amount,
             ledger,
-        ),
+        },
     );
 }
 
+/// `proposal_expired` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalExpiredEvent {
+    pub proposal_id: u64,
+    pub expires_at: u64,
+}
+
+impl VaultEvent for ProposalExpiredEvent {
+    const NAME: &'static str = "proposal_expired";
+}
+
 pub fn emit_proposal_expired(env: &Env, proposal_id: u64, expires_at: u64) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_expired"), proposal_id),
-        expires_at,
+    publish(
+        env,
+        ProposalExpiredEvent {
+            proposal_id,
+            expires_at,
+        },
     );
 }
 
+/// `exec_window_ledgers_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecWindowLedgersUpdatedEvent {
+    pub admin: Address,
+    pub ledgers: u64,
+}
+
+impl VaultEvent for ExecWindowLedgersUpdatedEvent {
+    const NAME: &'static str = "exec_window_ledgers_updated";
+}
+
 /// Emit when the execution window ledgers configuration is updated.
 pub fn emit_exec_window_ledgers_updated(env: &Env, admin: &Address, ledgers: u64) {
-    env.events().publish(
-        (
-            Symbol::new(env, "exec_window_ledgers_updated"),
-            admin.clone(),
-        ),
-        ledgers,
+    publish(
+        env,
+        ExecWindowLedgersUpdatedEvent {
+            admin: admin.clone(),
+            ledgers,
+        },
     );
 }
 
+/// `execution_window_expired` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutionWindowExpiredEvent {
+    pub proposal_id: u64,
+    pub approved_at: u64,
+    pub execution_window: u64,
+}
+
+impl VaultEvent for ExecutionWindowExpiredEvent {
+    const NAME: &'static str = "execution_window_expired";
+}
+
 /// Emit when an approved proposal's execution window has passed and it auto-expires.
 /// This is separate from voting deadline expiry — the proposal was approved but
 /// not executed within the configured `exec_window_ledgers`.
@@ -139,51 +410,135 @@ pub fn emit_execution_window_expired(
     approved_at: u64,
     execution_window: u64,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "execution_window_expired"), proposal_id),
-        (approved_at, execution_window),
+    publish(
+        env,
+        ExecutionWindowExpiredEvent {
+            proposal_id,
+            approved_at,
+            execution_window,
+        },
     );
 }
 
+/// `proposal_deadline_rejected` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalDeadlineRejectedEvent {
+    pub proposal_id: u64,
+    pub voting_deadline: u64,
+}
+
+impl VaultEvent for ProposalDeadlineRejectedEvent {
+    const NAME: &'static str = "proposal_deadline_rejected";
+}
+
 pub fn emit_proposal_deadline_rejected(env: &Env, proposal_id: u64, voting_deadline: u64) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_deadline_rejected"), proposal_id),
-        voting_deadline,
+    publish(
+        env,
+        ProposalDeadlineRejectedEvent {
+            proposal_id,
+            voting_deadline,
+        },
     );
 }
 
+/// `delegated_vote` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegatedVoteEvent {
+    pub proposal_id: u64,
+    pub effective_voter: Address,
+    pub signer: Address,
+}
+
+impl VaultEvent for DelegatedVoteEvent {
+    const NAME: &'static str = "delegated_vote";
+}
+
 pub fn emit_delegated_vote(
     env: &Env,
     proposal_id: u64,
     effective_voter: &Address,
     signer: &Address,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "delegated_vote"), proposal_id),
-        (effective_voter.clone(), signer.clone()),
+    publish(
+        env,
+        DelegatedVoteEvent {
+            proposal_id,
+            effective_voter: effective_voter.clone(),
+            signer: signer.clone(),
+        },
     );
 }
 
+/// `proposal_scheduled` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalScheduledEvent {
+    pub proposal_id: u64,
+    pub execution_time: u64,
+    pub unlock_ledger: u64,
+}
+
+impl VaultEvent for ProposalScheduledEvent {
+    const NAME: &'static str = "proposal_scheduled";
+}
+
 pub fn emit_proposal_scheduled(
     env: &Env,
     proposal_id: u64,
     execution_time: u64,
     unlock_ledger: u64,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_scheduled"), proposal_id),
-        (execution_time, unlock_ledger),
+    publish(
+        env,
+        ProposalScheduledEvent {
+            proposal_id,
+            execution_time,
+            unlock_ledger,
+        },
     );
 }
 
+/// `proposal_rejected` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalRejectedEvent {
+    pub proposal_id: u64,
+    pub rejector: Address,
+    pub proposer: Address,
+}
+
+impl VaultEvent for ProposalRejectedEvent {
+    const NAME: &'static str = "proposal_rejected";
+}
+
 /// Emit when a proposal is rejected (enhanced: includes proposer)
 pub fn emit_proposal_rejected(env: &Env, proposal_id: u64, rejector: &Address, proposer: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_rejected"), proposal_id),
-        (rejector.clone(), proposer.clone()),
+    publish(
+        env,
+        ProposalRejectedEvent {
+            proposal_id,
+            rejector: rejector.clone(),
+            proposer: proposer.clone(),
+        },
     );
 }
 
+/// `proposal_cancelled` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCancelledEvent {
+    pub proposal_id: u64,
+    pub cancelled_by: Address,
+    pub reason: Symbol,
+    pub refunded_amount: i128,
+}
+
+impl VaultEvent for ProposalCancelledEvent {
+    const NAME: &'static str = "proposal_cancelled";
+}
+
 /// Emit when a proposal is cancelled with a refund
 pub fn emit_proposal_cancelled(
     env: &Env,
@@ -192,56 +547,229 @@ pub fn emit_proposal_cancelled(
     reason: &Symbol,
     refunded_amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_cancelled"), proposal_id),
-        (cancelled_by.clone(), reason.clone(), refunded_amount),
+    publish(
+        env,
+        ProposalCancelledEvent {
+            proposal_id,
+            cancelled_by: cancelled_by.clone(),
+            reason: reason.clone(),
+            refunded_amount,
+        },
     );
 }
 
+/// `scheduled_proposal_cancelled` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledProposalCancelledEvent {
+    pub proposal_id: u64,
+    pub current_ledger: u64,
+}
+
+impl VaultEvent for ScheduledProposalCancelledEvent {
+    const NAME: &'static str = "scheduled_proposal_cancelled";
+}
+
 pub fn emit_scheduled_proposal_cancelled(env: &Env, proposal_id: u64, current_ledger: u64) {
-    env.events().publish(
-        (
-            Symbol::new(env, "scheduled_proposal_cancelled"),
+    publish(
+        env,
+        ScheduledProposalCancelledEvent {
             proposal_id,
-        ),
-        current_ledger,
+            current_ledger,
+        },
     );
 }
 
+/// `proposal_vetoed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalVetoedEvent {
+    pub proposal_id: u64,
+    pub vetoer: Address,
+}
+
+impl VaultEvent for ProposalVetoedEvent {
+    const NAME: &'static str = "proposal_vetoed";
+}
+
 pub fn emit_proposal_vetoed(env: &Env, proposal_id: u64, vetoer: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_vetoed"), proposal_id),
-        vetoer.clone(),
+    publish(
+        env,
+        ProposalVetoedEvent {
+            proposal_id,
+            vetoer: vetoer.clone(),
+        },
     );
 }
 
+/// `proposal_amended` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalAmendedEvent {
+    pub proposal_id: u64,
+    pub amended_by: Address,
+    pub old_recipient: Address,
+    pub new_recipient: Address,
+    pub old_amount: i128,
+    pub new_amount: i128,
+    pub old_memo: Symbol,
+    pub new_memo: Symbol,
+    pub amended_at_ledger: u64,
+}
+
+impl VaultEvent for ProposalAmendedEvent {
+    const NAME: &'static str = "proposal_amended";
+}
+
 /// Emit when a proposal is amended.
 pub fn emit_proposal_amended(env: &Env, amendment: &ProposalAmendment) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_amended"), amendment.proposal_id),
-        (
-            amendment.amended_by.clone(),
-            amendment.old_recipient.clone(),
-            amendment.new_recipient.clone(),
-            amendment.old_amount,
-            amendment.new_amount,
-            amendment.old_memo.clone(),
-            amendment.new_memo.clone(),
-            amendment.amended_at_ledger,
-        ),
+    publish(
+        env,
+        ProposalAmendedEvent {
+            proposal_id: amendment.proposal_id,
+            amended_by: amendment.amended_by.clone(),
+            old_recipient: amendment.old_recipient.clone(),
+            new_recipient: amendment.new_recipient.clone(),
+            old_amount: amendment.old_amount,
+            new_amount: amendment.new_amount,
+            old_memo: amendment.old_memo.clone(),
+            new_memo: amendment.new_memo.clone(),
+            amended_at_ledger: amendment.amended_at_ledger,
+        },
     );
 }
 
+/// `role_assigned` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleAssignedEvent {
+    pub addr: Address,
+    pub role: u32,
+}
+
+impl VaultEvent for RoleAssignedEvent {
+    const NAME: &'static str = "role_assigned";
+}
+
 /// Emit when a role is assigned
 pub fn emit_role_assigned(env: &Env, addr: &Address, role: u32) {
-    env.events()
-        .publish((Symbol::new(env, "role_assigned"),), (addr.clone(), role));
+    publish(
+        env,
+        RoleAssignedEvent {
+            addr: addr.clone(),
+            role,
+        },
+    );
+}
+
+/// `admin_rotation_proposed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminRotationProposedEvent {
+    pub proposer: Address,
+    pub action: AdminRotationAction,
+    pub executable_at: u64,
+}
+
+impl VaultEvent for AdminRotationProposedEvent {
+    const NAME: &'static str = "admin_rotation_proposed";
+}
+
+/// Emit when an admin rotation is proposed
+pub fn emit_admin_rotation_proposed(
+    env: &Env,
+    proposer: &Address,
+    action: &AdminRotationAction,
+    executable_at: u64,
+) {
+    publish(
+        env,
+        AdminRotationProposedEvent {
+            proposer: proposer.clone(),
+            action: action.clone(),
+            executable_at,
+        },
+    );
+}
+
+/// `admin_rotation_cancelled` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminRotationCancelledEvent {
+    pub canceller: Address,
+    pub action: AdminRotationAction,
+}
+
+impl VaultEvent for AdminRotationCancelledEvent {
+    const NAME: &'static str = "admin_rotation_cancelled";
+}
+
+/// Emit when a pending admin rotation is cancelled
+pub fn emit_admin_rotation_cancelled(env: &Env, canceller: &Address, action: &AdminRotationAction) {
+    publish(
+        env,
+        AdminRotationCancelledEvent {
+            canceller: canceller.clone(),
+            action: action.clone(),
+        },
+    );
+}
+
+/// `admin_rotation_accepted` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminRotationAcceptedEvent {
+    pub acceptor: Address,
+    pub action: AdminRotationAction,
+}
+
+impl VaultEvent for AdminRotationAcceptedEvent {
+    const NAME: &'static str = "admin_rotation_accepted";
+}
+
+/// Emit when a pending admin rotation takes effect
+pub fn emit_admin_rotation_accepted(env: &Env, acceptor: &Address, action: &AdminRotationAction) {
+    publish(
+        env,
+        AdminRotationAcceptedEvent {
+            acceptor: acceptor.clone(),
+            action: action.clone(),
+        },
+    );
+}
+
+/// `config_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigUpdatedEvent {
+    pub updater: Address,
+}
+
+impl VaultEvent for ConfigUpdatedEvent {
+    const NAME: &'static str = "config_updated";
 }
 
 /// Emit when config is updated
 pub fn emit_config_updated(env: &Env, updater: &Address) {
-    env.events()
-        .publish((Symbol::new(env, "config_updated"),), updater.clone());
+    publish(
+        env,
+        ConfigUpdatedEvent {
+            updater: updater.clone(),
+        },
+    );
+}
+
+/// `stream_burst_factor_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamBurstFactorUpdatedEvent {
+    pub admin: Address,
+    pub old_factor: u32,
+    pub new_factor: u32,
+}
+
+impl VaultEvent for StreamBurstFactorUpdatedEvent {
+    const NAME: &'static str = "stream_burst_factor_updated";
 }
 
 pub fn emit_stream_burst_factor_updated(
@@ -250,9 +778,13 @@ pub fn emit_stream_burst_factor_updated(
     old_factor: u32,
     new_factor: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "stream_burst_factor_updated"),),
-        (admin.clone(), old_factor, new_factor),
+    publish(
+        env,
+        StreamBurstFactorUpdatedEvent {
+            admin: admin.clone(),
+            old_factor,
+            new_factor,
+        },
     );
 }
 
@@ -260,19 +792,51 @@ pub fn emit_stream_burst_factor_updated(
 // Oracle Events (feature/oracle-integration)
 // ============================================================================
 
+/// `oracle_cfg_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleConfigUpdatedEvent {
+    pub admin: Address,
+    pub oracle: Address,
+}
+
+impl VaultEvent for OracleConfigUpdatedEvent {
+    const NAME: &'static str = "oracle_cfg_updated";
+}
+
 /// Emit when oracle configuration is updated by admin
 pub fn emit_oracle_config_updated(env: &Env, admin: &Address, oracle: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "oracle_cfg_updated"),),
-        (admin.clone(), oracle.clone()),
+    publish(
+        env,
+        OracleConfigUpdatedEvent {
+            admin: admin.clone(),
+            oracle: oracle.clone(),
+        },
     );
 }
 
+/// `oracle_price_stale` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OraclePriceStaleEvent {
+    pub asset: Address,
+    pub price_ledger: u64,
+    pub current_ledger: u64,
+}
+
+impl VaultEvent for OraclePriceStaleEvent {
+    const NAME: &'static str = "oracle_price_stale";
+}
+
 /// Emit when a stale oracle price blocks condition evaluation
 pub fn emit_oracle_price_stale(env: &Env, asset: &Address, price_ledger: u64, current_ledger: u64) {
-    env.events().publish(
-        (Symbol::new(env, "oracle_price_stale"),),
-        (asset.clone(), price_ledger, current_ledger),
+    publish(
+        env,
+        OraclePriceStaleEvent {
+            asset: asset.clone(),
+            price_ledger,
+            current_ledger,
+        },
     );
 }
 
@@ -280,6 +844,19 @@ pub fn emit_oracle_price_stale(env: &Env, asset: &Address, price_ledger: u64, cu
 // Gas-Price Oracle Fee Estimation Events (Issue #1367)
 // ============================================================================
 
+/// `oracle_gas_price_used` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleGasPriceUsedEvent {
+    pub proposal_id: u64,
+    pub price_used: i128,
+    pub source_is_oracle: bool,
+}
+
+impl VaultEvent for OracleGasPriceUsedEvent {
+    const NAME: &'static str = "oracle_gas_price_used";
+}
+
 /// Emit when a proposal fee estimate is finalized, recording which price source
 /// was used (oracle live price vs. local CostModel fallback) and the price value.
 ///
@@ -294,51 +871,136 @@ pub fn emit_oracle_gas_price_used(
     price_used: i128,
     source_is_oracle: bool,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "oracle_gas_price_used"), proposal_id),
-        (price_used, source_is_oracle),
+    publish(
+        env,
+        OracleGasPriceUsedEvent {
+            proposal_id,
+            price_used,
+            source_is_oracle,
+        },
     );
 }
 
+/// `quorum_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuorumUpdatedEvent {
+    pub admin: Address,
+    pub old_quorum: u32,
+    pub new_quorum: u32,
+}
+
+impl VaultEvent for QuorumUpdatedEvent {
+    const NAME: &'static str = "quorum_updated";
+}
+
 /// Emit when quorum configuration is updated by admin
 pub fn emit_quorum_updated(env: &Env, admin: &Address, old_quorum: u32, new_quorum: u32) {
-    env.events().publish(
-        (Symbol::new(env, "quorum_updated"),),
-        (admin.clone(), old_quorum, new_quorum),
+    publish(
+        env,
+        QuorumUpdatedEvent {
+            admin: admin.clone(),
+            old_quorum,
+            new_quorum,
+        },
     );
 }
 
+/// `quorum_reached` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuorumReachedEvent {
+    pub proposal_id: u64,
+    pub quorum_votes: u32,
+    pub required_quorum: u32,
+}
+
+impl VaultEvent for QuorumReachedEvent {
+    const NAME: &'static str = "quorum_reached";
+}
+
 /// Emit when a proposal reaches quorum participation threshold.
 pub fn emit_quorum_reached(env: &Env, proposal_id: u64, quorum_votes: u32, required_quorum: u32) {
-    env.events().publish(
-        (Symbol::new(env, "quorum_reached"), proposal_id),
-        (quorum_votes, required_quorum),
+    publish(
+        env,
+        QuorumReachedEvent {
+            proposal_id,
+            quorum_votes,
+            required_quorum,
+        },
     );
 }
 
+/// `threshold_reduced` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThresholdReducedEvent {
+    pub proposal_id: u64,
+    pub old_threshold: u32,
+    pub new_threshold: u32,
+}
+
+impl VaultEvent for ThresholdReducedEvent {
+    const NAME: &'static str = "threshold_reduced";
+}
+
 /// Emit when a proposal's threshold is reduced due to time-based strategy
 pub fn emit_threshold_reduced(env: &Env, proposal_id: u64, old_threshold: u32, new_threshold: u32) {
-    env.events().publish(
-        (Symbol::new(env, "threshold_reduced"), proposal_id),
-        (old_threshold, new_threshold),
+    publish(
+        env,
+        ThresholdReducedEvent {
+            proposal_id,
+            old_threshold,
+            new_threshold,
+        },
     );
 }
 
+/// `signer_added` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerAddedEvent {
+    pub signer: Address,
+    pub total_signers: u32,
+}
+
+impl VaultEvent for SignerAddedEvent {
+    const NAME: &'static str = "signer_added";
+}
+
 /// Emit when a signer is added
 #[allow(dead_code)]
 pub fn emit_signer_added(env: &Env, signer: &Address, total_signers: u32) {
-    env.events().publish(
-        (Symbol::new(env, "signer_added"),),
-        (signer.clone(), total_signers),
+    publish(
+        env,
+        SignerAddedEvent {
+            signer: signer.clone(),
+            total_signers,
+        },
     );
 }
 
+/// `signer_removed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerRemovedEvent {
+    pub signer: Address,
+    pub total_signers: u32,
+}
+
+impl VaultEvent for SignerRemovedEvent {
+    const NAME: &'static str = "signer_removed";
+}
+
 /// Emit when a signer is removed
 #[allow(dead_code)]
 pub fn emit_signer_removed(env: &Env, signer: &Address, total_signers: u32) {
-    env.events().publish(
-        (Symbol::new(env, "signer_removed"),),
-        (signer.clone(), total_signers),
+    publish(
+        env,
+        SignerRemovedEvent {
+            signer: signer.clone(),
+            total_signers,
+        },
     );
 }
 
@@ -346,6 +1008,20 @@ pub fn emit_signer_removed(env: &Env, signer: &Address, total_signers: u32) {
 // Insurance Events (feature/proposal-insurance)
 // ============================================================================
 
+/// `insurance_locked` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceLockedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub amount: i128,
+    pub token: Address,
+}
+
+impl VaultEvent for InsuranceLockedEvent {
+    const NAME: &'static str = "insurance_locked";
+}
+
 /// Emit when insurance stake is locked on proposal creation
 pub fn emit_insurance_locked(
     env: &Env,
@@ -354,12 +1030,31 @@ pub fn emit_insurance_locked(
     amount: i128,
     token: &Address,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "insurance_locked"), proposal_id),
-        (proposer.clone(), amount, token.clone()),
+    publish(
+        env,
+        InsuranceLockedEvent {
+            proposal_id,
+            proposer: proposer.clone(),
+            amount,
+            token: token.clone(),
+        },
     );
 }
 
+/// `insurance_slashed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceSlashedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub slashed_amount: i128,
+    pub returned_amount: i128,
+}
+
+impl VaultEvent for InsuranceSlashedEvent {
+    const NAME: &'static str = "insurance_slashed";
+}
+
 /// Emit when insurance stake is slashed on rejection
 pub fn emit_insurance_slashed(
     env: &Env,
@@ -368,17 +1063,39 @@ pub fn emit_insurance_slashed(
     slashed_amount: i128,
     returned_amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "insurance_slashed"), proposal_id),
-        (proposer.clone(), slashed_amount, returned_amount),
+    publish(
+        env,
+        InsuranceSlashedEvent {
+            proposal_id,
+            proposer: proposer.clone(),
+            slashed_amount,
+            returned_amount,
+        },
     );
 }
 
+/// `insurance_returned` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceReturnedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for InsuranceReturnedEvent {
+    const NAME: &'static str = "insurance_returned";
+}
+
 /// Emit when insurance stake is fully returned on successful execution
 pub fn emit_insurance_returned(env: &Env, proposal_id: u64, proposer: &Address, amount: i128) {
-    env.events().publish(
-        (Symbol::new(env, "insurance_returned"), proposal_id),
-        (proposer.clone(), amount),
+    publish(
+        env,
+        InsuranceReturnedEvent {
+            proposal_id,
+            proposer: proposer.clone(),
+            amount,
+        },
     );
 }
 
@@ -386,6 +1103,20 @@ pub fn emit_insurance_returned(env: &Env, proposal_id: u64, proposer: &Address,
 // Staking Events (feature/proposal-staking)
 // ============================================================================
 
+/// `stake_locked` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeLockedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub amount: i128,
+    pub token: Address,
+}
+
+impl VaultEvent for StakeLockedEvent {
+    const NAME: &'static str = "stake_locked";
+}
+
 /// Emit when stake is locked on proposal creation
 pub fn emit_stake_locked(
     env: &Env,
@@ -394,12 +1125,32 @@ pub fn emit_stake_locked(
     amount: i128,
     token: &Address,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "stake_locked"), proposal_id),
-        (proposer.clone(), amount, token.clone()),
+    publish(
+        env,
+        StakeLockedEvent {
+            proposal_id,
+            proposer: proposer.clone(),
+            amount,
+            token: token.clone(),
+        },
     );
 }
 
+/// `stake_slashed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeSlashedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub slashed: i128,
+    pub returned: i128,
+    pub reason: Symbol,
+}
+
+impl VaultEvent for StakeSlashedEvent {
+    const NAME: &'static str = "stake_slashed";
+}
+
 /// Emit when stake is slashed for a rejected or cancelled proposal.
 ///
 /// `reason` distinguishes the graduated slashing tiers (Issue #1360) so indexers
@@ -412,29 +1163,81 @@ pub fn emit_stake_slashed(
     returned: i128,
     reason: &Symbol,
 ) {
-    let topics = (Symbol::new(env, "stake_slashed"), proposal_id);
-    env.events().publish(
-        topics,
-        (proposer.clone(), slashed, returned, reason.clone()),
+    publish(
+        env,
+        StakeSlashedEvent {
+            proposal_id,
+            proposer: proposer.clone(),
+            slashed,
+            returned,
+            reason: reason.clone(),
+        },
     );
 }
 
+/// `stake_refunded` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeRefundedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for StakeRefundedEvent {
+    const NAME: &'static str = "stake_refunded";
+}
+
 /// Emit when stake is refunded on successful execution
 pub fn emit_stake_refunded(env: &Env, proposal_id: u64, proposer: &Address, amount: i128) {
-    env.events().publish(
-        (Symbol::new(env, "stake_refunded"), proposal_id),
-        (proposer.clone(), amount),
+    publish(
+        env,
+        StakeRefundedEvent {
+            proposal_id,
+            proposer: proposer.clone(),
+            amount,
+        },
     );
 }
 
+/// `auto_compound_enabled` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoCompoundEnabledEvent {
+    pub proposal_id: u64,
+    pub staker: Address,
+}
+
+impl VaultEvent for AutoCompoundEnabledEvent {
+    const NAME: &'static str = "auto_compound_enabled";
+}
+
 /// Emit when auto-compound is enabled for a stake
 pub fn emit_auto_compound_enabled(env: &Env, proposal_id: u64, staker: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "auto_compound_enabled"), proposal_id),
-        staker.clone(),
+    publish(
+        env,
+        AutoCompoundEnabledEvent {
+            proposal_id,
+            staker: staker.clone(),
+        },
     );
 }
 
+/// `stake_compounded` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeCompoundedEvent {
+    pub proposal_id: u64,
+    pub staker: Address,
+    pub reward_amount: i128,
+    pub new_stake_amount: i128,
+    pub lock_until: u64,
+}
+
+impl VaultEvent for StakeCompoundedEvent {
+    const NAME: &'static str = "stake_compounded";
+}
+
 /// Emit when a stake is compounded
 pub fn emit_stake_compounded(
     env: &Env,
@@ -444,9 +1247,15 @@ pub fn emit_stake_compounded(
     new_stake_amount: i128,
     lock_until: u64,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "stake_compounded"), proposal_id),
-        (staker.clone(), reward_amount, new_stake_amount, lock_until),
+    publish(
+        env,
+        StakeCompoundedEvent {
+            proposal_id,
+            staker: staker.clone(),
+            reward_amount,
+            new_stake_amount,
+            lock_until,
+        },
     );
 }
 
@@ -454,6 +1263,20 @@ pub fn emit_stake_compounded(
 // Reputation Events (feature/reputation-system)
 // ============================================================================
 
+/// `reputation_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationUpdatedEvent {
+    pub addr: Address,
+    pub old_score: u32,
+    pub new_score: u32,
+    pub reason: Symbol,
+}
+
+impl VaultEvent for ReputationUpdatedEvent {
+    const NAME: &'static str = "reputation_updated";
+}
+
 /// Emit when a user's reputation score is updated
 pub fn emit_reputation_updated(
     env: &Env,
@@ -462,9 +1285,14 @@ pub fn emit_reputation_updated(
     new_score: u32,
     reason: Symbol,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "reputation_updated"),),
-        (addr.clone(), old_score, new_score, reason),
+    publish(
+        env,
+        ReputationUpdatedEvent {
+            addr: addr.clone(),
+            old_score,
+            new_score,
+            reason,
+        },
     );
 }
 
@@ -472,22 +1300,56 @@ pub fn emit_reputation_updated(
 // Batch Execution Events (feature/batch-optimization)
 // ============================================================================
 
+/// `batch_executed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchExecutedEvent {
+    pub executor: Address,
+    pub executed_count: u32,
+    pub failed_count: u32,
+}
+
+impl VaultEvent for BatchExecutedEvent {
+    const NAME: &'static str = "batch_executed";
+}
+
 /// Emit when a batch execution completes
 pub fn emit_batch_executed(env: &Env, executor: &Address, executed_count: u32, failed_count: u32) {
-    env.events().publish(
-        (Symbol::new(env, "batch_executed"),),
-        (executor.clone(), executed_count, failed_count),
+    publish(
+        env,
+        BatchExecutedEvent {
+            executor: executor.clone(),
+            executed_count,
+            failed_count,
+        },
     );
 }
 
-/// Emit when a batch execution failed and was rolled back / aborted.
-///
-/// `reason` is the `VaultError` discriminant (as u32) that caused the abort,
+/// `batch_rolled_back` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchRolledBackEvent {
+    pub executor: Address,
+    pub rolled_back_count: u32,
+    pub reason: u32,
+}
+
+impl VaultEvent for BatchRolledBackEvent {
+    const NAME: &'static str = "batch_rolled_back";
+}
+
+/// Emit when a batch execution failed and was rolled back / aborted.
+///
+/// `reason` is the `VaultError` discriminant (as u32) that caused the abort,
 /// so off-chain indexers can surface *why* the batch didn't commit.
 pub fn emit_batch_rolled_back(env: &Env, executor: &Address, rolled_back_count: u32, reason: u32) {
-    env.events().publish(
-        (Symbol::new(env, "batch_rolled_back"),),
-        (executor.clone(), rolled_back_count, reason),
+    publish(
+        env,
+        BatchRolledBackEvent {
+            executor: executor.clone(),
+            rolled_back_count,
+            reason,
+        },
     );
 }
 
@@ -495,10 +1357,34 @@ pub fn emit_batch_rolled_back(env: &Env, executor: &Address, rolled_back_count:
 // Notification Events (feature/execution-notifications)
 // ============================================================================
 
+/// `notif_prefs_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationPrefsUpdatedEvent {
+    pub addr: Address,
+}
+
+impl VaultEvent for NotificationPrefsUpdatedEvent {
+    const NAME: &'static str = "notif_prefs_updated";
+}
+
 /// Emit when notification preferences are updated
 pub fn emit_notification_prefs_updated(env: &Env, addr: &Address) {
-    env.events()
-        .publish((Symbol::new(env, "notif_prefs_updated"),), addr.clone());
+    publish(env, NotificationPrefsUpdatedEvent { addr: addr.clone() });
+}
+
+/// `notif_dispatch` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationDispatchEvent {
+    pub event_type: Symbol,
+    pub proposal_id: u64,
+    pub amount: i128,
+    pub relevant_signers: Vec<Address>,
+}
+
+impl VaultEvent for NotificationDispatchEvent {
+    const NAME: &'static str = "notif_dispatch";
 }
 
 /// Companion event emitted alongside key proposal lifecycle events.
@@ -516,46 +1402,144 @@ pub fn emit_notification_dispatch(
     amount: i128,
     relevant_signers: &Vec<Address>,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "notif_dispatch"), event_type.clone()),
-        (proposal_id, amount, relevant_signers.clone()),
+    publish(
+        env,
+        NotificationDispatchEvent {
+            event_type: event_type.clone(),
+            proposal_id,
+            amount,
+            relevant_signers: relevant_signers.clone(),
+        },
     );
 }
 
+/// `insurance_cfg_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceConfigUpdatedEvent {
+    pub admin: Address,
+}
+
+impl VaultEvent for InsuranceConfigUpdatedEvent {
+    const NAME: &'static str = "insurance_cfg_updated";
+}
+
 /// Emit when insurance config is updated by admin
 pub fn emit_insurance_config_updated(env: &Env, admin: &Address) {
-    env.events()
-        .publish((Symbol::new(env, "insurance_cfg_updated"),), admin.clone());
+    publish(
+        env,
+        InsuranceConfigUpdatedEvent {
+            admin: admin.clone(),
+        },
+    );
+}
+
+/// `comment_added` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommentAddedEvent {
+    pub comment_id: u64,
+    pub proposal_id: u64,
+    pub author: Address,
+}
+
+impl VaultEvent for CommentAddedEvent {
+    const NAME: &'static str = "comment_added";
 }
 
 /// Emit when a comment is added
 pub fn emit_comment_added(env: &Env, comment_id: u64, proposal_id: u64, author: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "comment_added"), comment_id),
-        (proposal_id, author.clone()),
+    publish(
+        env,
+        CommentAddedEvent {
+            comment_id,
+            proposal_id,
+            author: author.clone(),
+        },
     );
 }
 
+/// `comment_edited` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommentEditedEvent {
+    pub comment_id: u64,
+    pub author: Address,
+}
+
+impl VaultEvent for CommentEditedEvent {
+    const NAME: &'static str = "comment_edited";
+}
+
 /// Emit when a comment is edited
 pub fn emit_comment_edited(env: &Env, comment_id: u64, author: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "comment_edited"), comment_id),
-        author.clone(),
+    publish(
+        env,
+        CommentEditedEvent {
+            comment_id,
+            author: author.clone(),
+        },
     );
 }
 
+/// `hook_registered` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HookRegisteredEvent {
+    pub hook: Address,
+    pub is_pre: bool,
+}
+
+impl VaultEvent for HookRegisteredEvent {
+    const NAME: &'static str = "hook_registered";
+}
+
 /// Emit when a hook is registered
 pub fn emit_hook_registered(env: &Env, hook: &Address, is_pre: bool) {
-    env.events().publish(
-        (Symbol::new(env, "hook_registered"),),
-        (hook.clone(), is_pre),
+    publish(
+        env,
+        HookRegisteredEvent {
+            hook: hook.clone(),
+            is_pre,
+        },
     );
 }
 
+/// `hook_removed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HookRemovedEvent {
+    pub hook: Address,
+    pub is_pre: bool,
+}
+
+impl VaultEvent for HookRemovedEvent {
+    const NAME: &'static str = "hook_removed";
+}
+
 /// Emit when a hook is removed
 pub fn emit_hook_removed(env: &Env, hook: &Address, is_pre: bool) {
-    env.events()
-        .publish((Symbol::new(env, "hook_removed"),), (hook.clone(), is_pre));
+    publish(
+        env,
+        HookRemovedEvent {
+            hook: hook.clone(),
+            is_pre,
+        },
+    );
+}
+
+/// `hook_executed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HookExecutedEvent {
+    pub hook: Address,
+    pub proposal_id: u64,
+    pub is_pre: bool,
+    pub success: bool,
+}
+
+impl VaultEvent for HookExecutedEvent {
+    const NAME: &'static str = "hook_executed";
 }
 
 /// Emit when a hook is executed
@@ -566,36 +1550,347 @@ pub fn emit_hook_executed(
     is_pre: bool,
     success: bool,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "hook_executed"), proposal_id),
-        (hook.clone(), is_pre, success),
+    publish(
+        env,
+        HookExecutedEvent {
+            hook: hook.clone(),
+            proposal_id,
+            is_pre,
+            success,
+        },
+    );
+}
+
+/// `hook_approvals_required` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HookApprovalsRequiredEvent {
+    pub proposal_id: u64,
+    pub hook: Address,
+    pub extra_approvals: u32,
+}
+
+impl VaultEvent for HookApprovalsRequiredEvent {
+    const NAME: &'static str = "hook_approvals_required";
+}
+
+/// Emit when a policy pre-hook sends a proposal back for extra approvals
+pub fn emit_hook_approvals_required(
+    env: &Env,
+    proposal_id: u64,
+    hook: &Address,
+    extra_approvals: u32,
+) {
+    publish(
+        env,
+        HookApprovalsRequiredEvent {
+            proposal_id,
+            hook: hook.clone(),
+            extra_approvals,
+        },
+    );
+}
+
+/// `hook_budget_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HookBudgetSetEvent {
+    pub admin: Address,
+    pub max_calls: u32,
+}
+
+impl VaultEvent for HookBudgetSetEvent {
+    const NAME: &'static str = "hook_budget_set";
+}
+
+/// Emit when the per-execution policy hook call budget changes
+pub fn emit_hook_budget_set(env: &Env, admin: &Address, max_calls: u32) {
+    publish(
+        env,
+        HookBudgetSetEvent {
+            admin: admin.clone(),
+            max_calls,
+        },
+    );
+}
+
+/// `screening_config_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScreeningConfigSetEvent {
+    pub admin: Address,
+    pub registries: Vec<Address>,
+    pub fail_closed: bool,
+}
+
+impl VaultEvent for ScreeningConfigSetEvent {
+    const NAME: &'static str = "screening_config_set";
+}
+
+/// Emit when the recipient screening registries change
+pub fn emit_screening_config_set(
+    env: &Env,
+    admin: &Address,
+    registries: &Vec<Address>,
+    fail_closed: bool,
+) {
+    publish(
+        env,
+        ScreeningConfigSetEvent {
+            admin: admin.clone(),
+            registries: registries.clone(),
+            fail_closed,
+        },
+    );
+}
+
+/// `screening_skipped` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScreeningSkippedEvent {
+    pub registry: Address,
+    pub recipient: Address,
+}
+
+impl VaultEvent for ScreeningSkippedEvent {
+    const NAME: &'static str = "screening_skipped";
+}
+
+/// Emit when an unreachable registry is skipped because screening fails open
+pub fn emit_screening_skipped(env: &Env, registry: &Address, recipient: &Address) {
+    publish(
+        env,
+        ScreeningSkippedEvent {
+            registry: registry.clone(),
+            recipient: recipient.clone(),
+        },
+    );
+}
+
+/// `anomaly_policy_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnomalyPolicySetEvent {
+    pub admin: Address,
+    pub max_multiplier: u32,
+    pub mean_multiplier: u32,
+    pub flag_new_recipient: bool,
+}
+
+impl VaultEvent for AnomalyPolicySetEvent {
+    const NAME: &'static str = "anomaly_policy_set";
+}
+
+/// Emit when the payment anomaly policy changes
+pub fn emit_anomaly_policy_set(
+    env: &Env,
+    admin: &Address,
+    max_multiplier: u32,
+    mean_multiplier: u32,
+    flag_new_recipient: bool,
+) {
+    publish(
+        env,
+        AnomalyPolicySetEvent {
+            admin: admin.clone(),
+            max_multiplier,
+            mean_multiplier,
+            flag_new_recipient,
+        },
+    );
+}
+
+/// `proposal_anomaly_flagged` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalAnomalyFlaggedEvent {
+    pub proposal_id: u64,
+    pub reasons: Vec<AnomalyReason>,
+    pub extra_approvals: u32,
+    pub extra_delay_ledgers: u64,
+    pub veto_ack_required: bool,
+}
+
+impl VaultEvent for ProposalAnomalyFlaggedEvent {
+    const NAME: &'static str = "proposal_anomaly_flagged";
+}
+
+/// Emit when a new proposal deviates from the vault's payment history
+pub fn emit_proposal_anomaly_flagged(
+    env: &Env,
+    proposal_id: u64,
+    reasons: &Vec<AnomalyReason>,
+    extra_approvals: u32,
+    extra_delay_ledgers: u64,
+    veto_ack_required: bool,
+) {
+    publish(
+        env,
+        ProposalAnomalyFlaggedEvent {
+            proposal_id,
+            reasons: reasons.clone(),
+            extra_approvals,
+            extra_delay_ledgers,
+            veto_ack_required,
+        },
+    );
+}
+
+/// `anomaly_acknowledged` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnomalyAcknowledgedEvent {
+    pub proposal_id: u64,
+    pub veto_address: Address,
+}
+
+impl VaultEvent for AnomalyAcknowledgedEvent {
+    const NAME: &'static str = "anomaly_acknowledged";
+}
+
+/// Emit when a veto address acknowledges an anomalous proposal
+pub fn emit_anomaly_acknowledged(env: &Env, proposal_id: u64, veto_address: &Address) {
+    publish(
+        env,
+        AnomalyAcknowledgedEvent {
+            proposal_id,
+            veto_address: veto_address.clone(),
+        },
+    );
+}
+
+/// `impact_model_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImpactModelSetEvent {
+    pub admin: Address,
+    /// False when the model was cleared and the built-in formula applies again
+    pub configured: bool,
+    pub bands: u32,
+}
+
+impl VaultEvent for ImpactModelSetEvent {
+    const NAME: &'static str = "impact_model_set";
+}
+
+/// Emit when the impact score model is set or cleared
+pub fn emit_impact_model_set(env: &Env, admin: &Address, configured: bool, bands: u32) {
+    publish(
+        env,
+        ImpactModelSetEvent {
+            admin: admin.clone(),
+            configured,
+            bands,
+        },
+    );
+}
+
+/// `impact_band_applied` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImpactBandAppliedEvent {
+    pub proposal_id: u64,
+    pub total_score: u32,
+    pub min_score: u32,
+    pub extra_approvals: u32,
+    pub timelock_multiplier_bps: u32,
+    pub insurance_bps: u32,
+}
+
+impl VaultEvent for ImpactBandAppliedEvent {
+    const NAME: &'static str = "impact_band_applied";
+}
+
+/// Emit when a new proposal falls into an impact score band
+pub fn emit_impact_band_applied(env: &Env, proposal_id: u64, total_score: u32, band: &ImpactBand) {
+    publish(
+        env,
+        ImpactBandAppliedEvent {
+            proposal_id,
+            total_score,
+            min_score: band.min_score,
+            extra_approvals: band.extra_approvals,
+            timelock_multiplier_bps: band.timelock_multiplier_bps,
+            insurance_bps: band.insurance_bps,
+        },
     );
 }
 
+/// `liquidity_removed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiquidityRemovedEvent {
+    pub proposal_id: u64,
+    pub dex: Address,
+    pub lp_tokens: i128,
+}
+
+impl VaultEvent for LiquidityRemovedEvent {
+    const NAME: &'static str = "liquidity_removed";
+}
+
 /// Emit when liquidity is removed
 #[allow(dead_code)]
 pub fn emit_liquidity_removed(env: &Env, proposal_id: u64, dex: &Address, lp_tokens: i128) {
-    env.events().publish(
-        (Symbol::new(env, "liquidity_removed"), proposal_id),
-        (dex.clone(), lp_tokens),
+    publish(
+        env,
+        LiquidityRemovedEvent {
+            proposal_id,
+            dex: dex.clone(),
+            lp_tokens,
+        },
     );
 }
 
+/// `lp_staked` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LpStakedEvent {
+    pub proposal_id: u64,
+    pub farm: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for LpStakedEvent {
+    const NAME: &'static str = "lp_staked";
+}
+
 /// Emit when LP tokens are staked
 #[allow(dead_code)]
 pub fn emit_lp_staked(env: &Env, proposal_id: u64, farm: &Address, amount: i128) {
-    env.events().publish(
-        (Symbol::new(env, "lp_staked"), proposal_id),
-        (farm.clone(), amount),
+    publish(
+        env,
+        LpStakedEvent {
+            proposal_id,
+            farm: farm.clone(),
+            amount,
+        },
     );
 }
 
+/// `rewards_claimed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardsClaimedEvent {
+    pub proposal_id: u64,
+    pub farm: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for RewardsClaimedEvent {
+    const NAME: &'static str = "rewards_claimed";
+}
+
 /// Emit when rewards are claimed
 #[allow(dead_code)]
 pub fn emit_rewards_claimed(env: &Env, proposal_id: u64, farm: &Address, amount: i128) {
-    env.events().publish(
-        (Symbol::new(env, "rewards_claimed"), proposal_id),
-        (farm.clone(), amount),
+    publish(
+        env,
+        RewardsClaimedEvent {
+            proposal_id,
+            farm: farm.clone(),
+            amount,
+        },
     );
 }
 
@@ -603,18 +1898,64 @@ pub fn emit_rewards_claimed(env: &Env, proposal_id: u64, farm: &Address, amount:
 // Gas Limit Events (feature/gas-limits)
 // ============================================================================
 
+/// `gas_limit_exceeded` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GasLimitExceededEvent {
+    pub proposal_id: u64,
+    pub gas_used: u64,
+    pub gas_limit: u64,
+}
+
+impl VaultEvent for GasLimitExceededEvent {
+    const NAME: &'static str = "gas_limit_exceeded";
+}
+
 /// Emit when a proposal execution is blocked by its gas limit
 pub fn emit_gas_limit_exceeded(env: &Env, proposal_id: u64, gas_used: u64, gas_limit: u64) {
-    env.events().publish(
-        (Symbol::new(env, "gas_limit_exceeded"), proposal_id),
-        (gas_used, gas_limit),
+    publish(
+        env,
+        GasLimitExceededEvent {
+            proposal_id,
+            gas_used,
+            gas_limit,
+        },
     );
 }
 
+/// `gas_cfg_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GasConfigUpdatedEvent {
+    pub admin: Address,
+}
+
+impl VaultEvent for GasConfigUpdatedEvent {
+    const NAME: &'static str = "gas_cfg_updated";
+}
+
 /// Emit when gas configuration is updated by admin
 pub fn emit_gas_config_updated(env: &Env, admin: &Address) {
-    env.events()
-        .publish((Symbol::new(env, "gas_cfg_updated"),), admin.clone());
+    publish(
+        env,
+        GasConfigUpdatedEvent {
+            admin: admin.clone(),
+        },
+    );
+}
+
+/// `exec_fee_estimated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutionFeeEstimatedEvent {
+    pub proposal_id: u64,
+    pub base_fee: u64,
+    pub resource_fee: u64,
+    pub total_fee: u64,
+}
+
+impl VaultEvent for ExecutionFeeEstimatedEvent {
+    const NAME: &'static str = "exec_fee_estimated";
 }
 
 /// Emit when execution fee estimate is calculated/refreshed for a proposal.
@@ -625,22 +1966,58 @@ pub fn emit_execution_fee_estimated(
     resource_fee: u64,
     total_fee: u64,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "exec_fee_estimated"), proposal_id),
-        (base_fee, resource_fee, total_fee),
+    publish(
+        env,
+        ExecutionFeeEstimatedEvent {
+            proposal_id,
+            base_fee,
+            resource_fee,
+            total_fee,
+        },
     );
 }
 
+/// `exec_fee_used` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutionFeeUsedEvent {
+    pub proposal_id: u64,
+    pub total_fee: u64,
+}
+
+impl VaultEvent for ExecutionFeeUsedEvent {
+    const NAME: &'static str = "exec_fee_used";
+}
+
 /// Emit when a proposal execution consumes its estimated fee.
 pub fn emit_execution_fee_used(env: &Env, proposal_id: u64, total_fee: u64) {
-    env.events()
-        .publish((Symbol::new(env, "exec_fee_used"), proposal_id), total_fee);
+    publish(
+        env,
+        ExecutionFeeUsedEvent {
+            proposal_id,
+            total_fee,
+        },
+    );
 }
 
 // ============================================================================
 // Performance Metrics Events (feature/performance-metrics)
 // ============================================================================
 
+/// `metrics_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetricsUpdatedEvent {
+    pub executed: u64,
+    pub rejected: u64,
+    pub expired: u64,
+    pub success_rate_bps: u32,
+}
+
+impl VaultEvent for MetricsUpdatedEvent {
+    const NAME: &'static str = "metrics_updated";
+}
+
 /// Emit when vault-wide metrics are updated
 pub fn emit_metrics_updated(
     env: &Env,
@@ -649,9 +2026,14 @@ pub fn emit_metrics_updated(
     expired: u64,
     success_rate_bps: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "metrics_updated"),),
-        (executed, rejected, expired, success_rate_bps),
+    publish(
+        env,
+        MetricsUpdatedEvent {
+            executed,
+            rejected,
+            expired,
+            success_rate_bps,
+        },
     );
 }
 
@@ -659,6 +2041,20 @@ pub fn emit_metrics_updated(
 // Voting Deadline Events
 // ============================================================================
 
+/// `voting_deadline_ext` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VotingDeadlineExtendedEvent {
+    pub proposal_id: u64,
+    pub old_deadline: u64,
+    pub new_deadline: u64,
+    pub admin: Address,
+}
+
+impl VaultEvent for VotingDeadlineExtendedEvent {
+    const NAME: &'static str = "voting_deadline_ext";
+}
+
 /// Emit when a proposal's voting deadline is extended
 pub fn emit_voting_deadline_extended(
     env: &Env,
@@ -667,9 +2063,14 @@ pub fn emit_voting_deadline_extended(
     new_deadline: u64,
     admin: &Address,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "voting_deadline_ext"), proposal_id),
-        (old_deadline, new_deadline, admin.clone()),
+    publish(
+        env,
+        VotingDeadlineExtendedEvent {
+            proposal_id,
+            old_deadline,
+            new_deadline,
+            admin: admin.clone(),
+        },
     );
 }
 
@@ -677,6 +2078,19 @@ pub fn emit_voting_deadline_extended(
 // Proposal Template Events (feature/contract-templates)
 // ============================================================================
 
+/// `template_created` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateCreatedEvent {
+    pub template_id: u64,
+    pub name: Symbol,
+    pub creator: Address,
+}
+
+impl VaultEvent for TemplateCreatedEvent {
+    const NAME: &'static str = "template_created";
+}
+
 /// Emit when a new template is created
 #[allow(dead_code)]
 pub fn emit_template_created(
@@ -685,12 +2099,30 @@ pub fn emit_template_created(
     name: &soroban_sdk::Symbol,
     creator: &Address,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "template_created"), template_id),
-        (name.clone(), creator.clone()),
+    publish(
+        env,
+        TemplateCreatedEvent {
+            template_id,
+            name: name.clone(),
+            creator: creator.clone(),
+        },
     );
 }
 
+/// `template_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateUpdatedEvent {
+    pub template_id: u64,
+    pub name: Symbol,
+    pub version: u32,
+    pub updater: Address,
+}
+
+impl VaultEvent for TemplateUpdatedEvent {
+    const NAME: &'static str = "template_updated";
+}
+
 /// Emit when a template is updated
 #[allow(dead_code)]
 pub fn emit_template_updated(
@@ -700,20 +2132,54 @@ pub fn emit_template_updated(
     version: u32,
     updater: &Address,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "template_updated"), template_id),
-        (name.clone(), version, updater.clone()),
+    publish(
+        env,
+        TemplateUpdatedEvent {
+            template_id,
+            name: name.clone(),
+            version,
+            updater: updater.clone(),
+        },
     );
 }
 
+/// `template_ver_pruned` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateVersionPrunedEvent {
+    pub template_id: u64,
+    pub pruned_version: u32,
+}
+
+impl VaultEvent for TemplateVersionPrunedEvent {
+    const NAME: &'static str = "template_ver_pruned";
+}
+
 /// Emit when the oldest stored template version is pruned due to the 10-version cap
 pub fn emit_template_version_pruned(env: &Env, template_id: u64, pruned_version: u32) {
-    env.events().publish(
-        (Symbol::new(env, "template_ver_pruned"), template_id),
-        pruned_version,
+    publish(
+        env,
+        TemplateVersionPrunedEvent {
+            template_id,
+            pruned_version,
+        },
     );
 }
 
+/// `template_status` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateStatusChangedEvent {
+    pub template_id: u64,
+    pub name: Symbol,
+    pub is_active: bool,
+    pub admin: Address,
+}
+
+impl VaultEvent for TemplateStatusChangedEvent {
+    const NAME: &'static str = "template_status";
+}
+
 /// Emit when a template's active status changes
 #[allow(dead_code)]
 pub fn emit_template_status_changed(
@@ -723,12 +2189,31 @@ pub fn emit_template_status_changed(
     is_active: bool,
     admin: &Address,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "template_status"), template_id),
-        (name.clone(), is_active, admin.clone()),
+    publish(
+        env,
+        TemplateStatusChangedEvent {
+            template_id,
+            name: name.clone(),
+            is_active,
+            admin: admin.clone(),
+        },
     );
 }
 
+/// `proposal_from_template` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalFromTemplateEvent {
+    pub proposal_id: u64,
+    pub template_id: u64,
+    pub template_name: Symbol,
+    pub proposer: Address,
+}
+
+impl VaultEvent for ProposalFromTemplateEvent {
+    const NAME: &'static str = "proposal_from_template";
+}
+
 /// Emit when a proposal is created from a template
 pub fn emit_proposal_from_template(
     env: &Env,
@@ -737,9 +2222,14 @@ pub fn emit_proposal_from_template(
     template_name: &soroban_sdk::Symbol,
     proposer: &Address,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "proposal_from_template"), proposal_id),
-        (template_id, template_name.clone(), proposer.clone()),
+    publish(
+        env,
+        ProposalFromTemplateEvent {
+            proposal_id,
+            template_id,
+            template_name: template_name.clone(),
+            proposer: proposer.clone(),
+        },
     );
 }
 
@@ -747,6 +2237,20 @@ pub fn emit_proposal_from_template(
 // Retry Events (feature/execution-retry)
 // ============================================================================
 
+/// `retry_scheduled` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryScheduledEvent {
+    pub proposal_id: u64,
+    pub retry_count: u32,
+    pub next_retry_ledger: u64,
+    pub error_code: u32,
+}
+
+impl VaultEvent for RetryScheduledEvent {
+    const NAME: &'static str = "retry_scheduled";
+}
+
 /// Emit when an execution retry is scheduled after a transient failure
 pub fn emit_retry_scheduled(
     env: &Env,
@@ -755,12 +2259,31 @@ pub fn emit_retry_scheduled(
     next_retry_ledger: u64,
     error_code: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "retry_scheduled"), proposal_id),
-        (retry_count, next_retry_ledger, error_code),
+    publish(
+        env,
+        RetryScheduledEvent {
+            proposal_id,
+            retry_count,
+            next_retry_ledger,
+            error_code,
+        },
     );
 }
 
+/// `recurring_retry_scheduled` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecurringRetryScheduledEvent {
+    pub payment_id: u64,
+    pub retry_count: u32,
+    pub next_retry_ledger: u64,
+    pub error_code: u32,
+}
+
+impl VaultEvent for RecurringRetryScheduledEvent {
+    const NAME: &'static str = "recurring_retry_scheduled";
+}
+
 /// Emit when a recurring payment retry is scheduled after a failed transfer
 pub fn emit_recurring_retry_scheduled(
     env: &Env,
@@ -769,40 +2292,109 @@ pub fn emit_recurring_retry_scheduled(
     next_retry_ledger: u64,
     error_code: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "recurring_retry_scheduled"), payment_id),
-        (retry_count, next_retry_ledger, error_code),
+    publish(
+        env,
+        RecurringRetryScheduledEvent {
+            payment_id,
+            retry_count,
+            next_retry_ledger,
+            error_code,
+        },
     );
 }
 
+/// `retry_attempted` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryAttemptedEvent {
+    pub proposal_id: u64,
+    pub retry_count: u32,
+    pub executor: Address,
+}
+
+impl VaultEvent for RetryAttemptedEvent {
+    const NAME: &'static str = "retry_attempted";
+}
+
 /// Emit when a retry execution attempt is made
 #[allow(dead_code)]
 pub fn emit_retry_attempted(env: &Env, proposal_id: u64, retry_count: u32, executor: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "retry_attempted"), proposal_id),
-        (retry_count, executor.clone()),
+    publish(
+        env,
+        RetryAttemptedEvent {
+            proposal_id,
+            retry_count,
+            executor: executor.clone(),
+        },
     );
 }
 
+/// `retries_exhausted` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetriesExhaustedEvent {
+    pub proposal_id: u64,
+    pub total_attempts: u32,
+}
+
+impl VaultEvent for RetriesExhaustedEvent {
+    const NAME: &'static str = "retries_exhausted";
+}
+
 /// Emit when all retry attempts for a proposal have been exhausted
 pub fn emit_retries_exhausted(env: &Env, proposal_id: u64, total_attempts: u32) {
-    env.events().publish(
-        (Symbol::new(env, "retries_exhausted"), proposal_id),
-        total_attempts,
+    publish(
+        env,
+        RetriesExhaustedEvent {
+            proposal_id,
+            total_attempts,
+        },
     );
 }
 
+/// `dead_letter_added` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadLetterAddedEvent {
+    pub record_id: u64,
+    pub proposal_id: u64,
+    pub retry_count: u32,
+}
+
+impl VaultEvent for DeadLetterAddedEvent {
+    const NAME: &'static str = "dead_letter_added";
+}
+
 pub fn emit_dead_letter_added(env: &Env, record_id: u64, proposal_id: u64, retry_count: u32) {
-    env.events().publish(
-        (Symbol::new(env, "dead_letter_added"), record_id),
-        (proposal_id, retry_count),
+    publish(
+        env,
+        DeadLetterAddedEvent {
+            record_id,
+            proposal_id,
+            retry_count,
+        },
     );
 }
 
+/// `dead_letter_proc` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadLetterProcessedEvent {
+    pub record_id: u64,
+    pub admin: Address,
+}
+
+impl VaultEvent for DeadLetterProcessedEvent {
+    const NAME: &'static str = "dead_letter_proc";
+}
+
 pub fn emit_dead_letter_processed(env: &Env, record_id: u64, admin: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "dead_letter_proc"), record_id),
-        admin.clone(),
+    publish(
+        env,
+        DeadLetterProcessedEvent {
+            record_id,
+            admin: admin.clone(),
+        },
     );
 }
 
@@ -810,6 +2402,20 @@ pub fn emit_dead_letter_processed(env: &Env, record_id: u64, admin: &Address) {
 // Subscription Events (feature/subscription-system)
 // ============================================================================
 
+/// `subscription_created` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscriptionCreatedEvent {
+    pub subscription_id: u64,
+    pub subscriber: Address,
+    pub tier: u32,
+    pub amount: i128,
+}
+
+impl VaultEvent for SubscriptionCreatedEvent {
+    const NAME: &'static str = "subscription_created";
+}
+
 /// Emit when a new subscription is created
 #[allow(dead_code)]
 pub fn emit_subscription_created(
@@ -819,12 +2425,30 @@ pub fn emit_subscription_created(
     tier: u32,
     amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "subscription_created"), subscription_id),
-        (subscriber.clone(), tier, amount),
+    publish(
+        env,
+        SubscriptionCreatedEvent {
+            subscription_id,
+            subscriber: subscriber.clone(),
+            tier,
+            amount,
+        },
     );
 }
 
+/// `subscription_renewed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscriptionRenewedEvent {
+    pub subscription_id: u64,
+    pub payment_number: u32,
+    pub amount: i128,
+}
+
+impl VaultEvent for SubscriptionRenewedEvent {
+    const NAME: &'static str = "subscription_renewed";
+}
+
 /// Emit when a subscription is renewed
 #[allow(dead_code)]
 pub fn emit_subscription_renewed(
@@ -833,21 +2457,54 @@ pub fn emit_subscription_renewed(
     payment_number: u32,
     amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "subscription_renewed"), subscription_id),
-        (payment_number, amount),
+    publish(
+        env,
+        SubscriptionRenewedEvent {
+            subscription_id,
+            payment_number,
+            amount,
+        },
     );
 }
 
+/// `subscription_cancelled` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscriptionCancelledEvent {
+    pub subscription_id: u64,
+    pub cancelled_by: Address,
+}
+
+impl VaultEvent for SubscriptionCancelledEvent {
+    const NAME: &'static str = "subscription_cancelled";
+}
+
 /// Emit when a subscription is cancelled
 #[allow(dead_code)]
 pub fn emit_subscription_cancelled(env: &Env, subscription_id: u64, cancelled_by: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "subscription_cancelled"), subscription_id),
-        cancelled_by.clone(),
+    publish(
+        env,
+        SubscriptionCancelledEvent {
+            subscription_id,
+            cancelled_by: cancelled_by.clone(),
+        },
     );
 }
 
+/// `subscription_upgraded` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscriptionUpgradedEvent {
+    pub subscription_id: u64,
+    pub old_tier: u32,
+    pub new_tier: u32,
+    pub new_amount: i128,
+}
+
+impl VaultEvent for SubscriptionUpgradedEvent {
+    const NAME: &'static str = "subscription_upgraded";
+}
+
 /// Emit when a subscription tier is upgraded
 #[allow(dead_code)]
 pub fn emit_subscription_upgraded(
@@ -857,35 +2514,82 @@ pub fn emit_subscription_upgraded(
     new_tier: u32,
     new_amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "subscription_upgraded"), subscription_id),
-        (old_tier, new_tier, new_amount),
+    publish(
+        env,
+        SubscriptionUpgradedEvent {
+            subscription_id,
+            old_tier,
+            new_tier,
+            new_amount,
+        },
     );
 }
 
+/// `subscription_expired` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscriptionExpiredEvent {
+    pub subscription_id: u64,
+}
+
+impl VaultEvent for SubscriptionExpiredEvent {
+    const NAME: &'static str = "subscription_expired";
+}
+
 /// Emit when a subscription expires
 #[allow(dead_code)]
 pub fn emit_subscription_expired(env: &Env, subscription_id: u64) {
-    env.events()
-        .publish((Symbol::new(env, "subscription_expired"),), subscription_id);
+    publish(env, SubscriptionExpiredEvent { subscription_id });
+}
+
+/// `subscription_paused` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscriptionPausedEvent {
+    pub subscription_id: u64,
+    pub paused_by: Address,
+}
+
+impl VaultEvent for SubscriptionPausedEvent {
+    const NAME: &'static str = "subscription_paused";
 }
 
 pub fn emit_subscription_paused(env: &Env, subscription_id: u64, paused_by: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "subscription_paused"), subscription_id),
-        paused_by.clone(),
+    publish(
+        env,
+        SubscriptionPausedEvent {
+            subscription_id,
+            paused_by: paused_by.clone(),
+        },
     );
 }
 
-pub fn emit_subscription_resumed(
-    env: &Env,
+/// `subscription_resumed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscriptionResumedEvent {
+    pub subscription_id: u64,
+    pub resumed_by: Address,
+    pub pause_duration: u64,
+}
+
+impl VaultEvent for SubscriptionResumedEvent {
+    const NAME: &'static str = "subscription_resumed";
+}
+
+pub fn emit_subscription_resumed(
+    env: &Env,
     subscription_id: u64,
     resumed_by: &Address,
     pause_duration: u64,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "subscription_resumed"), subscription_id),
-        (resumed_by.clone(), pause_duration),
+    publish(
+        env,
+        SubscriptionResumedEvent {
+            subscription_id,
+            resumed_by: resumed_by.clone(),
+            pause_duration,
+        },
     );
 }
 
@@ -893,6 +2597,22 @@ pub fn emit_subscription_resumed(
 // Escrow Events (feature/escrow-system)
 // ============================================================================
 
+/// `escrow_created` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowCreatedEvent {
+    pub escrow_id: u64,
+    pub funder: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub duration_ledgers: u64,
+}
+
+impl VaultEvent for EscrowCreatedEvent {
+    const NAME: &'static str = "escrow_created";
+}
+
 /// Emit when an escrow agreement is created
 pub fn emit_escrow_created(
     env: &Env,
@@ -903,26 +2623,67 @@ pub fn emit_escrow_created(
     amount: i128,
     duration_ledgers: u64,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "escrow_created"), escrow_id),
-        (
-            funder.clone(),
-            recipient.clone(),
-            token.clone(),
+    publish(
+        env,
+        EscrowCreatedEvent {
+            escrow_id,
+            funder: funder.clone(),
+            recipient: recipient.clone(),
+            token: token.clone(),
             amount,
             duration_ledgers,
-        ),
+        },
     );
 }
 
+/// `milestone_complete` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneCompletedEvent {
+    pub escrow_id: u64,
+    pub milestone_id: u64,
+    pub completer: Address,
+    /// Every milestone of the escrow is now complete
+    pub all_complete: bool,
+}
+
+impl VaultEvent for MilestoneCompletedEvent {
+    const NAME: &'static str = "milestone_complete";
+}
+
 /// Emit when a milestone is completed
-pub fn emit_milestone_completed(env: &Env, escrow_id: u64, milestone_id: u64, completer: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "milestone_complete"), escrow_id),
-        (milestone_id, completer.clone()),
+pub fn emit_milestone_completed(
+    env: &Env,
+    escrow_id: u64,
+    milestone_id: u64,
+    completer: &Address,
+    all_complete: bool,
+) {
+    publish(
+        env,
+        MilestoneCompletedEvent {
+            escrow_id,
+            milestone_id,
+            completer: completer.clone(),
+            all_complete,
+        },
     );
 }
 
+/// `escrow_released` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReleasedEvent {
+    pub escrow_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub is_refund: bool,
+}
+
+impl VaultEvent for EscrowReleasedEvent {
+    const NAME: &'static str = "escrow_released";
+}
+
 /// Emit when escrow funds are released
 pub fn emit_escrow_released(
     env: &Env,
@@ -931,20 +2692,55 @@ pub fn emit_escrow_released(
     amount: i128,
     is_refund: bool,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "escrow_released"), escrow_id),
-        (recipient.clone(), amount, is_refund),
+    publish(
+        env,
+        EscrowReleasedEvent {
+            escrow_id,
+            recipient: recipient.clone(),
+            amount,
+            is_refund,
+        },
     );
 }
 
+/// `escrow_disputed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowDisputedEvent {
+    pub escrow_id: u64,
+    pub disputer: Address,
+    pub reason: Symbol,
+}
+
+impl VaultEvent for EscrowDisputedEvent {
+    const NAME: &'static str = "escrow_disputed";
+}
+
 /// Emit when an escrow is disputed
 pub fn emit_escrow_disputed(env: &Env, escrow_id: u64, disputer: &Address, reason: &Symbol) {
-    env.events().publish(
-        (Symbol::new(env, "escrow_disputed"), escrow_id),
-        (disputer.clone(), reason.clone()),
+    publish(
+        env,
+        EscrowDisputedEvent {
+            escrow_id,
+            disputer: disputer.clone(),
+            reason: reason.clone(),
+        },
     );
 }
 
+/// `escrow_resolved` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowDisputeResolvedEvent {
+    pub escrow_id: u64,
+    pub arbitrator: Address,
+    pub released_to_recipient: bool,
+}
+
+impl VaultEvent for EscrowDisputeResolvedEvent {
+    const NAME: &'static str = "escrow_resolved";
+}
+
 /// Emit when an escrow dispute is resolved
 pub fn emit_escrow_dispute_resolved(
     env: &Env,
@@ -952,19 +2748,54 @@ pub fn emit_escrow_dispute_resolved(
     arbitrator: &Address,
     released_to_recipient: bool,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "escrow_resolved"), escrow_id),
-        (arbitrator.clone(), released_to_recipient),
+    publish(
+        env,
+        EscrowDisputeResolvedEvent {
+            escrow_id,
+            arbitrator: arbitrator.clone(),
+            released_to_recipient,
+        },
     );
 }
 
+/// `escrow_auto_resolved` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowAutoResolvedEvent {
+    pub escrow_id: u64,
+    pub amount_refunded: i128,
+}
+
+impl VaultEvent for EscrowAutoResolvedEvent {
+    const NAME: &'static str = "escrow_auto_resolved";
+}
+
 pub fn emit_escrow_auto_resolved(env: &Env, escrow_id: u64, amount_refunded: i128) {
-    env.events().publish(
-        (Symbol::new(env, "escrow_auto_resolved"), escrow_id),
-        amount_refunded,
+    publish(
+        env,
+        EscrowAutoResolvedEvent {
+            escrow_id,
+            amount_refunded,
+        },
     );
 }
 
+/// `funding_round_created` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingRoundCreatedEvent {
+    pub round_id: u64,
+    pub proposal_id: u64,
+    pub recipient: Address,
+    pub token: Address,
+    pub total_amount: i128,
+    pub milestone_count: u32,
+}
+
+impl VaultEvent for FundingRoundCreatedEvent {
+    const NAME: &'static str = "funding_round_created";
+}
+
 /// Emit when a funding round is created
 pub fn emit_funding_round_created(
     env: &Env,
@@ -975,26 +2806,55 @@ pub fn emit_funding_round_created(
     total_amount: i128,
     milestone_count: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "funding_round_created"), round_id),
-        (
+    publish(
+        env,
+        FundingRoundCreatedEvent {
+            round_id,
             proposal_id,
-            recipient.clone(),
-            token.clone(),
+            recipient: recipient.clone(),
+            token: token.clone(),
             total_amount,
             milestone_count,
-        ),
+        },
     );
 }
 
+/// `funding_round_approved` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingRoundApprovedEvent {
+    pub round_id: u64,
+    pub approver: Address,
+}
+
+impl VaultEvent for FundingRoundApprovedEvent {
+    const NAME: &'static str = "funding_round_approved";
+}
+
 /// Emit when a funding round is approved
 pub fn emit_funding_round_approved(env: &Env, round_id: u64, approver: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "funding_round_approved"), round_id),
-        approver.clone(),
+    publish(
+        env,
+        FundingRoundApprovedEvent {
+            round_id,
+            approver: approver.clone(),
+        },
     );
 }
 
+/// `milestone_submitted` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneSubmittedEvent {
+    pub round_id: u64,
+    pub milestone_index: u32,
+    pub submitter: Address,
+}
+
+impl VaultEvent for MilestoneSubmittedEvent {
+    const NAME: &'static str = "milestone_submitted";
+}
+
 /// Emit when a milestone is submitted for verification
 pub fn emit_milestone_submitted(
     env: &Env,
@@ -1002,12 +2862,30 @@ pub fn emit_milestone_submitted(
     milestone_index: u32,
     submitter: &Address,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "milestone_submitted"), round_id),
-        (milestone_index, submitter.clone()),
+    publish(
+        env,
+        MilestoneSubmittedEvent {
+            round_id,
+            milestone_index,
+            submitter: submitter.clone(),
+        },
     );
 }
 
+/// `milestone_verified` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneVerifiedEvent {
+    pub round_id: u64,
+    pub milestone_index: u32,
+    pub verifier: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for MilestoneVerifiedEvent {
+    const NAME: &'static str = "milestone_verified";
+}
+
 /// Emit when a milestone is verified
 pub fn emit_milestone_verified(
     env: &Env,
@@ -1016,21 +2894,58 @@ pub fn emit_milestone_verified(
     verifier: &Address,
     amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "milestone_verified"), round_id),
-        (milestone_index, verifier.clone(), amount),
+    publish(
+        env,
+        MilestoneVerifiedEvent {
+            round_id,
+            milestone_index,
+            verifier: verifier.clone(),
+            amount,
+        },
     );
 }
 
+/// `milestone_rejected` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneRejectedEvent {
+    pub round_id: u64,
+    pub milestone_index: u32,
+    pub rejector: Address,
+}
+
+impl VaultEvent for MilestoneRejectedEvent {
+    const NAME: &'static str = "milestone_rejected";
+}
+
 /// Emit when a milestone is rejected
 #[allow(dead_code)]
 pub fn emit_milestone_rejected(env: &Env, round_id: u64, milestone_index: u32, rejector: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "milestone_rejected"), round_id),
-        (milestone_index, rejector.clone()),
+    publish(
+        env,
+        MilestoneRejectedEvent {
+            round_id,
+            milestone_index,
+            rejector: rejector.clone(),
+        },
     );
 }
 
+/// `funding_released` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingReleasedEvent {
+    pub round_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub milestone_index: u32,
+    pub percentage_bps: u32,
+}
+
+impl VaultEvent for FundingReleasedEvent {
+    const NAME: &'static str = "funding_released";
+}
+
 /// Emit when funds are released from a funding round
 pub fn emit_funding_released(
     env: &Env,
@@ -1040,17 +2955,38 @@ pub fn emit_funding_released(
     milestone_index: u32,
     percentage_bps: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "funding_released"), round_id),
-        (recipient.clone(), amount, milestone_index, percentage_bps),
+    publish(
+        env,
+        FundingReleasedEvent {
+            round_id,
+            recipient: recipient.clone(),
+            amount,
+            milestone_index,
+            percentage_bps,
+        },
     );
 }
 
+/// `funding_round_cancelled` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingRoundCancelledEvent {
+    pub round_id: u64,
+    pub canceller: Address,
+}
+
+impl VaultEvent for FundingRoundCancelledEvent {
+    const NAME: &'static str = "funding_round_cancelled";
+}
+
 /// Emit when a funding round is cancelled
 pub fn emit_funding_round_cancelled(env: &Env, round_id: u64, canceller: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "funding_round_cancelled"), round_id),
-        canceller,
+    publish(
+        env,
+        FundingRoundCancelledEvent {
+            round_id,
+            canceller: canceller.clone(),
+        },
     );
 }
 
@@ -1058,6 +2994,20 @@ pub fn emit_funding_round_cancelled(env: &Env, round_id: u64, canceller: &Addres
 // Time-Weighted Voting Events
 // ============================================================================
 
+/// `tokens_locked` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensLockedEvent {
+    pub owner: Address,
+    pub amount: i128,
+    pub duration: u64,
+    pub power_multiplier_bps: u32,
+}
+
+impl VaultEvent for TokensLockedEvent {
+    const NAME: &'static str = "tokens_locked";
+}
+
 /// Emit when tokens are locked for voting power
 pub fn emit_tokens_locked(
     env: &Env,
@@ -1066,12 +3016,30 @@ pub fn emit_tokens_locked(
     duration: u64,
     power_multiplier_bps: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "tokens_locked"),),
-        (owner.clone(), amount, duration, power_multiplier_bps),
+    publish(
+        env,
+        TokensLockedEvent {
+            owner: owner.clone(),
+            amount,
+            duration,
+            power_multiplier_bps,
+        },
     );
 }
 
+/// `lock_extended` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockExtendedEvent {
+    pub owner: Address,
+    pub new_duration: u64,
+    pub power_multiplier_bps: u32,
+}
+
+impl VaultEvent for LockExtendedEvent {
+    const NAME: &'static str = "lock_extended";
+}
+
 /// Emit when a token lock is extended
 pub fn emit_lock_extended(
     env: &Env,
@@ -1079,25 +3047,61 @@ pub fn emit_lock_extended(
     new_duration: u64,
     power_multiplier_bps: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "lock_extended"),),
-        (owner.clone(), new_duration, power_multiplier_bps),
+    publish(
+        env,
+        LockExtendedEvent {
+            owner: owner.clone(),
+            new_duration,
+            power_multiplier_bps,
+        },
     );
 }
 
+/// `tokens_unlocked` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensUnlockedEvent {
+    pub owner: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for TokensUnlockedEvent {
+    const NAME: &'static str = "tokens_unlocked";
+}
+
 /// Emit when tokens are unlocked after lock period
 pub fn emit_tokens_unlocked(env: &Env, owner: &Address, amount: i128) {
-    env.events().publish(
-        (Symbol::new(env, "tokens_unlocked"),),
-        (owner.clone(), amount),
+    publish(
+        env,
+        TokensUnlockedEvent {
+            owner: owner.clone(),
+            amount,
+        },
     );
 }
 
+/// `early_unlock` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyUnlockEvent {
+    pub owner: Address,
+    pub returned_amount: i128,
+    pub penalty: i128,
+}
+
+impl VaultEvent for EarlyUnlockEvent {
+    const NAME: &'static str = "early_unlock";
+}
+
 /// Emit when tokens are unlocked early with penalty
 pub fn emit_early_unlock(env: &Env, owner: &Address, returned_amount: i128, penalty: i128) {
-    env.events().publish(
-        (Symbol::new(env, "early_unlock"),),
-        (owner.clone(), returned_amount, penalty),
+    publish(
+        env,
+        EarlyUnlockEvent {
+            owner: owner.clone(),
+            returned_amount,
+            penalty,
+        },
     );
 }
 
@@ -1105,58 +3109,174 @@ pub fn emit_early_unlock(env: &Env, owner: &Address, returned_amount: i128, pena
 // Recovery Events
 // ============================================================================
 
+/// `recovery_config` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryConfigUpdatedEvent {
+    pub admin: Address,
+}
+
+impl VaultEvent for RecoveryConfigUpdatedEvent {
+    const NAME: &'static str = "recovery_config";
+}
+
 /// Emit when recovery configuration is updated
 pub fn emit_recovery_config_updated(env: &Env, admin: &Address) {
-    env.events()
-        .publish((Symbol::new(env, "recovery_config"),), admin.clone());
+    publish(
+        env,
+        RecoveryConfigUpdatedEvent {
+            admin: admin.clone(),
+        },
+    );
+}
+
+/// `recovery_proposed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryProposedEvent {
+    pub proposal_id: u64,
+    pub new_threshold: u32,
+}
+
+impl VaultEvent for RecoveryProposedEvent {
+    const NAME: &'static str = "recovery_proposed";
 }
 
 /// Emit when a recovery proposal is created
 pub fn emit_recovery_proposed(env: &Env, proposal_id: u64, new_threshold: u32) {
-    env.events().publish(
-        (Symbol::new(env, "recovery_proposed"), proposal_id),
-        new_threshold,
+    publish(
+        env,
+        RecoveryProposedEvent {
+            proposal_id,
+            new_threshold,
+        },
     );
 }
 
+/// `recovery_approved` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryApprovedEvent {
+    pub proposal_id: u64,
+    pub guardian: Address,
+}
+
+impl VaultEvent for RecoveryApprovedEvent {
+    const NAME: &'static str = "recovery_approved";
+}
+
 /// Emit when a recovery proposal is approved
 pub fn emit_recovery_approved(env: &Env, proposal_id: u64, guardian: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "recovery_approved"), proposal_id),
-        guardian.clone(),
+    publish(
+        env,
+        RecoveryApprovedEvent {
+            proposal_id,
+            guardian: guardian.clone(),
+        },
     );
 }
 
+/// `recovery_executed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryExecutedEvent {
+    pub proposal_id: u64,
+}
+
+impl VaultEvent for RecoveryExecutedEvent {
+    const NAME: &'static str = "recovery_executed";
+}
+
 /// Emit when a recovery proposal is executed
 pub fn emit_recovery_executed(env: &Env, proposal_id: u64) {
-    env.events()
-        .publish((Symbol::new(env, "recovery_executed"), proposal_id), ());
+    publish(env, RecoveryExecutedEvent { proposal_id });
+}
+
+/// `recovery_cancelled` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryCancelledEvent {
+    pub proposal_id: u64,
+    pub canceller: Address,
+}
+
+impl VaultEvent for RecoveryCancelledEvent {
+    const NAME: &'static str = "recovery_cancelled";
 }
 
 /// Emit when a recovery proposal is cancelled
 pub fn emit_recovery_cancelled(env: &Env, proposal_id: u64, canceller: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "recovery_cancelled"), proposal_id),
-        canceller.clone(),
+    publish(
+        env,
+        RecoveryCancelledEvent {
+            proposal_id,
+            canceller: canceller.clone(),
+        },
     );
 }
 
+/// `funding_round_completed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingRoundCompletedEvent {
+    pub round_id: u64,
+    pub total_released: i128,
+}
+
+impl VaultEvent for FundingRoundCompletedEvent {
+    const NAME: &'static str = "funding_round_completed";
+}
+
 /// Emit when a funding round is completed
 pub fn emit_funding_round_completed(env: &Env, round_id: u64, total_released: i128) {
-    env.events().publish(
-        (Symbol::new(env, "funding_round_completed"), round_id),
-        total_released,
+    publish(
+        env,
+        FundingRoundCompletedEvent {
+            round_id,
+            total_released,
+        },
     );
 }
 
+/// `fee_structure_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeStructureUpdatedEvent {
+    pub admin: Address,
+    pub enabled: bool,
+}
+
+impl VaultEvent for FeeStructureUpdatedEvent {
+    const NAME: &'static str = "fee_structure_updated";
+}
+
 /// Emit when fee structure configuration is updated
 pub fn emit_fee_structure_updated(env: &Env, admin: &Address, enabled: bool) {
-    env.events().publish(
-        (Symbol::new(env, "fee_structure_updated"),),
-        (admin.clone(), enabled),
+    publish(
+        env,
+        FeeStructureUpdatedEvent {
+            admin: admin.clone(),
+            enabled,
+        },
     );
 }
 
+/// `fee_collected` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCollectedEvent {
+    pub user: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub fee_bps: u32,
+    pub reputation_discount_applied: bool,
+}
+
+impl VaultEvent for FeeCollectedEvent {
+    const NAME: &'static str = "fee_collected";
+}
+
 /// Emit when a fee is collected from a transaction
 pub fn emit_fee_collected(
     env: &Env,
@@ -1167,22 +3287,53 @@ pub fn emit_fee_collected(
     fee_bps: u32,
     reputation_discount_applied: bool,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "fee_collected"),),
-        (
-            user.clone(),
-            token.clone(),
+    publish(
+        env,
+        FeeCollectedEvent {
+            user: user.clone(),
+            token: token.clone(),
             amount,
             fee,
             fee_bps,
             reputation_discount_applied,
-        ),
+        },
     );
 }
 
+/// `dex_cfg_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DexConfigUpdatedEvent {
+    pub admin: Address,
+}
+
+impl VaultEvent for DexConfigUpdatedEvent {
+    const NAME: &'static str = "dex_cfg_updated";
+}
+
 pub fn emit_dex_config_updated(env: &Env, admin: &Address) {
-    env.events()
-        .publish((Symbol::new(env, "dex_cfg_updated"),), admin.clone());
+    publish(
+        env,
+        DexConfigUpdatedEvent {
+            admin: admin.clone(),
+        },
+    );
+}
+
+/// `swap_executed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapExecutedEvent {
+    pub proposal_id: u64,
+    pub dex: Address,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+}
+
+impl VaultEvent for SwapExecutedEvent {
+    const NAME: &'static str = "swap_executed";
 }
 
 /// Emit event when a swap is executed
@@ -1195,19 +3346,36 @@ pub fn emit_swap_executed(
     amount_in: i128,
     amount_out: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "swap_executed"),),
-        (
+    publish(
+        env,
+        SwapExecutedEvent {
             proposal_id,
-            dex.clone(),
-            token_in.clone(),
-            token_out.clone(),
+            dex: dex.clone(),
+            token_in: token_in.clone(),
+            token_out: token_out.clone(),
             amount_in,
             amount_out,
-        ),
+        },
     );
 }
 
+/// `swap_balances` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapBalancesEvent {
+    pub proposal_id: u64,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub token_in_before: i128,
+    pub token_in_after: i128,
+    pub token_out_before: i128,
+    pub token_out_after: i128,
+}
+
+impl VaultEvent for SwapBalancesEvent {
+    const NAME: &'static str = "swap_balances";
+}
+
 /// Emit event recording the vault's token balances immediately before and after
 /// a swap's on-chain settlement (issue #1441), so off-chain observers can verify
 /// the swap moved exactly the reported amounts.
@@ -1222,20 +3390,37 @@ pub fn emit_swap_balances(
     token_out_before: i128,
     token_out_after: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "swap_balances"),),
-        (
+    publish(
+        env,
+        SwapBalancesEvent {
             proposal_id,
-            token_in.clone(),
-            token_out.clone(),
+            token_in: token_in.clone(),
+            token_out: token_out.clone(),
             token_in_before,
             token_in_after,
             token_out_before,
             token_out_after,
-        ),
+        },
     );
 }
 
+/// `liquidity_added` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiquidityAddedEvent {
+    pub proposal_id: u64,
+    pub dex: Address,
+    pub token_a: Address,
+    pub token_b: Address,
+    pub amount_a: i128,
+    pub amount_b: i128,
+    pub lp_tokens: i128,
+}
+
+impl VaultEvent for LiquidityAddedEvent {
+    const NAME: &'static str = "liquidity_added";
+}
+
 /// Emit event when liquidity is added
 pub fn emit_liquidity_added(
     env: &Env,
@@ -1247,28 +3432,61 @@ pub fn emit_liquidity_added(
     amount_b: i128,
     lp_tokens: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "liquidity_added"),),
-        (
+    publish(
+        env,
+        LiquidityAddedEvent {
             proposal_id,
-            dex.clone(),
-            token_a.clone(),
-            token_b.clone(),
+            dex: dex.clone(),
+            token_a: token_a.clone(),
+            token_b: token_b.clone(),
             amount_a,
             amount_b,
             lp_tokens,
-        ),
+        },
     );
 }
 
+/// `lp_unstaked` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LpUnstakedEvent {
+    pub proposal_id: u64,
+    pub farm: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for LpUnstakedEvent {
+    const NAME: &'static str = "lp_unstaked";
+}
+
 /// Emit event when LP tokens are unstaked
 pub fn emit_lp_unstaked(env: &Env, proposal_id: u64, farm: &Address, amount: i128) {
-    env.events().publish(
-        (Symbol::new(env, "lp_unstaked"),),
-        (proposal_id, farm.clone(), amount),
+    publish(
+        env,
+        LpUnstakedEvent {
+            proposal_id,
+            farm: farm.clone(),
+            amount,
+        },
     );
 }
 
+/// `stream_created` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamCreatedEvent {
+    pub stream_id: u64,
+    pub sender: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub total_amount: i128,
+    pub rate: i128,
+}
+
+impl VaultEvent for StreamCreatedEvent {
+    const NAME: &'static str = "stream_created";
+}
+
 pub fn emit_stream_created(
     env: &Env,
     stream_id: u64,
@@ -1278,18 +3496,33 @@ pub fn emit_stream_created(
     total_amount: i128,
     rate: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "stream_created"), stream_id),
-        (
-            sender.clone(),
-            recipient.clone(),
-            token.clone(),
+    publish(
+        env,
+        StreamCreatedEvent {
+            stream_id,
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            token: token.clone(),
             total_amount,
             rate,
-        ),
+        },
     );
 }
 
+/// `stream_rate_adj` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamRateAdjustedEvent {
+    pub stream_id: u64,
+    pub old_rate: i128,
+    pub new_rate: i128,
+    pub adjusted_by: Address,
+}
+
+impl VaultEvent for StreamRateAdjustedEvent {
+    const NAME: &'static str = "stream_rate_adj";
+}
+
 /// Emit when a stream rate is adjusted
 pub fn emit_stream_rate_adjusted(
     env: &Env,
@@ -1298,30 +3531,83 @@ pub fn emit_stream_rate_adjusted(
     new_rate: i128,
     adjusted_by: &Address,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "stream_rate_adj"), stream_id),
-        (old_rate, new_rate, adjusted_by.clone()),
+    publish(
+        env,
+        StreamRateAdjustedEvent {
+            stream_id,
+            old_rate,
+            new_rate,
+            adjusted_by: adjusted_by.clone(),
+        },
     );
 }
 
+/// `stream_status` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamStatusUpdatedEvent {
+    pub stream_id: u64,
+    pub status: u32,
+    pub updated_by: Address,
+}
+
+impl VaultEvent for StreamStatusUpdatedEvent {
+    const NAME: &'static str = "stream_status";
+}
+
 /// Emit when a stream status is updated (paused, resumed, or cancelled)
 #[allow(dead_code)]
 pub fn emit_stream_status_updated(env: &Env, stream_id: u64, status: u32, updated_by: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "stream_status"), stream_id),
-        (status, updated_by.clone()),
+    publish(
+        env,
+        StreamStatusUpdatedEvent {
+            stream_id,
+            status,
+            updated_by: updated_by.clone(),
+        },
     );
 }
 
+/// `stream_claimed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamClaimedEvent {
+    pub stream_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for StreamClaimedEvent {
+    const NAME: &'static str = "stream_claimed";
+}
+
 /// Emit when tokens are claimed from a stream
 #[allow(dead_code)]
 pub fn emit_stream_claimed(env: &Env, stream_id: u64, recipient: &Address, amount: i128) {
-    env.events().publish(
-        (Symbol::new(env, "stream_claimed"), stream_id),
-        (recipient.clone(), amount),
+    publish(
+        env,
+        StreamClaimedEvent {
+            stream_id,
+            recipient: recipient.clone(),
+            amount,
+        },
     );
 }
 
+/// `stream_auto_done` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamAutoCompletedEvent {
+    pub stream_id: u64,
+    pub reason: Symbol,
+    pub available: i128,
+    pub required: i128,
+}
+
+impl VaultEvent for StreamAutoCompletedEvent {
+    const NAME: &'static str = "stream_auto_done";
+}
+
 /// Emit when a stream is auto-completed because the vault balance can no
 /// longer sustain it (Issue #1359).
 ///
@@ -1333,81 +3619,314 @@ pub fn emit_stream_auto_completed(
     available: i128,
     required: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "stream_auto_done"), stream_id),
-        (reason, available, required),
+    publish(
+        env,
+        StreamAutoCompletedEvent {
+            stream_id,
+            reason,
+            available,
+            required,
+        },
     );
 }
 
+/// `cv_proposed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrossVaultProposedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub action_count: u32,
+}
+
+impl VaultEvent for CrossVaultProposedEvent {
+    const NAME: &'static str = "cv_proposed";
+}
+
 pub fn emit_cross_vault_proposed(
     env: &Env,
     proposal_id: u64,
     proposer: &Address,
     action_count: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "cv_proposed"), proposal_id),
-        (proposer.clone(), action_count),
+    publish(
+        env,
+        CrossVaultProposedEvent {
+            proposal_id,
+            proposer: proposer.clone(),
+            action_count,
+        },
     );
 }
 
+/// `cv_executed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrossVaultExecutedEvent {
+    pub proposal_id: u64,
+    pub executor: Address,
+    pub success_count: u32,
+}
+
+impl VaultEvent for CrossVaultExecutedEvent {
+    const NAME: &'static str = "cv_executed";
+}
+
 pub fn emit_cross_vault_executed(
     env: &Env,
     proposal_id: u64,
     executor: &Address,
     success_count: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "cv_executed"), proposal_id),
-        (executor.clone(), success_count),
+    publish(
+        env,
+        CrossVaultExecutedEvent {
+            proposal_id,
+            executor: executor.clone(),
+            success_count,
+        },
     );
 }
 
+/// `cv_config_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrossVaultConfigSetEvent {
+    pub admin: Address,
+}
+
+impl VaultEvent for CrossVaultConfigSetEvent {
+    const NAME: &'static str = "cv_config_set";
+}
+
 pub fn emit_cross_vault_config_set(env: &Env, admin: &Address) {
-    env.events()
-        .publish((Symbol::new(env, "cv_config_set"),), admin.clone());
+    publish(
+        env,
+        CrossVaultConfigSetEvent {
+            admin: admin.clone(),
+        },
+    );
+}
+
+/// `permission_granted` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermissionGrantedEvent {
+    pub admin: Address,
+    pub target: Address,
+    pub permission: u32,
+}
+
+impl VaultEvent for PermissionGrantedEvent {
+    const NAME: &'static str = "permission_granted";
 }
 
 pub fn emit_permission_granted(env: &Env, admin: &Address, target: &Address, permission: u32) {
-    env.events().publish(
-        (Symbol::new(env, "permission_granted"),),
-        (admin.clone(), target.clone(), permission),
+    publish(
+        env,
+        PermissionGrantedEvent {
+            admin: admin.clone(),
+            target: target.clone(),
+            permission,
+        },
     );
 }
 
+/// `permission_revoked` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermissionRevokedEvent {
+    pub admin: Address,
+    pub target: Address,
+    pub permission: u32,
+}
+
+impl VaultEvent for PermissionRevokedEvent {
+    const NAME: &'static str = "permission_revoked";
+}
+
 pub fn emit_permission_revoked(env: &Env, admin: &Address, target: &Address, permission: u32) {
-    env.events().publish(
-        (Symbol::new(env, "permission_revoked"),),
-        (admin.clone(), target.clone(), permission),
+    publish(
+        env,
+        PermissionRevokedEvent {
+            admin: admin.clone(),
+            target: target.clone(),
+            permission,
+        },
+    );
+}
+
+/// `custom_role_defined` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomRoleDefinedEvent {
+    pub admin: Address,
+    pub name: Symbol,
+    pub permissions: u32,
+}
+
+impl VaultEvent for CustomRoleDefinedEvent {
+    const NAME: &'static str = "custom_role_defined";
+}
+
+pub fn emit_custom_role_defined(env: &Env, admin: &Address, name: &Symbol, permissions: u32) {
+    publish(
+        env,
+        CustomRoleDefinedEvent {
+            admin: admin.clone(),
+            name: name.clone(),
+            permissions,
+        },
+    );
+}
+
+/// `custom_role_removed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomRoleRemovedEvent {
+    pub admin: Address,
+    pub name: Symbol,
+}
+
+impl VaultEvent for CustomRoleRemovedEvent {
+    const NAME: &'static str = "custom_role_removed";
+}
+
+pub fn emit_custom_role_removed(env: &Env, admin: &Address, name: &Symbol) {
+    publish(
+        env,
+        CustomRoleRemovedEvent {
+            admin: admin.clone(),
+            name: name.clone(),
+        },
+    );
+}
+
+/// `custom_role_assignment` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomRoleAssignmentEvent {
+    pub admin: Address,
+    pub target: Address,
+    pub name: Symbol,
+    pub assigned: bool,
+}
+
+impl VaultEvent for CustomRoleAssignmentEvent {
+    const NAME: &'static str = "custom_role_assignment";
+}
+
+/// Emit when a custom role is assigned to (`assigned = true`) or taken from
+/// an address
+pub fn emit_custom_role_assignment(
+    env: &Env,
+    admin: &Address,
+    target: &Address,
+    name: &Symbol,
+    assigned: bool,
+) {
+    publish(
+        env,
+        CustomRoleAssignmentEvent {
+            admin: admin.clone(),
+            target: target.clone(),
+            name: name.clone(),
+            assigned,
+        },
     );
 }
 
+/// `permission_delegated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermissionDelegatedEvent {
+    pub delegator: Address,
+    pub delegatee: Address,
+    pub permission: u32,
+}
+
+impl VaultEvent for PermissionDelegatedEvent {
+    const NAME: &'static str = "permission_delegated";
+}
+
 pub fn emit_permission_delegated(
     env: &Env,
     delegator: &Address,
     delegatee: &Address,
     permission: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "permission_delegated"),),
-        (delegator.clone(), delegatee.clone(), permission),
+    publish(
+        env,
+        PermissionDelegatedEvent {
+            delegator: delegator.clone(),
+            delegatee: delegatee.clone(),
+            permission,
+        },
     );
 }
 
+/// `dispute_raised` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRaisedEvent {
+    pub dispute_id: u64,
+    pub proposal_id: u64,
+    pub disputer: Address,
+}
+
+impl VaultEvent for DisputeRaisedEvent {
+    const NAME: &'static str = "dispute_raised";
+}
+
 pub fn emit_dispute_raised(env: &Env, dispute_id: u64, proposal_id: u64, disputer: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "dispute_raised"), dispute_id),
-        (proposal_id, disputer.clone()),
+    publish(
+        env,
+        DisputeRaisedEvent {
+            dispute_id,
+            proposal_id,
+            disputer: disputer.clone(),
+        },
     );
 }
 
+/// `dispute_resolved` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeResolvedEvent {
+    pub dispute_id: u64,
+    pub admin: Address,
+    pub resolution: u32,
+}
+
+impl VaultEvent for DisputeResolvedEvent {
+    const NAME: &'static str = "dispute_resolved";
+}
+
 pub fn emit_dispute_resolved(env: &Env, dispute_id: u64, admin: &Address, resolution: u32) {
-    env.events().publish(
-        (Symbol::new(env, "dispute_resolved"), dispute_id),
-        (admin.clone(), resolution),
+    publish(
+        env,
+        DisputeResolvedEvent {
+            dispute_id,
+            admin: admin.clone(),
+            resolution,
+        },
     );
 }
 
+/// `dispute_bond_posted` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeBondPostedEvent {
+    pub dispute_id: u64,
+    pub disputer: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for DisputeBondPostedEvent {
+    const NAME: &'static str = "dispute_bond_posted";
+}
+
 /// Emit when a dispute bond is posted
 pub fn emit_dispute_bond_posted(
     env: &Env,
@@ -1416,20 +3935,56 @@ pub fn emit_dispute_bond_posted(
     token: &Address,
     amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "dispute_bond_posted"), dispute_id),
-        (disputer.clone(), token.clone(), amount),
+    publish(
+        env,
+        DisputeBondPostedEvent {
+            dispute_id,
+            disputer: disputer.clone(),
+            token: token.clone(),
+            amount,
+        },
     );
 }
 
+/// `dispute_outcome` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeOutcomeEvent {
+    pub dispute_id: u64,
+    pub arbitrator: Address,
+    pub outcome: u32,
+}
+
+impl VaultEvent for DisputeOutcomeEvent {
+    const NAME: &'static str = "dispute_outcome";
+}
+
 /// Emit when a dispute is resolved with outcome
 pub fn emit_dispute_outcome(env: &Env, dispute_id: u64, arbitrator: &Address, outcome: u32) {
-    env.events().publish(
-        (Symbol::new(env, "dispute_outcome"), dispute_id),
-        (arbitrator.clone(), outcome),
+    publish(
+        env,
+        DisputeOutcomeEvent {
+            dispute_id,
+            arbitrator: arbitrator.clone(),
+            outcome,
+        },
     );
 }
 
+/// `dispute_bond_slashed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeBondSlashedEvent {
+    pub dispute_id: u64,
+    pub token: Address,
+    pub slashed_amount: i128,
+    pub treasury_amount: i128,
+}
+
+impl VaultEvent for DisputeBondSlashedEvent {
+    const NAME: &'static str = "dispute_bond_slashed";
+}
+
 /// Emit when a dispute bond is slashed
 pub fn emit_dispute_bond_slashed(
     env: &Env,
@@ -1438,17 +3993,39 @@ pub fn emit_dispute_bond_slashed(
     slashed_amount: i128,
     treasury_amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "dispute_bond_slashed"), dispute_id),
-        (token.clone(), slashed_amount, treasury_amount),
+    publish(
+        env,
+        DisputeBondSlashedEvent {
+            dispute_id,
+            token: token.clone(),
+            slashed_amount,
+            treasury_amount,
+        },
     );
 }
 
+/// `dispute_bond_returned` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeBondReturnedEvent {
+    pub dispute_id: u64,
+    pub token: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for DisputeBondReturnedEvent {
+    const NAME: &'static str = "dispute_bond_returned";
+}
+
 /// Emit when a dispute bond is returned
 pub fn emit_dispute_bond_returned(env: &Env, dispute_id: u64, token: &Address, amount: i128) {
-    env.events().publish(
-        (Symbol::new(env, "dispute_bond_returned"), dispute_id),
-        (token.clone(), amount),
+    publish(
+        env,
+        DisputeBondReturnedEvent {
+            dispute_id,
+            token: token.clone(),
+            amount,
+        },
     );
 }
 
@@ -1456,24 +4033,75 @@ pub fn emit_dispute_bond_returned(env: &Env, dispute_id: u64, token: &Address, a
 // Bridge Events (feature/cross-chain-bridge)
 // ============================================================================
 
+/// `bridge_proposed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeProposedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub asset_count: u32,
+}
+
+impl VaultEvent for BridgeProposedEvent {
+    const NAME: &'static str = "bridge_proposed";
+}
+
 /// Emit when a bridge transfer proposal is created
 pub fn emit_bridge_proposed(env: &Env, proposal_id: u64, proposer: &Address, asset_count: u32) {
-    env.events().publish(
-        (Symbol::new(env, "bridge_proposed"), proposal_id),
-        (proposer.clone(), asset_count),
+    publish(
+        env,
+        BridgeProposedEvent {
+            proposal_id,
+            proposer: proposer.clone(),
+            asset_count,
+        },
     );
 }
 
+/// `bridge_executed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeExecutedEvent {
+    pub proposal_id: u64,
+    pub executor: Address,
+    pub success_count: u32,
+}
+
+impl VaultEvent for BridgeExecutedEvent {
+    const NAME: &'static str = "bridge_executed";
+}
+
 /// Emit when a bridge proposal is executed
 pub fn emit_bridge_executed(env: &Env, proposal_id: u64, executor: &Address, success_count: u32) {
-    env.events().publish(
-        (Symbol::new(env, "bridge_executed"), proposal_id),
-        (executor.clone(), success_count),
+    publish(
+        env,
+        BridgeExecutedEvent {
+            proposal_id,
+            executor: executor.clone(),
+            success_count,
+        },
     );
 }
 
-/// Emit when a bridge to vault is initiated
-pub fn emit_bridge_to_vault_initiated(
+/// `bridge_to_vault_initiated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeToVaultInitiatedEvent {
+    pub bridge_id: BytesN<32>,
+    pub source_vault: Address,
+    pub target_vault: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub min_received: i128,
+    pub deadline_ledger: u64,
+}
+
+impl VaultEvent for BridgeToVaultInitiatedEvent {
+    const NAME: &'static str = "bridge_to_vault_initiated";
+}
+
+/// Emit when a bridge to vault is initiated
+pub fn emit_bridge_to_vault_initiated(
     env: &Env,
     bridge_id: &soroban_sdk::BytesN<32>,
     source_vault: &Address,
@@ -1483,22 +4111,33 @@ pub fn emit_bridge_to_vault_initiated(
     min_received: i128,
     deadline_ledger: u64,
 ) {
-    env.events().publish(
-        (
-            Symbol::new(env, "bridge_to_vault_initiated"),
-            bridge_id.clone(),
-        ),
-        (
-            source_vault.clone(),
-            target_vault.clone(),
-            token.clone(),
+    publish(
+        env,
+        BridgeToVaultInitiatedEvent {
+            bridge_id: bridge_id.clone(),
+            source_vault: source_vault.clone(),
+            target_vault: target_vault.clone(),
+            token: token.clone(),
             amount,
             min_received,
             deadline_ledger,
-        ),
+        },
     );
 }
 
+/// `bridge_receipt_confirmed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeReceiptConfirmedEvent {
+    pub bridge_id: BytesN<32>,
+    pub target_vault: Address,
+    pub actual_amount: i128,
+}
+
+impl VaultEvent for BridgeReceiptConfirmedEvent {
+    const NAME: &'static str = "bridge_receipt_confirmed";
+}
+
 /// Emit when a bridge receipt is confirmed
 pub fn emit_bridge_receipt_confirmed(
     env: &Env,
@@ -1506,15 +4145,30 @@ pub fn emit_bridge_receipt_confirmed(
     target_vault: &Address,
     actual_amount: i128,
 ) {
-    env.events().publish(
-        (
-            Symbol::new(env, "bridge_receipt_confirmed"),
-            bridge_id.clone(),
-        ),
-        (target_vault.clone(), actual_amount),
+    publish(
+        env,
+        BridgeReceiptConfirmedEvent {
+            bridge_id: bridge_id.clone(),
+            target_vault: target_vault.clone(),
+            actual_amount,
+        },
     );
 }
 
+/// `bridge_slippage_rejected` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeSlippageRejectedEvent {
+    pub bridge_id: BytesN<32>,
+    pub target_vault: Address,
+    pub actual_amount: i128,
+    pub min_received: i128,
+}
+
+impl VaultEvent for BridgeSlippageRejectedEvent {
+    const NAME: &'static str = "bridge_slippage_rejected";
+}
+
 /// Emit when a bridge is rejected due to slippage
 pub fn emit_bridge_slippage_rejected(
     env: &Env,
@@ -1523,15 +4177,30 @@ pub fn emit_bridge_slippage_rejected(
     actual_amount: i128,
     min_received: i128,
 ) {
-    env.events().publish(
-        (
-            Symbol::new(env, "bridge_slippage_rejected"),
-            bridge_id.clone(),
-        ),
-        (target_vault.clone(), actual_amount, min_received),
+    publish(
+        env,
+        BridgeSlippageRejectedEvent {
+            bridge_id: bridge_id.clone(),
+            target_vault: target_vault.clone(),
+            actual_amount,
+            min_received,
+        },
     );
 }
 
+/// `bridge_funds_returned` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeFundsReturnedEvent {
+    pub bridge_id: BytesN<32>,
+    pub source_vault: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for BridgeFundsReturnedEvent {
+    const NAME: &'static str = "bridge_funds_returned";
+}
+
 /// Emit when bridge funds are returned to source vault
 pub fn emit_bridge_funds_returned(
     env: &Env,
@@ -1539,32 +4208,95 @@ pub fn emit_bridge_funds_returned(
     source_vault: &Address,
     amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "bridge_funds_returned"), bridge_id.clone()),
-        (source_vault.clone(), amount),
+    publish(
+        env,
+        BridgeFundsReturnedEvent {
+            bridge_id: bridge_id.clone(),
+            source_vault: source_vault.clone(),
+            amount,
+        },
     );
 }
 
+/// `bridge_cfg_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeConfigUpdatedEvent {
+    pub admin: Address,
+}
+
+impl VaultEvent for BridgeConfigUpdatedEvent {
+    const NAME: &'static str = "bridge_cfg_updated";
+}
+
 /// Emit when bridge configuration is updated
 pub fn emit_bridge_config_updated(env: &Env, admin: &Address) {
-    env.events()
-        .publish((Symbol::new(env, "bridge_cfg_updated"),), admin.clone());
+    publish(
+        env,
+        BridgeConfigUpdatedEvent {
+            admin: admin.clone(),
+        },
+    );
+}
+
+/// `rep_config_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationConfigUpdatedEvent {
+    pub admin: Address,
+}
+
+impl VaultEvent for ReputationConfigUpdatedEvent {
+    const NAME: &'static str = "rep_config_updated";
 }
 
 /// Emit when reputation config is updated
 pub fn emit_reputation_config_updated(env: &Env, admin: &Address) {
-    env.events()
-        .publish((Symbol::new(env, "rep_config_updated"),), admin.clone());
+    publish(
+        env,
+        ReputationConfigUpdatedEvent {
+            admin: admin.clone(),
+        },
+    );
+}
+
+/// `comment_deleted` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommentDeletedEvent {
+    pub comment_id: u64,
+    pub caller: Address,
+}
+
+impl VaultEvent for CommentDeletedEvent {
+    const NAME: &'static str = "comment_deleted";
 }
 
 /// Emit when a comment is deleted (soft delete)
 pub fn emit_comment_deleted(env: &Env, comment_id: u64, caller: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "comment_deleted"), comment_id),
-        caller.clone(),
+    publish(
+        env,
+        CommentDeletedEvent {
+            comment_id,
+            caller: caller.clone(),
+        },
     );
 }
 
+/// `metrics_bucket_upd` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetricsBucketUpdatedEvent {
+    pub week: u64,
+    pub executed: u64,
+    pub rejected: u64,
+    pub expired: u64,
+}
+
+impl VaultEvent for MetricsBucketUpdatedEvent {
+    const NAME: &'static str = "metrics_bucket_upd";
+}
+
 /// Emit when metrics bucket is updated with current week stats
 pub fn emit_metrics_bucket_updated(
     env: &Env,
@@ -1573,33 +4305,87 @@ pub fn emit_metrics_bucket_updated(
     rejected: u64,
     expired: u64,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "metrics_bucket_upd"), week),
-        (executed, rejected, expired),
+    publish(
+        env,
+        MetricsBucketUpdatedEvent {
+            week,
+            executed,
+            rejected,
+            expired,
+        },
     );
 }
 
+/// `vote_committed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCommittedEvent {
+    pub proposal_id: u64,
+    pub voter: Address,
+}
+
+impl VaultEvent for VoteCommittedEvent {
+    const NAME: &'static str = "vote_committed";
+}
+
 /// Emit when a signer commits a vote in the commit-reveal scheme.
 pub fn emit_vote_committed(env: &Env, proposal_id: u64, voter: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "vote_committed"), proposal_id),
-        voter.clone(),
+    publish(
+        env,
+        VoteCommittedEvent {
+            proposal_id,
+            voter: voter.clone(),
+        },
     );
 }
 
+/// `vote_revealed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteRevealedEvent {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub approve: bool,
+}
+
+impl VaultEvent for VoteRevealedEvent {
+    const NAME: &'static str = "vote_revealed";
+}
+
 /// Emit when a signer reveals their committed vote.
 pub fn emit_vote_revealed(env: &Env, proposal_id: u64, voter: &Address, approve: bool) {
-    env.events().publish(
-        (Symbol::new(env, "vote_revealed"), proposal_id),
-        (voter.clone(), approve),
+    publish(
+        env,
+        VoteRevealedEvent {
+            proposal_id,
+            voter: voter.clone(),
+            approve,
+        },
     );
 }
 
+/// `private_tally` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrivateTallyComputedEvent {
+    pub proposal_id: u64,
+    pub approvals: u32,
+    pub abstentions: u32,
+}
+
+impl VaultEvent for PrivateTallyComputedEvent {
+    const NAME: &'static str = "private_tally";
+}
+
 /// Emit when the private-vote tally is computed after the reveal deadline.
 pub fn emit_private_tally_computed(env: &Env, proposal_id: u64, approvals: u32, abstentions: u32) {
-    env.events().publish(
-        (Symbol::new(env, "private_tally"), proposal_id),
-        (approvals, abstentions),
+    publish(
+        env,
+        PrivateTallyComputedEvent {
+            proposal_id,
+            approvals,
+            abstentions,
+        },
     );
 }
 
@@ -1607,10 +4393,25 @@ pub fn emit_private_tally_computed(env: &Env, proposal_id: u64, approvals: u32,
 // Emergency Pause Events (#1084)
 // ============================================================================
 
+/// `vault_paused` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultPausedEvent {
+    pub paused_by: Address,
+    pub cause: Symbol,
+}
+
+impl VaultEvent for VaultPausedEvent {
+    const NAME: &'static str = "vault_paused";
+}
+
 pub fn emit_vault_paused(env: &Env, paused_by: &Address, cause: &soroban_sdk::Symbol) {
-    env.events().publish(
-        (Symbol::new(env, "vault_paused"),),
-        (paused_by.clone(), cause.clone()),
+    publish(
+        env,
+        VaultPausedEvent {
+            paused_by: paused_by.clone(),
+            cause: cause.clone(),
+        },
     );
 }
 
@@ -1618,6 +4419,20 @@ pub fn emit_vault_paused(env: &Env, paused_by: &Address, cause: &soroban_sdk::Sy
 // Scoped Delegation Events (#1082)
 // ============================================================================
 
+/// `scoped_deleg_created` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScopedDelegationCreatedEvent {
+    pub id: u64,
+    pub delegator: Address,
+    pub delegate: Address,
+    pub max_amount: i128,
+}
+
+impl VaultEvent for ScopedDelegationCreatedEvent {
+    const NAME: &'static str = "scoped_deleg_created";
+}
+
 pub fn emit_scoped_delegation_created(
     env: &Env,
     id: u64,
@@ -1625,19 +4440,53 @@ pub fn emit_scoped_delegation_created(
     delegate: &Address,
     max_amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "scoped_deleg_created"), id),
-        (delegator.clone(), delegate.clone(), max_amount),
+    publish(
+        env,
+        ScopedDelegationCreatedEvent {
+            id,
+            delegator: delegator.clone(),
+            delegate: delegate.clone(),
+            max_amount,
+        },
     );
 }
 
+/// `scoped_deleg_revoked` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScopedDelegationRevokedEvent {
+    pub id: u64,
+    pub revoker: Address,
+}
+
+impl VaultEvent for ScopedDelegationRevokedEvent {
+    const NAME: &'static str = "scoped_deleg_revoked";
+}
+
 pub fn emit_scoped_delegation_revoked(env: &Env, id: u64, revoker: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "scoped_deleg_revoked"), id),
-        revoker.clone(),
+    publish(
+        env,
+        ScopedDelegationRevokedEvent {
+            id,
+            revoker: revoker.clone(),
+        },
     );
 }
 
+/// `delegate_voted` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateVotedEvent {
+    pub delegation_id: u64,
+    pub proposal_id: u64,
+    pub delegate: Address,
+    pub approve: bool,
+}
+
+impl VaultEvent for DelegateVotedEvent {
+    const NAME: &'static str = "delegate_voted";
+}
+
 pub fn emit_delegate_voted(
     env: &Env,
     delegation_id: u64,
@@ -1645,9 +4494,14 @@ pub fn emit_delegate_voted(
     delegate: &Address,
     approve: bool,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "delegate_voted"), delegation_id),
-        (proposal_id, delegate.clone(), approve),
+    publish(
+        env,
+        DelegateVotedEvent {
+            delegation_id,
+            proposal_id,
+            delegate: delegate.clone(),
+            approve,
+        },
     );
 }
 
@@ -1655,42 +4509,207 @@ pub fn emit_delegate_voted(
 // Balance Snapshot Events (#1080)
 // ============================================================================
 
+/// `snapshot_taken` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotTakenEvent {
+    pub ledger: u64,
+    pub token_count: u32,
+}
+
+impl VaultEvent for SnapshotTakenEvent {
+    const NAME: &'static str = "snapshot_taken";
+}
+
 pub fn emit_snapshot_taken(env: &Env, ledger: u64, token_count: u32) {
-    env.events()
-        .publish((Symbol::new(env, "snapshot_taken"),), (ledger, token_count));
+    publish(
+        env,
+        SnapshotTakenEvent {
+            ledger,
+            token_count,
+        },
+    );
 }
 
 // ============================================================================
 // Governance Proposal Events (#1068)
 // ============================================================================
 
+/// `gov_proposed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovProposalCreatedEvent {
+    pub id: u64,
+    pub proposer: Address,
+    pub param: u32,
+}
+
+impl VaultEvent for GovProposalCreatedEvent {
+    const NAME: &'static str = "gov_proposed";
+}
+
 pub fn emit_gov_proposal_created(env: &Env, id: u64, proposer: &Address, param: u32) {
-    env.events().publish(
-        (Symbol::new(env, "gov_proposed"), id),
-        (proposer.clone(), param),
+    publish(
+        env,
+        GovProposalCreatedEvent {
+            id,
+            proposer: proposer.clone(),
+            param,
+        },
     );
 }
 
+/// `gov_approved` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovProposalApprovedEvent {
+    pub id: u64,
+    pub voter: Address,
+    pub count: u32,
+}
+
+impl VaultEvent for GovProposalApprovedEvent {
+    const NAME: &'static str = "gov_approved";
+}
+
 pub fn emit_gov_proposal_approved(env: &Env, id: u64, voter: &Address, count: u32) {
-    env.events().publish(
-        (Symbol::new(env, "gov_approved"), id),
-        (voter.clone(), count),
+    publish(
+        env,
+        GovProposalApprovedEvent {
+            id,
+            voter: voter.clone(),
+            count,
+        },
     );
 }
 
+/// `gov_executed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovProposalExecutedEvent {
+    pub id: u64,
+    pub param: u32,
+    pub new_value: i128,
+}
+
+impl VaultEvent for GovProposalExecutedEvent {
+    const NAME: &'static str = "gov_executed";
+}
+
 pub fn emit_gov_proposal_executed(env: &Env, id: u64, param: u32, new_value: i128) {
-    env.events()
-        .publish((Symbol::new(env, "gov_executed"), id), (param, new_value));
+    publish(
+        env,
+        GovProposalExecutedEvent {
+            id,
+            param,
+            new_value,
+        },
+    );
+}
+
+/// `param_change_proposed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParamChangeProposedEvent {
+    pub id: u64,
+    pub proposer: Address,
+    pub change_count: u32,
+    pub timelock_ledgers: u64,
+}
+
+impl VaultEvent for ParamChangeProposedEvent {
+    const NAME: &'static str = "param_change_proposed";
+}
+
+pub fn emit_param_change_proposed(
+    env: &Env,
+    id: u64,
+    proposer: &Address,
+    change_count: u32,
+    timelock_ledgers: u64,
+) {
+    publish(
+        env,
+        ParamChangeProposedEvent {
+            id,
+            proposer: proposer.clone(),
+            change_count,
+            timelock_ledgers,
+        },
+    );
+}
+
+/// `param_change_approved` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParamChangeApprovedEvent {
+    pub id: u64,
+    pub voter: Address,
+    pub count: u32,
+}
+
+impl VaultEvent for ParamChangeApprovedEvent {
+    const NAME: &'static str = "param_change_approved";
+}
+
+pub fn emit_param_change_approved(env: &Env, id: u64, voter: &Address, count: u32) {
+    publish(
+        env,
+        ParamChangeApprovedEvent {
+            id,
+            voter: voter.clone(),
+            count,
+        },
+    );
+}
+
+/// `param_change_executed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParamChangeExecutedEvent {
+    pub id: u64,
+    pub executor: Address,
+    pub change_count: u32,
+}
+
+impl VaultEvent for ParamChangeExecutedEvent {
+    const NAME: &'static str = "param_change_executed";
+}
+
+pub fn emit_param_change_executed(env: &Env, id: u64, executor: &Address, change_count: u32) {
+    publish(
+        env,
+        ParamChangeExecutedEvent {
+            id,
+            executor: executor.clone(),
+            change_count,
+        },
+    );
 }
 
 // ============================================================================
 // Fee Cache Events (#1428)
 // ============================================================================
 
+/// `fee_cache_invalidated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCacheInvalidatedEvent {
+    pub proposal_id: u64,
+    pub admin: Address,
+}
+
+impl VaultEvent for FeeCacheInvalidatedEvent {
+    const NAME: &'static str = "fee_cache_invalidated";
+}
+
 pub fn emit_fee_cache_invalidated(env: &Env, proposal_id: u64, admin: &Address) {
-    env.events().publish(
-        (Symbol::new(env, "fee_cache_invalidated"), proposal_id),
-        admin.clone(),
+    publish(
+        env,
+        FeeCacheInvalidatedEvent {
+            proposal_id,
+            admin: admin.clone(),
+        },
     );
 }
 
@@ -1698,27 +4717,61 @@ pub fn emit_fee_cache_invalidated(env: &Env, proposal_id: u64, admin: &Address)
 // Fan-Out Stream Events (#1430)
 // ============================================================================
 
+/// `fanout_stream_created` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FanOutStreamCreatedEvent {
+    pub stream_id: u64,
+    pub creator: Address,
+    pub recipient_count: u32,
+}
+
+impl VaultEvent for FanOutStreamCreatedEvent {
+    const NAME: &'static str = "fanout_stream_created";
+}
+
 pub fn emit_fan_out_stream_created(
     env: &Env,
     stream_id: u64,
     creator: &Address,
     recipient_count: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "fanout_stream_created"), stream_id),
-        (creator.clone(), recipient_count),
+    publish(
+        env,
+        FanOutStreamCreatedEvent {
+            stream_id,
+            creator: creator.clone(),
+            recipient_count,
+        },
     );
 }
 
+/// `fanout_payment_distributed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FanOutPaymentDistributedEvent {
+    pub stream_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+impl VaultEvent for FanOutPaymentDistributedEvent {
+    const NAME: &'static str = "fanout_payment_distributed";
+}
+
 pub fn emit_fan_out_payment_distributed(
     env: &Env,
     stream_id: u64,
     recipient: &Address,
     amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "fanout_payment_distributed"), stream_id),
-        (recipient.clone(), amount),
+    publish(
+        env,
+        FanOutPaymentDistributedEvent {
+            stream_id,
+            recipient: recipient.clone(),
+            amount,
+        },
     );
 }
 
@@ -1726,17 +4779,51 @@ pub fn emit_fan_out_payment_distributed(
 // Stream Pause/Resume TTL Events (#1429)
 // ============================================================================
 
+/// `stream_paused` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamPausedEvent {
+    pub stream_id: u64,
+    pub pauser: Address,
+    pub ledger: u64,
+}
+
+impl VaultEvent for StreamPausedEvent {
+    const NAME: &'static str = "stream_paused";
+}
+
 pub fn emit_stream_paused(env: &Env, stream_id: u64, pauser: &Address, ledger: u64) {
-    env.events().publish(
-        (Symbol::new(env, "stream_paused"), stream_id),
-        (pauser.clone(), ledger),
+    publish(
+        env,
+        StreamPausedEvent {
+            stream_id,
+            pauser: pauser.clone(),
+            ledger,
+        },
     );
 }
 
+/// `stream_resumed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamResumedEvent {
+    pub stream_id: u64,
+    pub resumer: Address,
+    pub pause_duration: u64,
+}
+
+impl VaultEvent for StreamResumedEvent {
+    const NAME: &'static str = "stream_resumed";
+}
+
 pub fn emit_stream_resumed(env: &Env, stream_id: u64, resumer: &Address, pause_duration: u64) {
-    env.events().publish(
-        (Symbol::new(env, "stream_resumed"), stream_id),
-        (resumer.clone(), pause_duration),
+    publish(
+        env,
+        StreamResumedEvent {
+            stream_id,
+            resumer: resumer.clone(),
+            pause_duration,
+        },
     );
 }
 
@@ -1744,13 +4831,43 @@ pub fn emit_stream_resumed(env: &Env, stream_id: u64, resumer: &Address, pause_d
 // Escrow Voting Events (#1431)
 // ============================================================================
 
+/// `escrow_release_locked` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReleaseLockedForVotingEvent {
+    pub escrow_id: u64,
+    pub required_approvals: u32,
+}
+
+impl VaultEvent for EscrowReleaseLockedForVotingEvent {
+    const NAME: &'static str = "escrow_release_locked";
+}
+
 pub fn emit_escrow_release_locked_for_voting(env: &Env, escrow_id: u64, required_approvals: u32) {
-    env.events().publish(
-        (Symbol::new(env, "escrow_release_locked"), escrow_id),
-        required_approvals,
+    publish(
+        env,
+        EscrowReleaseLockedForVotingEvent {
+            escrow_id,
+            required_approvals,
+        },
     );
 }
 
+/// `escrow_release_voted` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReleaseVotedEvent {
+    pub escrow_id: u64,
+    pub voter: Address,
+    pub approved: bool,
+    pub approval_count: u32,
+    pub rejection_count: u32,
+}
+
+impl VaultEvent for EscrowReleaseVotedEvent {
+    const NAME: &'static str = "escrow_release_voted";
+}
+
 pub fn emit_escrow_release_voted(
     env: &Env,
     escrow_id: u64,
@@ -1759,9 +4876,15 @@ pub fn emit_escrow_release_voted(
     approval_count: u32,
     rejection_count: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "escrow_release_voted"), escrow_id),
-        (voter.clone(), approved, approval_count, rejection_count),
+    publish(
+        env,
+        EscrowReleaseVotedEvent {
+            escrow_id,
+            voter: voter.clone(),
+            approved,
+            approval_count,
+            rejection_count,
+        },
     );
 }
 
@@ -1769,6 +4892,20 @@ pub fn emit_escrow_release_voted(
 // Recurring Payment Jitter Events (issue #1364)
 // ============================================================================
 
+/// `recurring_pay_jittered` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecurringPaymentJitteredEvent {
+    pub payment_id: u64,
+    pub nominal_next_ledger: u64,
+    pub jittered_next_ledger: u64,
+    pub jitter_offset: u32,
+}
+
+impl VaultEvent for RecurringPaymentJitteredEvent {
+    const NAME: &'static str = "recurring_pay_jittered";
+}
+
 /// Emit when jitter is applied to a recurring payment's next execution ledger.
 ///
 /// This event fires once per execution cycle (not per missed-payment catch-up
@@ -1798,22 +4935,57 @@ pub fn emit_recurring_payment_jittered(
     jittered_next_ledger: u64,
     jitter_offset: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "recurring_pay_jittered"), payment_id),
-        (nominal_next_ledger, jittered_next_ledger, jitter_offset),
+    publish(
+        env,
+        RecurringPaymentJitteredEvent {
+            payment_id,
+            nominal_next_ledger,
+            jittered_next_ledger,
+            jitter_offset,
+        },
     );
 }
 
+/// `cache_invalidated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CacheInvalidatedEvent {
+    pub tag: Symbol,
+    pub admin: Address,
+}
+
+impl VaultEvent for CacheInvalidatedEvent {
+    const NAME: &'static str = "cache_invalidated";
+}
+
 /// Emit when a cache tag is invalidated by admin (#1459)
 pub fn emit_cache_invalidated(env: &Env, tag: Symbol, admin: &Address) {
-    env.events()
-        .publish((Symbol::new(env, "cache_invalidated"), tag), admin.clone());
+    publish(
+        env,
+        CacheInvalidatedEvent {
+            tag,
+            admin: admin.clone(),
+        },
+    );
 }
 
 // ============================================================================
 // Issue #1091: Keeper Network Lifecycle Hooks
 // ============================================================================
 
+/// `keeper_hook_registered` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperHookRegisteredEvent {
+    pub keeper: Address,
+    pub event_type_id: u32,
+    pub callback_contract: Address,
+}
+
+impl VaultEvent for KeeperHookRegisteredEvent {
+    const NAME: &'static str = "keeper_hook_registered";
+}
+
 /// Emit when a keeper hook is successfully registered
 pub fn emit_keeper_hook_registered(
     env: &Env,
@@ -1821,20 +4993,54 @@ pub fn emit_keeper_hook_registered(
     event_type_id: u32,
     callback_contract: &soroban_sdk::Address,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "keeper_hook_registered"), event_type_id),
-        (keeper.clone(), callback_contract.clone()),
+    publish(
+        env,
+        KeeperHookRegisteredEvent {
+            keeper: keeper.clone(),
+            event_type_id,
+            callback_contract: callback_contract.clone(),
+        },
     );
 }
 
+/// `keeper_hook_removed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperHookRemovedEvent {
+    pub keeper: Address,
+    pub event_type_id: u32,
+}
+
+impl VaultEvent for KeeperHookRemovedEvent {
+    const NAME: &'static str = "keeper_hook_removed";
+}
+
 /// Emit when a keeper hook is removed
 pub fn emit_keeper_hook_removed(env: &Env, keeper: &soroban_sdk::Address, event_type_id: u32) {
-    env.events().publish(
-        (Symbol::new(env, "keeper_hook_removed"), event_type_id),
-        keeper.clone(),
+    publish(
+        env,
+        KeeperHookRemovedEvent {
+            keeper: keeper.clone(),
+            event_type_id,
+        },
     );
 }
 
+/// `keeper_hook_triggered` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperHookTriggeredEvent {
+    pub keeper: Address,
+    pub callback_contract: Address,
+    pub event_type_id: u32,
+    pub payload: u64,
+    pub fee_paid: i128,
+}
+
+impl VaultEvent for KeeperHookTriggeredEvent {
+    const NAME: &'static str = "keeper_hook_triggered";
+}
+
 /// Emit when a keeper hook callback is successfully triggered and fee paid
 pub fn emit_keeper_hook_triggered(
     env: &Env,
@@ -1844,12 +5050,32 @@ pub fn emit_keeper_hook_triggered(
     payload: u64,
     fee_paid: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "keeper_hook_triggered"), event_type_id),
-        (keeper.clone(), callback_contract.clone(), payload, fee_paid),
+    publish(
+        env,
+        KeeperHookTriggeredEvent {
+            keeper: keeper.clone(),
+            callback_contract: callback_contract.clone(),
+            event_type_id,
+            payload,
+            fee_paid,
+        },
     );
 }
 
+/// `keeper_hook_failed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperHookFailedEvent {
+    pub keeper: Address,
+    pub callback_contract: Address,
+    pub event_type_id: u32,
+    pub payload: u64,
+}
+
+impl VaultEvent for KeeperHookFailedEvent {
+    const NAME: &'static str = "keeper_hook_failed";
+}
+
 /// Emit when a keeper hook callback fails (non-blocking — vault continues)
 pub fn emit_keeper_hook_failed(
     env: &Env,
@@ -1858,9 +5084,105 @@ pub fn emit_keeper_hook_failed(
     event_type_id: u32,
     payload: u64,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "keeper_hook_failed"), event_type_id),
-        (keeper.clone(), callback_contract.clone(), payload),
+    publish(
+        env,
+        KeeperHookFailedEvent {
+            keeper: keeper.clone(),
+            callback_contract: callback_contract.clone(),
+            event_type_id,
+            payload,
+        },
+    );
+}
+
+/// `keeper_budget_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperBudgetSetEvent {
+    pub admin: Address,
+    pub token: Address,
+    pub bounty_per_item: i128,
+    pub remaining: i128,
+}
+
+impl VaultEvent for KeeperBudgetSetEvent {
+    const NAME: &'static str = "keeper_budget_set";
+}
+
+/// Emit when the keeper bounty budget is configured
+pub fn emit_keeper_budget_set(
+    env: &Env,
+    admin: &Address,
+    token: &Address,
+    bounty_per_item: i128,
+    remaining: i128,
+) {
+    publish(
+        env,
+        KeeperBudgetSetEvent {
+            admin: admin.clone(),
+            token: token.clone(),
+            bounty_per_item,
+            remaining,
+        },
+    );
+}
+
+/// `keeper_work_executed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperWorkExecutedEvent {
+    pub keeper: Address,
+    pub work: KeeperWork,
+    pub bounty: i128,
+}
+
+impl VaultEvent for KeeperWorkExecutedEvent {
+    const NAME: &'static str = "keeper_work_executed";
+}
+
+/// Emit when `execute_due` completes a work item (bounty is 0 when unpaid)
+pub fn emit_keeper_work_executed(env: &Env, keeper: &Address, work: &KeeperWork, bounty: i128) {
+    publish(
+        env,
+        KeeperWorkExecutedEvent {
+            keeper: keeper.clone(),
+            work: work.clone(),
+            bounty,
+        },
+    );
+}
+
+/// `keeper_bounty_paid` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperBountyPaidEvent {
+    pub keeper: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub remaining: i128,
+}
+
+impl VaultEvent for KeeperBountyPaidEvent {
+    const NAME: &'static str = "keeper_bounty_paid";
+}
+
+/// Emit when the bounties earned in an `execute_due` run are transferred
+pub fn emit_keeper_bounty_paid(
+    env: &Env,
+    keeper: &Address,
+    token: &Address,
+    amount: i128,
+    remaining: i128,
+) {
+    publish(
+        env,
+        KeeperBountyPaidEvent {
+            keeper: keeper.clone(),
+            token: token.clone(),
+            amount,
+            remaining,
+        },
     );
 }
 
@@ -1868,6 +5190,21 @@ pub fn emit_keeper_hook_failed(
 // Issue #1355: Insurance Claim Governance Voting with Quorum
 // ============================================================================
 
+/// `claim_voting_closed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimVotingClosedEvent {
+    pub claim_id: u64,
+    pub closer: Address,
+    pub approve_weight: i128,
+    pub reject_weight: i128,
+    pub status_code: u32,
+}
+
+impl VaultEvent for ClaimVotingClosedEvent {
+    const NAME: &'static str = "claim_voting_closed";
+}
+
 /// Emit when an insurance claim's voting period is explicitly closed and tallied.
 ///
 /// `status_code` mirrors `InsuranceClaimStatus` (1 = Approved, 2 = Rejected,
@@ -1880,12 +5217,32 @@ pub fn emit_claim_voting_closed(
     reject_weight: i128,
     status_code: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "claim_voting_closed"), claim_id),
-        (closer.clone(), approve_weight, reject_weight, status_code),
+    publish(
+        env,
+        ClaimVotingClosedEvent {
+            claim_id,
+            closer: closer.clone(),
+            approve_weight,
+            reject_weight,
+            status_code,
+        },
     );
 }
 
+/// `claim_quorum_failed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimQuorumFailedEvent {
+    pub claim_id: u64,
+    pub voters: u32,
+    pub required_voters: u32,
+    pub eligible_voters: u32,
+}
+
+impl VaultEvent for ClaimQuorumFailedEvent {
+    const NAME: &'static str = "claim_quorum_failed";
+}
+
 /// Emit when a claim fails to reach its participation quorum.
 pub fn emit_claim_quorum_failed(
     env: &Env,
@@ -1894,9 +5251,14 @@ pub fn emit_claim_quorum_failed(
     required_voters: u32,
     eligible_voters: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "claim_quorum_failed"), claim_id),
-        (voters, required_voters, eligible_voters),
+    publish(
+        env,
+        ClaimQuorumFailedEvent {
+            claim_id,
+            voters,
+            required_voters,
+            eligible_voters,
+        },
     );
 }
 
@@ -1904,6 +5266,20 @@ pub fn emit_claim_quorum_failed(
 // Issue #1356: Proposal Amendment Limits
 // ============================================================================
 
+/// `amendment_limit_warn` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmendmentLimitWarningEvent {
+    pub proposal_id: u64,
+    pub amendment_count: u32,
+    pub max_amendments: u32,
+    pub remaining: u32,
+}
+
+impl VaultEvent for AmendmentLimitWarningEvent {
+    const NAME: &'static str = "amendment_limit_warn";
+}
+
 /// Emit when a proposal is nearing (or has hit) its amendment ceiling.
 ///
 /// `remaining` is how many amendments are still permitted; 0 means the next
@@ -1915,9 +5291,14 @@ pub fn emit_amendment_limit_warning(
     max_amendments: u32,
     remaining: u32,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "amendment_limit_warn"), proposal_id),
-        (amendment_count, max_amendments, remaining),
+    publish(
+        env,
+        AmendmentLimitWarningEvent {
+            proposal_id,
+            amendment_count,
+            max_amendments,
+            remaining,
+        },
     );
 }
 
@@ -1925,6 +5306,19 @@ pub fn emit_amendment_limit_warning(
 // Issue #1363: Batch Dependency Validation
 // ============================================================================
 
+/// `batch_reordered` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchReorderedEvent {
+    pub batch_id: u64,
+    pub original_order: Vec<u64>,
+    pub sorted_order: Vec<u64>,
+}
+
+impl VaultEvent for BatchReorderedEvent {
+    const NAME: &'static str = "batch_reordered";
+}
+
 /// Emit when a batch's proposals had to be reordered to satisfy dependencies.
 pub fn emit_batch_reordered(
     env: &Env,
@@ -1932,15 +5326,32 @@ pub fn emit_batch_reordered(
     original_order: &Vec<u64>,
     sorted_order: &Vec<u64>,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "batch_reordered"), batch_id),
-        (original_order.clone(), sorted_order.clone()),
+    publish(
+        env,
+        BatchReorderedEvent {
+            batch_id,
+            original_order: original_order.clone(),
+            sorted_order: sorted_order.clone(),
+        },
     );
 }
 // ============================================================================
 // Issue #1350: Pause Circuit Breaker Cooldown
 // ============================================================================
 
+/// `pause_cooldown_active` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseCooldownActiveEvent {
+    pub requester: Address,
+    pub remaining_ledgers: u64,
+    pub reason: Symbol,
+}
+
+impl VaultEvent for PauseCooldownActiveEvent {
+    const NAME: &'static str = "pause_cooldown_active";
+}
+
 /// Emit when vault pause is rejected due to active cooldown
 pub fn emit_pause_cooldown_active(
     env: &Env,
@@ -1948,17 +5359,36 @@ pub fn emit_pause_cooldown_active(
     remaining_ledgers: u64,
     reason: Symbol,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "pause_cooldown_active"),),
-        (requester.clone(), remaining_ledgers, reason),
+    publish(
+        env,
+        PauseCooldownActiveEvent {
+            requester: requester.clone(),
+            remaining_ledgers,
+            reason,
+        },
     );
 }
 
+/// `vault_unpaused` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultUnpausedEvent {
+    pub unpauser: Address,
+    pub pause_duration_ledgers: u64,
+}
+
+impl VaultEvent for VaultUnpausedEvent {
+    const NAME: &'static str = "vault_unpaused";
+}
+
 /// Emit when vault is unpaused
 pub fn emit_vault_unpaused(env: &Env, unpauser: &Address, pause_duration_ledgers: u64) {
-    env.events().publish(
-        (Symbol::new(env, "vault_unpaused"),),
-        (unpauser.clone(), pause_duration_ledgers),
+    publish(
+        env,
+        VaultUnpausedEvent {
+            unpauser: unpauser.clone(),
+            pause_duration_ledgers,
+        },
     );
 }
 
@@ -1966,6 +5396,19 @@ pub fn emit_vault_unpaused(env: &Env, unpauser: &Address, pause_duration_ledgers
 // Issue #1351: Fix Voting Snapshot Stale Signer Issue
 // ============================================================================
 
+/// `vote_rejected_signer_removed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteRejectedSignerRemovedEvent {
+    pub proposal_id: u64,
+    pub signer: Address,
+    pub reason: Symbol,
+}
+
+impl VaultEvent for VoteRejectedSignerRemovedEvent {
+    const NAME: &'static str = "vote_rejected_signer_removed";
+}
+
 /// Emit when a vote is rejected because signer was removed after proposal creation
 pub fn emit_vote_rejected_signer_removed(
     env: &Env,
@@ -1973,12 +5416,13 @@ pub fn emit_vote_rejected_signer_removed(
     signer: &Address,
     reason: Symbol,
 ) {
-    env.events().publish(
-        (
-            Symbol::new(env, "vote_rejected_signer_removed"),
+    publish(
+        env,
+        VoteRejectedSignerRemovedEvent {
             proposal_id,
-        ),
-        (signer.clone(), reason),
+            signer: signer.clone(),
+            reason,
+        },
     );
 }
 
@@ -1986,6 +5430,20 @@ pub fn emit_vote_rejected_signer_removed(
 // Issue #1353: Spending Limit Recalculation on Config Update
 // ============================================================================
 
+/// `spending_limit_warning` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendingLimitWarningEvent {
+    pub proposal_id: u64,
+    pub old_limit: i128,
+    pub new_limit: i128,
+    pub proposal_amount: i128,
+}
+
+impl VaultEvent for SpendingLimitWarningEvent {
+    const NAME: &'static str = "spending_limit_warning";
+}
+
 /// Emit when a spending limit update causes a warning for pending proposals
 pub fn emit_spending_limit_warning(
     env: &Env,
@@ -1994,12 +5452,30 @@ pub fn emit_spending_limit_warning(
     new_limit: i128,
     proposal_amount: i128,
 ) {
-    env.events().publish(
-        (Symbol::new(env, "spending_limit_warning"), proposal_id),
-        (old_limit, new_limit, proposal_amount),
+    publish(
+        env,
+        SpendingLimitWarningEvent {
+            proposal_id,
+            old_limit,
+            new_limit,
+            proposal_amount,
+        },
     );
 }
 
+/// `proposal_auto_cancelled_limit` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalAutoCancelledLimitExceededEvent {
+    pub proposal_id: u64,
+    pub reason: Symbol,
+    pub admin: Address,
+}
+
+impl VaultEvent for ProposalAutoCancelledLimitExceededEvent {
+    const NAME: &'static str = "proposal_auto_cancelled_limit";
+}
+
 /// Emit when a pending proposal is auto-cancelled due to new spending limits
 pub fn emit_proposal_auto_cancelled_limit_exceeded(
     env: &Env,
@@ -2007,11 +5483,1102 @@ pub fn emit_proposal_auto_cancelled_limit_exceeded(
     reason: Symbol,
     admin: &Address,
 ) {
-    env.events().publish(
-        (
-            Symbol::new(env, "proposal_auto_cancelled_limit"),
+    publish(
+        env,
+        ProposalAutoCancelledLimitExceededEvent {
             proposal_id,
-        ),
-        (reason, admin.clone()),
+            reason,
+            admin: admin.clone(),
+        },
+    );
+}
+
+// ============================================================================
+// Rolling-Window Spending Limits
+// ============================================================================
+
+/// `spending_window_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendingWindowModeSetEvent {
+    pub admin: Address,
+    pub token: Option<Address>,
+    pub mode: SpendingWindowMode,
+}
+
+impl VaultEvent for SpendingWindowModeSetEvent {
+    const NAME: &'static str = "spending_window_set";
+}
+
+/// Emit when the spending window mode changes (`token = None` for the vault-wide mode)
+pub fn emit_spending_window_mode_set(
+    env: &Env,
+    admin: &Address,
+    token: Option<Address>,
+    mode: SpendingWindowMode,
+) {
+    publish(
+        env,
+        SpendingWindowModeSetEvent {
+            admin: admin.clone(),
+            token,
+            mode,
+        },
     );
 }
+
+// ============================================================================
+// Per-Recipient Caps and First-Payment Cool-Off
+// ============================================================================
+
+/// `recipient_limits_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecipientLimitConfigSetEvent {
+    pub admin: Address,
+    pub default_cap: i128,
+}
+
+impl VaultEvent for RecipientLimitConfigSetEvent {
+    const NAME: &'static str = "recipient_limits_set";
+}
+
+/// Emit when the vault-wide per-recipient safeguards change
+pub fn emit_recipient_limit_config_set(env: &Env, admin: &Address, default_cap: i128) {
+    publish(
+        env,
+        RecipientLimitConfigSetEvent {
+            admin: admin.clone(),
+            default_cap,
+        },
+    );
+}
+
+/// `recipient_cap_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecipientCapSetEvent {
+    pub admin: Address,
+    pub recipient: Address,
+    pub cap: i128,
+}
+
+impl VaultEvent for RecipientCapSetEvent {
+    const NAME: &'static str = "recipient_cap_set";
+}
+
+/// Emit when a recipient's cap override is set (`cap = 0` clears the override)
+pub fn emit_recipient_cap_set(env: &Env, admin: &Address, recipient: &Address, cap: i128) {
+    publish(
+        env,
+        RecipientCapSetEvent {
+            admin: admin.clone(),
+            recipient: recipient.clone(),
+            cap,
+        },
+    );
+}
+
+/// `first_payment_cooloff` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FirstPaymentCooloffEvent {
+    pub proposal_id: u64,
+    pub recipient: Address,
+    pub extra_approvals: u32,
+    pub extra_delay_ledgers: u64,
+}
+
+impl VaultEvent for FirstPaymentCooloffEvent {
+    const NAME: &'static str = "first_payment_cooloff";
+}
+
+/// Emit when a proposal to a never-paid recipient receives first-payment safeguards
+pub fn emit_first_payment_cooloff(
+    env: &Env,
+    proposal_id: u64,
+    recipient: &Address,
+    extra_approvals: u32,
+    extra_delay_ledgers: u64,
+) {
+    publish(
+        env,
+        FirstPaymentCooloffEvent {
+            proposal_id,
+            recipient: recipient.clone(),
+            extra_approvals,
+            extra_delay_ledgers,
+        },
+    );
+}
+
+// ============================================================================
+// Tag Budget Envelopes
+// ============================================================================
+
+/// `tag_budget_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TagBudgetSetEvent {
+    pub admin: Address,
+    pub tag_id: u64,
+    pub limit: i128,
+}
+
+impl VaultEvent for TagBudgetSetEvent {
+    const NAME: &'static str = "tag_budget_set";
+}
+
+/// Emit when a tag budget is set (`limit = 0` means the budget was removed)
+pub fn emit_tag_budget_set(env: &Env, admin: &Address, tag_id: u64, limit: i128) {
+    publish(
+        env,
+        TagBudgetSetEvent {
+            admin: admin.clone(),
+            tag_id,
+            limit,
+        },
+    );
+}
+
+/// `tag_budget_overrun` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TagBudgetOverrunEvent {
+    pub proposal_id: u64,
+    pub tag_id: u64,
+    pub used: i128,
+    pub limit: i128,
+    pub extra_approvals: u32,
+}
+
+impl VaultEvent for TagBudgetOverrunEvent {
+    const NAME: &'static str = "tag_budget_overrun";
+}
+
+/// Emit when a proposal takes a tag budget past its limit under an ExtraApprovals policy
+pub fn emit_tag_budget_overrun(
+    env: &Env,
+    proposal_id: u64,
+    tag_id: u64,
+    used: i128,
+    limit: i128,
+    extra_approvals: u32,
+) {
+    publish(
+        env,
+        TagBudgetOverrunEvent {
+            proposal_id,
+            tag_id,
+            used,
+            limit,
+            extra_approvals,
+        },
+    );
+}
+
+// ============================================================================
+// Committed Liabilities Register
+// ============================================================================
+
+/// `solvency_policy_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolvencyPolicySetEvent {
+    pub admin: Address,
+    pub override_approvals: u32,
+}
+
+impl VaultEvent for SolvencyPolicySetEvent {
+    const NAME: &'static str = "solvency_policy_set";
+}
+
+/// Emit when the admin changes how over-committed proposals are handled
+pub fn emit_solvency_policy_set(env: &Env, admin: &Address, override_approvals: u32) {
+    publish(
+        env,
+        SolvencyPolicySetEvent {
+            admin: admin.clone(),
+            override_approvals,
+        },
+    );
+}
+
+/// `solvency_override` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolvencyOverrideEvent {
+    pub proposal_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub free_balance: i128,
+    pub extra_approvals: u32,
+}
+
+impl VaultEvent for SolvencyOverrideEvent {
+    const NAME: &'static str = "solvency_override";
+}
+
+/// Emit when a proposal exceeding the free balance is admitted under an override vote
+pub fn emit_solvency_override(
+    env: &Env,
+    proposal_id: u64,
+    token: &Address,
+    amount: i128,
+    free_balance: i128,
+    extra_approvals: u32,
+) {
+    publish(
+        env,
+        SolvencyOverrideEvent {
+            proposal_id,
+            token: token.clone(),
+            amount,
+            free_balance,
+            extra_approvals,
+        },
+    );
+}
+
+// ============================================================================
+// Optimistic Execution
+// ============================================================================
+
+/// `optimistic_config_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OptimisticConfigSetEvent {
+    pub admin: Address,
+    pub enabled: bool,
+    pub max_amount: i128,
+}
+
+impl VaultEvent for OptimisticConfigSetEvent {
+    const NAME: &'static str = "optimistic_config_set";
+}
+
+/// Emit when the admin updates the optimistic proposal settings
+pub fn emit_optimistic_config_set(env: &Env, admin: &Address, enabled: bool, max_amount: i128) {
+    publish(
+        env,
+        OptimisticConfigSetEvent {
+            admin: admin.clone(),
+            enabled,
+            max_amount,
+        },
+    );
+}
+
+/// `optimistic_proposed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OptimisticProposedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub challenge_ends: u64,
+}
+
+impl VaultEvent for OptimisticProposedEvent {
+    const NAME: &'static str = "optimistic_proposed";
+}
+
+/// Emit when an optimistic proposal is created and its challenge window opens
+pub fn emit_optimistic_proposed(
+    env: &Env,
+    proposal_id: u64,
+    proposer: &Address,
+    challenge_ends: u64,
+) {
+    publish(
+        env,
+        OptimisticProposedEvent {
+            proposal_id,
+            proposer: proposer.clone(),
+            challenge_ends,
+        },
+    );
+}
+
+/// `proposal_objected` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalObjectedEvent {
+    pub proposal_id: u64,
+    pub signer: Address,
+    pub objections: u32,
+}
+
+impl VaultEvent for ProposalObjectedEvent {
+    const NAME: &'static str = "proposal_objected";
+}
+
+/// Emit when a signer objects to an optimistic proposal
+pub fn emit_proposal_objected(env: &Env, proposal_id: u64, signer: &Address, objections: u32) {
+    publish(
+        env,
+        ProposalObjectedEvent {
+            proposal_id,
+            signer: signer.clone(),
+            objections,
+        },
+    );
+}
+
+/// `optimistic_escalated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OptimisticEscalatedEvent {
+    pub proposal_id: u64,
+    pub objections: u32,
+    pub voting_deadline: u64,
+}
+
+impl VaultEvent for OptimisticEscalatedEvent {
+    const NAME: &'static str = "optimistic_escalated";
+}
+
+/// Emit when objections move an optimistic proposal to the normal vote
+pub fn emit_optimistic_escalated(
+    env: &Env,
+    proposal_id: u64,
+    objections: u32,
+    voting_deadline: u64,
+) {
+    publish(
+        env,
+        OptimisticEscalatedEvent {
+            proposal_id,
+            objections,
+            voting_deadline,
+        },
+    );
+}
+
+/// `topic_delegated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TopicDelegatedEvent {
+    pub delegator: Address,
+    pub delegate: Address,
+    pub scope: DelegationScope,
+}
+
+impl VaultEvent for TopicDelegatedEvent {
+    const NAME: &'static str = "topic_delegated";
+}
+
+/// Emit when a signer delegates their votes on one topic
+pub fn emit_topic_delegated(
+    env: &Env,
+    delegator: &Address,
+    delegate: &Address,
+    scope: &DelegationScope,
+) {
+    publish(
+        env,
+        TopicDelegatedEvent {
+            delegator: delegator.clone(),
+            delegate: delegate.clone(),
+            scope: scope.clone(),
+        },
+    );
+}
+
+/// `topic_deleg_revoked` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TopicDelegationRevokedEvent {
+    pub delegator: Address,
+    pub scope: DelegationScope,
+}
+
+impl VaultEvent for TopicDelegationRevokedEvent {
+    const NAME: &'static str = "topic_deleg_revoked";
+}
+
+/// Emit when a topic delegation is revoked
+pub fn emit_topic_delegation_revoked(env: &Env, delegator: &Address, scope: &DelegationScope) {
+    publish(
+        env,
+        TopicDelegationRevokedEvent {
+            delegator: delegator.clone(),
+            scope: scope.clone(),
+        },
+    );
+}
+
+/// `election_started` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerElectionStartedEvent {
+    pub election_id: u64,
+    pub seats: u32,
+    pub nomination_end: u64,
+    pub voting_end: u64,
+}
+
+impl VaultEvent for SignerElectionStartedEvent {
+    const NAME: &'static str = "election_started";
+}
+
+pub fn emit_signer_election_started(
+    env: &Env,
+    election_id: u64,
+    seats: u32,
+    nomination_end: u64,
+    voting_end: u64,
+) {
+    publish(
+        env,
+        SignerElectionStartedEvent {
+            election_id,
+            seats,
+            nomination_end,
+            voting_end,
+        },
+    );
+}
+
+/// `candidate_registered` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CandidateRegisteredEvent {
+    pub election_id: u64,
+    pub candidate: Address,
+}
+
+impl VaultEvent for CandidateRegisteredEvent {
+    const NAME: &'static str = "candidate_registered";
+}
+
+pub fn emit_candidate_registered(env: &Env, election_id: u64, candidate: &Address) {
+    publish(
+        env,
+        CandidateRegisteredEvent {
+            election_id,
+            candidate: candidate.clone(),
+        },
+    );
+}
+
+/// `election_ballot_cast` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElectionBallotCastEvent {
+    pub election_id: u64,
+    pub voter: Address,
+    pub power: i128,
+}
+
+impl VaultEvent for ElectionBallotCastEvent {
+    const NAME: &'static str = "election_ballot_cast";
+}
+
+pub fn emit_election_ballot_cast(env: &Env, election_id: u64, voter: &Address, power: i128) {
+    publish(
+        env,
+        ElectionBallotCastEvent {
+            election_id,
+            voter: voter.clone(),
+            power,
+        },
+    );
+}
+
+/// `election_tallied` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerElectionTalliedEvent {
+    pub election_id: u64,
+    pub elected: Vec<Address>,
+    pub new_threshold: u32,
+    pub unlock_ledger: u64,
+}
+
+impl VaultEvent for SignerElectionTalliedEvent {
+    const NAME: &'static str = "election_tallied";
+}
+
+pub fn emit_signer_election_tallied(
+    env: &Env,
+    election_id: u64,
+    elected: &Vec<Address>,
+    new_threshold: u32,
+    unlock_ledger: u64,
+) {
+    publish(
+        env,
+        SignerElectionTalliedEvent {
+            election_id,
+            elected: elected.clone(),
+            new_threshold,
+            unlock_ledger,
+        },
+    );
+}
+
+/// `election_failed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerElectionFailedEvent {
+    pub election_id: u64,
+    pub candidates: u32,
+}
+
+impl VaultEvent for SignerElectionFailedEvent {
+    const NAME: &'static str = "election_failed";
+}
+
+pub fn emit_signer_election_failed(env: &Env, election_id: u64, candidates: u32) {
+    publish(
+        env,
+        SignerElectionFailedEvent {
+            election_id,
+            candidates,
+        },
+    );
+}
+
+/// `election_applied` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerElectionAppliedEvent {
+    pub election_id: u64,
+    pub transitioned_proposals: u32,
+}
+
+impl VaultEvent for SignerElectionAppliedEvent {
+    const NAME: &'static str = "election_applied";
+}
+
+pub fn emit_signer_election_applied(env: &Env, election_id: u64, transitioned_proposals: u32) {
+    publish(
+        env,
+        SignerElectionAppliedEvent {
+            election_id,
+            transitioned_proposals,
+        },
+    );
+}
+
+/// `signer_term_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerTermSetEvent {
+    pub signer: Address,
+    pub term_ledgers: u64,
+    pub expires_at: u64,
+}
+
+impl VaultEvent for SignerTermSetEvent {
+    const NAME: &'static str = "signer_term_set";
+}
+
+pub fn emit_signer_term_set(env: &Env, signer: &Address, term_ledgers: u64, expires_at: u64) {
+    publish(
+        env,
+        SignerTermSetEvent {
+            signer: signer.clone(),
+            term_ledgers,
+            expires_at,
+        },
+    );
+}
+
+/// `inactivity_policy_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InactivityPolicySetEvent {
+    pub admin: Address,
+    pub inactive_after_ledgers: u64,
+}
+
+impl VaultEvent for InactivityPolicySetEvent {
+    const NAME: &'static str = "inactivity_policy_set";
+}
+
+pub fn emit_inactivity_policy_set(env: &Env, admin: &Address, inactive_after_ledgers: u64) {
+    publish(
+        env,
+        InactivityPolicySetEvent {
+            admin: admin.clone(),
+            inactive_after_ledgers,
+        },
+    );
+}
+
+/// `signer_removal_proposed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerRemovalProposedEvent {
+    pub removal_id: u64,
+    pub proposer: Address,
+    pub target: Address,
+}
+
+impl VaultEvent for SignerRemovalProposedEvent {
+    const NAME: &'static str = "signer_removal_proposed";
+}
+
+pub fn emit_signer_removal_proposed(
+    env: &Env,
+    removal_id: u64,
+    proposer: &Address,
+    target: &Address,
+) {
+    publish(
+        env,
+        SignerRemovalProposedEvent {
+            removal_id,
+            proposer: proposer.clone(),
+            target: target.clone(),
+        },
+    );
+}
+
+/// `inactive_signer_removed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InactiveSignerRemovedEvent {
+    pub removal_id: u64,
+    pub target: Address,
+    pub threshold: u32,
+}
+
+impl VaultEvent for InactiveSignerRemovedEvent {
+    const NAME: &'static str = "inactive_signer_removed";
+}
+
+pub fn emit_inactive_signer_removed(env: &Env, removal_id: u64, target: &Address, threshold: u32) {
+    publish(
+        env,
+        InactiveSignerRemovedEvent {
+            removal_id,
+            target: target.clone(),
+            threshold,
+        },
+    );
+}
+
+/// `guardian_council_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianCouncilSetEvent {
+    pub admin: Address,
+    pub guardians: u32,
+    pub threshold: u32,
+}
+
+impl VaultEvent for GuardianCouncilSetEvent {
+    const NAME: &'static str = "guardian_council_set";
+}
+
+pub fn emit_guardian_council_set(env: &Env, admin: &Address, guardians: u32, threshold: u32) {
+    publish(
+        env,
+        GuardianCouncilSetEvent {
+            admin: admin.clone(),
+            guardians,
+            threshold,
+        },
+    );
+}
+
+/// `guardian_action_proposed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianActionProposedEvent {
+    pub action_id: u64,
+    pub proposer: Address,
+    pub action: GuardianAction,
+}
+
+impl VaultEvent for GuardianActionProposedEvent {
+    const NAME: &'static str = "guardian_action_proposed";
+}
+
+pub fn emit_guardian_action_proposed(
+    env: &Env,
+    action_id: u64,
+    proposer: &Address,
+    action: &GuardianAction,
+) {
+    publish(
+        env,
+        GuardianActionProposedEvent {
+            action_id,
+            proposer: proposer.clone(),
+            action: action.clone(),
+        },
+    );
+}
+
+/// `guardian_action_active` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianActionActivatedEvent {
+    pub action_id: u64,
+    pub ratify_by: u64,
+}
+
+impl VaultEvent for GuardianActionActivatedEvent {
+    const NAME: &'static str = "guardian_action_active";
+}
+
+pub fn emit_guardian_action_activated(env: &Env, action_id: u64, ratify_by: u64) {
+    publish(
+        env,
+        GuardianActionActivatedEvent {
+            action_id,
+            ratify_by,
+        },
+    );
+}
+
+/// `guardian_action_status` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianActionStatusEvent {
+    pub action_id: u64,
+    pub status: u32,
+}
+
+impl VaultEvent for GuardianActionStatusEvent {
+    const NAME: &'static str = "guardian_action_status";
+}
+
+/// Emit when a guardian action changes status after activation (ratified,
+/// expired or lifted)
+pub fn emit_guardian_action_status(env: &Env, action_id: u64, status: u32) {
+    publish(env, GuardianActionStatusEvent { action_id, status });
+}
+
+/// `recurring_payment_executed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecurringPaymentExecutedEvent {
+    pub payment_id: u64,
+    pub payment_ledger: u64,
+    pub amount: i128,
+}
+
+impl VaultEvent for RecurringPaymentExecutedEvent {
+    const NAME: &'static str = "recurring_payment_executed";
+}
+
+/// Emit once per payment settled by a recurring execution
+pub fn emit_recurring_payment_executed(
+    env: &Env,
+    payment_id: u64,
+    payment_ledger: u64,
+    amount: i128,
+) {
+    publish(
+        env,
+        RecurringPaymentExecutedEvent {
+            payment_id,
+            payment_ledger,
+            amount,
+        },
+    );
+}
+
+/// `merge_initiated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MergeInitiatedEvent {
+    pub merge_id: u64,
+    pub source_vault: Address,
+    pub target_vault: Address,
+}
+
+impl VaultEvent for MergeInitiatedEvent {
+    const NAME: &'static str = "merge_initiated";
+}
+
+/// Emit when a vault merge is initiated
+pub fn emit_merge_initiated(
+    env: &Env,
+    merge_id: u64,
+    source_vault: &Address,
+    target_vault: &Address,
+) {
+    publish(
+        env,
+        MergeInitiatedEvent {
+            merge_id,
+            source_vault: source_vault.clone(),
+            target_vault: target_vault.clone(),
+        },
+    );
+}
+
+/// `merge_completed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MergeCompletedEvent {
+    pub merge_id: u64,
+    pub source_vault: Address,
+    pub target_vault: Address,
+}
+
+impl VaultEvent for MergeCompletedEvent {
+    const NAME: &'static str = "merge_completed";
+}
+
+/// Emit when a vault merge completes
+pub fn emit_merge_completed(
+    env: &Env,
+    merge_id: u64,
+    source_vault: &Address,
+    target_vault: &Address,
+) {
+    publish(
+        env,
+        MergeCompletedEvent {
+            merge_id,
+            source_vault: source_vault.clone(),
+            target_vault: target_vault.clone(),
+        },
+    );
+}
+
+/// `merge_aborted` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MergeAbortedEvent {
+    pub merge_id: u64,
+    pub source_vault: Address,
+}
+
+impl VaultEvent for MergeAbortedEvent {
+    const NAME: &'static str = "merge_aborted";
+}
+
+/// Emit when a vault merge is aborted
+pub fn emit_merge_aborted(env: &Env, merge_id: u64, source_vault: &Address) {
+    publish(
+        env,
+        MergeAbortedEvent {
+            merge_id,
+            source_vault: source_vault.clone(),
+        },
+    );
+}
+
+/// `vesting_created` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingCreatedEvent {
+    pub schedule_id: u64,
+    pub beneficiary: Address,
+    pub token: Address,
+    pub total: i128,
+    pub cliff_ledger: u32,
+    pub end_ledger: u32,
+}
+
+impl VaultEvent for VestingCreatedEvent {
+    const NAME: &'static str = "vesting_created";
+}
+
+/// Emit when a vesting schedule is created
+pub fn emit_vesting_created(
+    env: &Env,
+    schedule_id: u64,
+    beneficiary: &Address,
+    token: &Address,
+    total: i128,
+    cliff_ledger: u32,
+    end_ledger: u32,
+) {
+    publish(
+        env,
+        VestingCreatedEvent {
+            schedule_id,
+            beneficiary: beneficiary.clone(),
+            token: token.clone(),
+            total,
+            cliff_ledger,
+            end_ledger,
+        },
+    );
+}
+
+/// `vesting_claimed` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingClaimedEvent {
+    pub schedule_id: u64,
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub total_claimed: i128,
+}
+
+impl VaultEvent for VestingClaimedEvent {
+    const NAME: &'static str = "vesting_claimed";
+}
+
+/// Emit when a beneficiary claims vested tokens
+pub fn emit_vesting_claimed(
+    env: &Env,
+    schedule_id: u64,
+    beneficiary: &Address,
+    amount: i128,
+    total_claimed: i128,
+) {
+    publish(
+        env,
+        VestingClaimedEvent {
+            schedule_id,
+            beneficiary: beneficiary.clone(),
+            amount,
+            total_claimed,
+        },
+    );
+}
+
+/// `vesting_cancelled` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingCancelledEvent {
+    pub schedule_id: u64,
+    pub admin: Address,
+    pub vested_paid: i128,
+    pub unvested_returned: i128,
+}
+
+impl VaultEvent for VestingCancelledEvent {
+    const NAME: &'static str = "vesting_cancelled";
+}
+
+/// Emit when a vesting schedule is cancelled
+pub fn emit_vesting_cancelled(
+    env: &Env,
+    schedule_id: u64,
+    admin: &Address,
+    vested_paid: i128,
+    unvested_returned: i128,
+) {
+    publish(
+        env,
+        VestingCancelledEvent {
+            schedule_id,
+            admin: admin.clone(),
+            vested_paid,
+            unvested_returned,
+        },
+    );
+}
+
+/// `holiday_calendar_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HolidayCalendarSetEvent {
+    pub holiday_count: u32,
+}
+
+impl VaultEvent for HolidayCalendarSetEvent {
+    const NAME: &'static str = "holiday_calendar_set";
+}
+
+/// Emit when the holiday calendar is replaced
+pub fn emit_holiday_calendar_set(env: &Env, holiday_count: u32) {
+    publish(env, HolidayCalendarSetEvent { holiday_count });
+}
+
+/// `signer_tier_set` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerTierSetEvent {
+    pub signer: Address,
+    pub tier: SignerTier,
+}
+
+impl VaultEvent for SignerTierSetEvent {
+    const NAME: &'static str = "signer_tier_set";
+}
+
+/// Emit when a signer's approval tier is set
+pub fn emit_signer_tier_set(env: &Env, signer: &Address, tier: &SignerTier) {
+    publish(
+        env,
+        SignerTierSetEvent {
+            signer: signer.clone(),
+            tier: tier.clone(),
+        },
+    );
+}
+
+/// `full_quorum_threshold` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FullQuorumThresholdEvent {
+    pub threshold: i128,
+}
+
+impl VaultEvent for FullQuorumThresholdEvent {
+    const NAME: &'static str = "full_quorum_threshold";
+}
+
+/// Emit when the amount above which every signer must approve is set
+pub fn emit_full_quorum_threshold(env: &Env, threshold: i128) {
+    publish(env, FullQuorumThresholdEvent { threshold });
+}
+
+/// `proposal_status` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalStatusEvent {
+    pub proposal_id: u64,
+    pub status: ProposalStatus,
+}
+
+impl VaultEvent for ProposalStatusEvent {
+    const NAME: &'static str = "proposal_status";
+}
+
+/// Emit whenever a proposal is stored with a different status than before
+pub fn emit_proposal_status(env: &Env, proposal_id: u64, status: &ProposalStatus) {
+    publish(
+        env,
+        ProposalStatusEvent {
+            proposal_id,
+            status: status.clone(),
+        },
+    );
+}
+
+/// `daily_spent_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DailySpentUpdatedEvent {
+    pub day: u64,
+    pub spent: i128,
+}
+
+impl VaultEvent for DailySpentUpdatedEvent {
+    const NAME: &'static str = "daily_spent_updated";
+}
+
+/// Emit the new total of a daily spending bucket after it is charged or refunded
+pub fn emit_daily_spent_updated(env: &Env, day: u64, spent: i128) {
+    publish(env, DailySpentUpdatedEvent { day, spent });
+}
+
+/// `weekly_spent_updated` event payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WeeklySpentUpdatedEvent {
+    pub week: u64,
+    pub spent: i128,
+}
+
+impl VaultEvent for WeeklySpentUpdatedEvent {
+    const NAME: &'static str = "weekly_spent_updated";
+}
+
+/// Emit the new total of a weekly spending bucket after it is charged or refunded
+pub fn emit_weekly_spent_updated(env: &Env, week: u64, spent: i128) {
+    publish(env, WeeklySpentUpdatedEvent { week, spent });
+}
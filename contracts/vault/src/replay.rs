@@ -0,0 +1,698 @@
+//! VaultDAO - Event Replay
+//!
+//! Rebuilds an in-memory model of the vault from its event stream and
+//! compares it with what the contract's getters report. An indexer that
+//! keeps a `VaultModel` up to date from decoded events should agree with
+//! contract storage at every point; `VaultModel::check` lists where it does
+//! not. Built alongside `decode`, with the `decode` feature or in tests.
+
+extern crate std;
+
+use crate::decode::{self, DecodeError, Event};
+use crate::types::{EscrowStatus, ProposalStatus, StreamStatus, SubscriptionStatus, VoteChoice};
+use crate::VaultDAOClient;
+use soroban_sdk::xdr::ContractEvent;
+use soroban_sdk::{Address, Env, Symbol};
+use std::collections::BTreeMap;
+use std::format;
+use std::string::String;
+use std::vec::Vec;
+
+/// Replayed view of one proposal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProposalModel {
+    pub proposer: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub status: ProposalStatus,
+    pub approvals: Vec<Address>,
+    pub abstentions: Vec<Address>,
+    /// Memo, known once an amendment has published it
+    pub memo: Option<Symbol>,
+    pub amendments: u32,
+    pub cancellation: Option<CancellationModel>,
+}
+
+/// Replayed view of a proposal's cancellation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CancellationModel {
+    pub cancelled_by: Address,
+    pub reason: Symbol,
+    pub refunded_amount: i128,
+}
+
+/// Replayed view of one stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StreamModel {
+    pub sender: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub total_amount: i128,
+    pub rate: i128,
+    pub claimed_amount: i128,
+    pub status: StreamStatus,
+}
+
+/// Replayed view of one escrow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscrowModel {
+    pub funder: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub total_amount: i128,
+    pub released_amount: i128,
+    pub status: EscrowStatus,
+}
+
+/// Replayed view of one subscription.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubscriptionModel {
+    pub subscriber: Address,
+    pub tier: u32,
+    pub amount_per_period: i128,
+    pub total_payments: u32,
+    pub status: SubscriptionStatus,
+}
+
+/// Vault state reconstructed purely from events.
+#[derive(Clone, Debug, Default)]
+pub struct VaultModel {
+    pub proposals: BTreeMap<u64, ProposalModel>,
+    pub daily_spent: BTreeMap<u64, i128>,
+    pub weekly_spent: BTreeMap<u64, i128>,
+    pub streams: BTreeMap<u64, StreamModel>,
+    pub escrows: BTreeMap<u64, EscrowModel>,
+    /// Role discriminant per address, as published in `role_assigned`.
+    pub roles: BTreeMap<Address, u32>,
+    /// Directly granted permission discriminants per address.
+    pub grants: BTreeMap<Address, Vec<u32>>,
+    /// Custom role names assigned per address.
+    pub custom_roles: BTreeMap<Address, Vec<Symbol>>,
+    pub subscriptions: BTreeMap<u64, SubscriptionModel>,
+}
+
+/// What a mismatch refers to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Subject {
+    Proposal(u64),
+    DailySpent(u64),
+    WeeklySpent(u64),
+    Stream(u64),
+    Escrow(u64),
+    Role(Address),
+    Grants(Address),
+    CustomRoles(Address),
+    Subscription(u64),
+}
+
+/// A field on which the replayed model and contract storage disagree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub subject: Subject,
+    pub field: &'static str,
+    /// Value in the replayed model (`Debug` formatted).
+    pub model: String,
+    /// Value reported by the contract (`Debug` formatted).
+    pub contract: String,
+}
+
+fn insert_unique(list: &mut Vec<Address>, addr: &Address) {
+    if !list.contains(addr) {
+        list.push(addr.clone());
+    }
+}
+
+fn vote_list(proposal: &mut ProposalModel, vote: u32) -> Option<&mut Vec<Address>> {
+    if vote == VoteChoice::Approve as u32 {
+        Some(&mut proposal.approvals)
+    } else if vote == VoteChoice::Abstain as u32 {
+        Some(&mut proposal.abstentions)
+    } else {
+        None
+    }
+}
+
+fn stream_status(code: u32) -> Option<StreamStatus> {
+    match code {
+        0 => Some(StreamStatus::Active),
+        1 => Some(StreamStatus::Paused),
+        2 => Some(StreamStatus::Cancelled),
+        3 => Some(StreamStatus::Completed),
+        _ => None,
+    }
+}
+
+impl VaultModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode and apply raw contract events in the order they were published.
+    /// Events from other contracts must be filtered out by the caller.
+    pub fn apply_contract_events<'a>(
+        &mut self,
+        env: &Env,
+        events: impl IntoIterator<Item = &'a ContractEvent>,
+    ) -> Result<(), DecodeError> {
+        for event in events {
+            self.apply(&decode::decode_contract_event(env, event)?);
+        }
+        Ok(())
+    }
+
+    /// Fold one decoded event into the model. Events that do not affect the
+    /// modelled state are ignored.
+    pub fn apply(&mut self, event: &Event) {
+        match event {
+            Event::ProposalCreated(e) => {
+                self.proposals.insert(
+                    e.proposal_id,
+                    ProposalModel {
+                        proposer: e.proposer.clone(),
+                        recipient: e.recipient.clone(),
+                        token: e.token.clone(),
+                        amount: e.amount,
+                        status: ProposalStatus::Pending,
+                        approvals: Vec::new(),
+                        abstentions: Vec::new(),
+                        memo: None,
+                        amendments: 0,
+                        cancellation: None,
+                    },
+                );
+            }
+            Event::ProposalStatus(e) => {
+                if let Some(p) = self.proposals.get_mut(&e.proposal_id) {
+                    p.status = e.status.clone();
+                }
+            }
+            Event::ProposalApproved(e) => {
+                if let Some(p) = self.proposals.get_mut(&e.proposal_id) {
+                    insert_unique(&mut p.approvals, &e.approver);
+                }
+            }
+            Event::ProposalAbstained(e) => {
+                if let Some(p) = self.proposals.get_mut(&e.proposal_id) {
+                    insert_unique(&mut p.abstentions, &e.abstainer);
+                }
+            }
+            Event::VoteChanged(e) => {
+                if let Some(p) = self.proposals.get_mut(&e.proposal_id) {
+                    if let Some(list) = vote_list(p, e.old_vote) {
+                        list.retain(|a| a != &e.voter);
+                    }
+                    if let Some(list) = vote_list(p, e.new_vote) {
+                        insert_unique(list, &e.voter);
+                    }
+                }
+            }
+            Event::ProposalAmended(e) => {
+                if let Some(p) = self.proposals.get_mut(&e.proposal_id) {
+                    p.recipient = e.new_recipient.clone();
+                    p.amount = e.new_amount;
+                    p.memo = Some(e.new_memo.clone());
+                    p.amendments += 1;
+                    p.approvals.clear();
+                    p.abstentions.clear();
+                }
+            }
+            Event::ProposalCancelled(e) => {
+                if let Some(p) = self.proposals.get_mut(&e.proposal_id) {
+                    p.cancellation = Some(CancellationModel {
+                        cancelled_by: e.cancelled_by.clone(),
+                        reason: e.reason.clone(),
+                        refunded_amount: e.refunded_amount,
+                    });
+                }
+            }
+            Event::DailySpentUpdated(e) => {
+                self.daily_spent.insert(e.day, e.spent);
+            }
+            Event::WeeklySpentUpdated(e) => {
+                self.weekly_spent.insert(e.week, e.spent);
+            }
+            Event::StreamCreated(e) => {
+                self.streams.insert(
+                    e.stream_id,
+                    StreamModel {
+                        sender: e.sender.clone(),
+                        recipient: e.recipient.clone(),
+                        token: e.token.clone(),
+                        total_amount: e.total_amount,
+                        rate: e.rate,
+                        claimed_amount: 0,
+                        status: StreamStatus::Active,
+                    },
+                );
+            }
+            Event::StreamClaimed(e) => {
+                if let Some(s) = self.streams.get_mut(&e.stream_id) {
+                    s.claimed_amount += e.amount;
+                }
+            }
+            Event::StreamStatusUpdated(e) => {
+                if let (Some(s), Some(status)) =
+                    (self.streams.get_mut(&e.stream_id), stream_status(e.status))
+                {
+                    s.status = status;
+                }
+            }
+            Event::StreamRateAdjusted(e) => {
+                if let Some(s) = self.streams.get_mut(&e.stream_id) {
+                    s.rate = e.new_rate;
+                }
+            }
+            Event::EscrowCreated(e) => {
+                self.escrows.insert(
+                    e.escrow_id,
+                    EscrowModel {
+                        funder: e.funder.clone(),
+                        recipient: e.recipient.clone(),
+                        token: e.token.clone(),
+                        total_amount: e.amount,
+                        released_amount: 0,
+                        status: EscrowStatus::Active,
+                    },
+                );
+            }
+            Event::MilestoneCompleted(e) => {
+                if let Some(x) = self.escrows.get_mut(&e.escrow_id) {
+                    if e.all_complete {
+                        x.status = EscrowStatus::MilestonesComplete;
+                    }
+                }
+            }
+            Event::EscrowReleased(e) => {
+                if let Some(x) = self.escrows.get_mut(&e.escrow_id) {
+                    x.released_amount += e.amount;
+                    x.status = if e.is_refund {
+                        EscrowStatus::Refunded
+                    } else {
+                        EscrowStatus::Released
+                    };
+                }
+            }
+            Event::EscrowDisputed(e) => {
+                if let Some(x) = self.escrows.get_mut(&e.escrow_id) {
+                    x.status = EscrowStatus::Disputed;
+                }
+            }
+            Event::EscrowDisputeResolved(e) => {
+                // The arbitrator pays out everything still held, one way or the other.
+                if let Some(x) = self.escrows.get_mut(&e.escrow_id) {
+                    x.released_amount = x.total_amount;
+                    x.status = if e.released_to_recipient {
+                        EscrowStatus::Released
+                    } else {
+                        EscrowStatus::Refunded
+                    };
+                }
+            }
+            Event::EscrowAutoResolved(e) => {
+                if let Some(x) = self.escrows.get_mut(&e.escrow_id) {
+                    x.released_amount += e.amount_refunded;
+                    x.status = EscrowStatus::Refunded;
+                }
+            }
+            Event::RoleAssigned(e) => {
+                self.roles.insert(e.addr.clone(), e.role);
+            }
+            Event::PermissionGranted(e) => {
+                let grants = self.grants.entry(e.target.clone()).or_default();
+                if !grants.contains(&e.permission) {
+                    grants.push(e.permission);
+                }
+            }
+            Event::PermissionRevoked(e) => {
+                if let Some(grants) = self.grants.get_mut(&e.target) {
+                    grants.retain(|p| *p != e.permission);
+                }
+            }
+            Event::CustomRoleAssignment(e) => {
+                let held = self.custom_roles.entry(e.target.clone()).or_default();
+                if e.assigned {
+                    if !held.contains(&e.name) {
+                        held.push(e.name.clone());
+                    }
+                } else {
+                    held.retain(|n| n != &e.name);
+                }
+            }
+            Event::SubscriptionCreated(e) => {
+                self.subscriptions.insert(
+                    e.subscription_id,
+                    SubscriptionModel {
+                        subscriber: e.subscriber.clone(),
+                        tier: e.tier,
+                        amount_per_period: e.amount,
+                        total_payments: 1,
+                        status: SubscriptionStatus::Active,
+                    },
+                );
+            }
+            Event::SubscriptionRenewed(e) => {
+                // Also published when an expired subscription is reactivated.
+                if let Some(s) = self.subscriptions.get_mut(&e.subscription_id) {
+                    s.total_payments = e.payment_number;
+                    s.status = SubscriptionStatus::Active;
+                }
+            }
+            Event::SubscriptionUpgraded(e) => {
+                if let Some(s) = self.subscriptions.get_mut(&e.subscription_id) {
+                    s.tier = e.new_tier;
+                    s.amount_per_period = e.new_amount;
+                }
+            }
+            Event::SubscriptionCancelled(e) => {
+                self.set_subscription_status(e.subscription_id, SubscriptionStatus::Cancelled);
+            }
+            Event::SubscriptionExpired(e) => {
+                self.set_subscription_status(e.subscription_id, SubscriptionStatus::Expired);
+            }
+            Event::SubscriptionPaused(e) => {
+                self.set_subscription_status(e.subscription_id, SubscriptionStatus::Paused);
+            }
+            Event::SubscriptionResumed(e) => {
+                self.set_subscription_status(e.subscription_id, SubscriptionStatus::Active);
+            }
+            _ => {}
+        }
+    }
+
+    fn set_subscription_status(&mut self, id: u64, status: SubscriptionStatus) {
+        if let Some(s) = self.subscriptions.get_mut(&id) {
+            s.status = status;
+        }
+    }
+
+    /// Compare every modelled entity with the contract's getters and return
+    /// each disagreeing field. Proposals the contract lists but the model
+    /// never saw created are reported with field `exists`.
+    pub fn check(&self, client: &VaultDAOClient<'_>) -> Vec<Mismatch> {
+        let mut out = Vec::new();
+        let mut diff = |subject: &Subject, field: &'static str, model: String, contract: String| {
+            if model != contract {
+                out.push(Mismatch {
+                    subject: subject.clone(),
+                    field,
+                    model,
+                    contract,
+                });
+            }
+        };
+
+        for (id, m) in &self.proposals {
+            let subject = Subject::Proposal(*id);
+            let Ok(Ok(p)) = client.try_get_proposal(id) else {
+                diff(&subject, "exists", "true".into(), "false".into());
+                continue;
+            };
+            diff(
+                &subject,
+                "proposer",
+                format!("{:?}", m.proposer),
+                format!("{:?}", p.proposer),
+            );
+            diff(
+                &subject,
+                "recipient",
+                format!("{:?}", m.recipient),
+                format!("{:?}", p.recipient),
+            );
+            diff(
+                &subject,
+                "token",
+                format!("{:?}", m.token),
+                format!("{:?}", p.token),
+            );
+            diff(
+                &subject,
+                "amount",
+                format!("{}", m.amount),
+                format!("{}", p.amount),
+            );
+            diff(
+                &subject,
+                "status",
+                format!("{:?}", m.status),
+                format!("{:?}", p.status),
+            );
+            diff(
+                &subject,
+                "approvals",
+                format!("{:?}", sorted(m.approvals.iter().cloned())),
+                format!("{:?}", sorted(p.approvals.iter())),
+            );
+            diff(
+                &subject,
+                "abstentions",
+                format!("{:?}", sorted(m.abstentions.iter().cloned())),
+                format!("{:?}", sorted(p.abstentions.iter())),
+            );
+            if let Some(memo) = &m.memo {
+                diff(
+                    &subject,
+                    "memo",
+                    format!("{memo:?}"),
+                    format!("{:?}", p.memo),
+                );
+            }
+            diff(
+                &subject,
+                "amendments",
+                format!("{}", m.amendments),
+                format!("{}", client.get_amendment_count(id)),
+            );
+            let record = client
+                .try_get_cancellation_record(id)
+                .ok()
+                .and_then(|r| r.ok())
+                .map(|r| CancellationModel {
+                    cancelled_by: r.cancelled_by,
+                    reason: r.reason,
+                    refunded_amount: r.refunded_amount,
+                });
+            diff(
+                &subject,
+                "cancellation",
+                format!("{:?}", m.cancellation),
+                format!("{record:?}"),
+            );
+        }
+        let mut offset = 0;
+        loop {
+            let page = client.list_proposals(&offset, &50);
+            for p in page.iter() {
+                if !self.proposals.contains_key(&p.id) {
+                    diff(
+                        &Subject::Proposal(p.id),
+                        "exists",
+                        "false".into(),
+                        "true".into(),
+                    );
+                }
+            }
+            if page.len() < 50 {
+                break;
+            }
+            offset += 50;
+        }
+
+        for (day, spent) in &self.daily_spent {
+            let actual = client.get_daily_spent(day);
+            diff(
+                &Subject::DailySpent(*day),
+                "spent",
+                format!("{spent}"),
+                format!("{actual}"),
+            );
+        }
+        for (week, spent) in &self.weekly_spent {
+            let actual = client.get_weekly_spent(week);
+            diff(
+                &Subject::WeeklySpent(*week),
+                "spent",
+                format!("{spent}"),
+                format!("{actual}"),
+            );
+        }
+
+        for (id, m) in &self.streams {
+            let subject = Subject::Stream(*id);
+            let Ok(Ok(s)) = client.try_get_stream(id) else {
+                diff(&subject, "exists", "true".into(), "false".into());
+                continue;
+            };
+            diff(
+                &subject,
+                "sender",
+                format!("{:?}", m.sender),
+                format!("{:?}", s.sender),
+            );
+            diff(
+                &subject,
+                "recipient",
+                format!("{:?}", m.recipient),
+                format!("{:?}", s.recipient),
+            );
+            diff(
+                &subject,
+                "token",
+                format!("{:?}", m.token),
+                format!("{:?}", s.token_addr),
+            );
+            diff(
+                &subject,
+                "total_amount",
+                format!("{}", m.total_amount),
+                format!("{}", s.total_amount),
+            );
+            diff(
+                &subject,
+                "rate",
+                format!("{}", m.rate),
+                format!("{}", s.rate),
+            );
+            diff(
+                &subject,
+                "claimed_amount",
+                format!("{}", m.claimed_amount),
+                format!("{}", s.claimed_amount),
+            );
+            diff(
+                &subject,
+                "status",
+                format!("{:?}", m.status),
+                format!("{:?}", s.status),
+            );
+        }
+
+        for (id, m) in &self.escrows {
+            let subject = Subject::Escrow(*id);
+            let Ok(Ok(x)) = client.try_get_escrow_info(id) else {
+                diff(&subject, "exists", "true".into(), "false".into());
+                continue;
+            };
+            diff(
+                &subject,
+                "funder",
+                format!("{:?}", m.funder),
+                format!("{:?}", x.funder),
+            );
+            diff(
+                &subject,
+                "recipient",
+                format!("{:?}", m.recipient),
+                format!("{:?}", x.recipient),
+            );
+            diff(
+                &subject,
+                "token",
+                format!("{:?}", m.token),
+                format!("{:?}", x.token),
+            );
+            diff(
+                &subject,
+                "total_amount",
+                format!("{}", m.total_amount),
+                format!("{}", x.total_amount),
+            );
+            diff(
+                &subject,
+                "released_amount",
+                format!("{}", m.released_amount),
+                format!("{}", x.released_amount),
+            );
+            diff(
+                &subject,
+                "status",
+                format!("{:?}", m.status),
+                format!("{:?}", x.status),
+            );
+        }
+
+        for (addr, role) in &self.roles {
+            let actual = client.get_role(addr) as u32;
+            diff(
+                &Subject::Role(addr.clone()),
+                "role",
+                format!("{role}"),
+                format!("{actual}"),
+            );
+        }
+
+        for (addr, grants) in &self.grants {
+            let mut model = grants.clone();
+            model.sort();
+            let mut actual: Vec<u32> = client
+                .get_permissions(addr)
+                .iter()
+                .map(|g| g.permission as u32)
+                .collect();
+            actual.sort();
+            diff(
+                &Subject::Grants(addr.clone()),
+                "permissions",
+                format!("{model:?}"),
+                format!("{actual:?}"),
+            );
+        }
+
+        for (addr, held) in &self.custom_roles {
+            let actual: Vec<Symbol> = client.get_custom_roles_of(addr).iter().collect();
+            diff(
+                &Subject::CustomRoles(addr.clone()),
+                "names",
+                format!("{held:?}"),
+                format!("{actual:?}"),
+            );
+        }
+
+        for (id, m) in &self.subscriptions {
+            let subject = Subject::Subscription(*id);
+            let Ok(Ok(s)) = client.try_get_subscription(id) else {
+                diff(&subject, "exists", "true".into(), "false".into());
+                continue;
+            };
+            diff(
+                &subject,
+                "subscriber",
+                format!("{:?}", m.subscriber),
+                format!("{:?}", s.subscriber),
+            );
+            diff(
+                &subject,
+                "tier",
+                format!("{}", m.tier),
+                format!("{}", s.tier as u32),
+            );
+            diff(
+                &subject,
+                "amount_per_period",
+                format!("{}", m.amount_per_period),
+                format!("{}", s.amount_per_period),
+            );
+            diff(
+                &subject,
+                "total_payments",
+                format!("{}", m.total_payments),
+                format!("{}", s.total_payments),
+            );
+            diff(
+                &subject,
+                "status",
+                format!("{:?}", m.status),
+                format!("{:?}", s.status),
+            );
+        }
+
+        out
+    }
+}
+
+fn sorted(addrs: impl IntoIterator<Item = Address>) -> Vec<Address> {
+    let mut v: Vec<Address> = addrs.into_iter().collect();
+    v.sort();
+    v
+}
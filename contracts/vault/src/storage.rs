@@ -23,19 +23,25 @@ use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 use crate::errors::VaultError;
 use crate::types::{
-    AuditCheckpoint, AuditEntry, BridgeConfig, CapabilityToken, ColdSignatureRecord,
-    ColdSignerConfig, Comment, Config, CostModel, CrossChainProposal, DeadLetterRecord,
+    ActiveSet, AmountStats, AnomalyAssessment, AnomalyPolicy, AuditCheckpoint, AuditEntry,
+    AuditTarget, BridgeConfig, BudgetPeriod, CapabilityToken, ColdSignatureRecord,
+    ColdSignerConfig, Comment, Config, CostModel, CrossChainProposal, CustomRole, DeadLetterRecord,
     DelegatedPermission, Delegation, DelegationHistory, DexConfig, Escrow, ExecutionFeeEstimate,
     ExecutionSnapshot, FeeStructure, FundingRound, FundingRoundConfig, GasConfig,
-    GasPriceOracleConfig, GovernanceProposal, HolidayCalendar, HookEventType, HookRegistration,
-    InsuranceClaim, InsuranceConfig, InsuranceVotingConfig, ListMode, MergeRecord,
-    MultiPhaseProposal, NotificationPreferences, NotificationPrefs, PauseCooldownConfig,
-    PauseState, PermissionGrant, Proposal, ProposalAmendment, ProposalStatus, ProposalTemplate,
-    RecoveryProposal, Reputation, ReputationConfig, RetryState, Role, RoleAssignment,
-    ScopedDelegation, SignerTier, StakeRecord, StakingConfig, StreamRateWindow, Subscription,
-    SwapProposal, SwapResult, Tag, TemplateVarRef, TimeWeightedConfig, TokenLock,
-    TokenSpendingConfig, VarTemplate, VaultMetrics, VelocityConfig, VestingSchedule,
-    VotingStrategy, WhitelistEntry,
+    GasPriceOracleConfig, GovernanceProposal, GrantConstraints, GrantUsage, GuardianAction,
+    GuardianActionRecord, GuardianCouncil, HolidayCalendar, HookEventType, HookRegistration,
+    ImpactScore, ImpactScoreModel, InactivityPolicy, InsuranceClaim, InsuranceConfig,
+    InsuranceVotingConfig, KeeperBudget, LiabilityKind, ListMode, MergeRecord, MultiPhaseProposal,
+    NotificationPreferences, NotificationPrefs, OptimisticConfig, OptimisticState,
+    ParamChangeProposal, PauseCooldownConfig, PauseState, PendingAdminRotation, PermissionGrant,
+    PolicyHook, Proposal, ProposalAmendment, ProposalSafeguards, ProposalStatus, ProposalTemplate,
+    RecipientLimitConfig, RecoveryProposal, Reputation, ReputationConfig, RetryState, Role,
+    RoleAssignment, ScopedDelegation, ScreeningConfig, ScreeningResult, SignerElection,
+    SignerRemovalProposal, SignerTerm, SignerTier, SolvencyPolicy, SpendingWindowMode, StakeRecord,
+    StakingConfig, StreamRateWindow, Subscription, SwapProposal, SwapResult, Tag, TagBudget,
+    TagBudgetReservation, TemplateVarRef, TimeWeightedConfig, TokenLock, TokenSpendingConfig,
+    TopicDelegation, VarTemplate, VaultMetrics, VelocityConfig, VestingSchedule, VotingStrategy,
+    WhitelistEntry,
 };
 use crate::types_balance_snapshot::BalanceSnapshot;
 
@@ -83,7 +89,7 @@ pub enum DataKey {
     AuditEntry(u64),
     /// Next audit entry ID counter
     NextAuditId,
-    /// Last audit entry hash
+    /// Last audit entry hash of the legacy 64-bit chain (u64)
     LastAuditHash,
     /// Proposal IPFS attachment hashes -> Vec<String>
     Attachments(u64),
@@ -127,6 +133,20 @@ pub enum DataKey {
     TokenWeeklySpent(Address, u64),
     /// Supported token spending config by token address -> TokenSpendingConfig
     TokenSpendingConfig(Address),
+    // ---- Rolling-window spending limits ----
+    /// Hourly outflow sub-buckets for the vault-wide rolling window -> Map<u64, i128>
+    RollingSpent,
+    /// Hourly outflow sub-buckets for a token's rolling window (token) -> Map<u64, i128>
+    TokenRollingSpent(Address),
+    /// Rolling sub-bucket a proposal's spend was reserved in (proposal_id) -> u64
+    ProposalSpendSlot(u64),
+    // ---- Per-recipient caps and first-payment cool-off ----
+    /// Daily amounts received/reserved by a recipient (recipient) -> Map<u64, i128>
+    RecipientReceived(Address),
+    /// Ledger of the first completed payment to a recipient (recipient) -> u64
+    RecipientFirstPaid(Address),
+    /// Extra approval/timelock requirements attached to a proposal (proposal_id) -> ProposalSafeguards
+    ProposalSafeguards(u64),
     /// Voting power delegation (delegator) -> Delegation
     Delegation(Address),
     /// Delegation history for an address -> Vec<DelegationHistory>
@@ -161,6 +181,29 @@ pub enum DataKey {
     /// Tag name uniqueness within a parent scope.
     /// Key: parent_id (0 = root scope) -> Map<Symbol, u64> (name -> tag_id)
     HTagNameScope(u64),
+    // ---- Tag budget envelopes ----
+    /// Budget envelope attached to a hierarchical tag (tag_id) -> TagBudget
+    TagBudget(u64),
+    /// Tag IDs that carry a budget -> Vec<u64>
+    BudgetedTags,
+    /// Amount reserved in an envelope (tag_id, BudgetPeriod, period_start_day) -> i128
+    TagBudgetUsed(u64, u32, u64),
+    /// Envelope reservations held by a proposal (proposal_id) -> Vec<TagBudgetReservation>
+    ProposalBudgetReservations(u64),
+    // ---- Committed liabilities register ----
+    /// Outstanding obligations (token, LiabilityKind) -> i128
+    Liability(Address, u32),
+    // ---- Reject votes ----
+    /// Signers who voted to reject a proposal (proposal_id) -> Vec<Address>
+    ProposalRejections(u64),
+    // ---- Optimistic execution ----
+    /// Challenge state of an optimistic proposal (proposal_id) -> OptimisticState
+    OptimisticState(u64),
+    // ---- Topic-scoped delegation ----
+    /// Topic delegations set by a signer (delegator) -> Vec<TopicDelegation>
+    TopicDelegations(Address),
+    /// Signers who cast their own vote on a proposal (proposal_id) -> Vec<Address>
+    ProposalDirectVoters(u64),
     // ---- Issue #1086: Cold Storage Signatures ----
     /// Cold signature record (proposal_id, signer_pubkey_hash) -> ColdSignatureRecord
     ColdSig(u64, soroban_sdk::BytesN<32>),
@@ -184,6 +227,8 @@ pub enum DataKey {
     // ---- Issue #1087: Audit Trail Compression ----
     /// Audit checkpoint by ID -> AuditCheckpoint
     AuditCheckpoint(u64),
+    /// SHA-256 hash of the latest audit entry -> BytesN<32>
+    AuditChainHead,
     /// Next audit checkpoint ID counter -> u64
     NextAuditCheckpointId,
     // ---- Issue #1100: Vault Merge Protocol ----
@@ -216,6 +261,17 @@ pub enum CounterKey {
     FundingRound = 6,
     Batch = 7,
     ScopedDelegation = 8,
+    SignerElection = 9,
+    SignerRemoval = 10,
+    GuardianAction = 11,
+}
+
+/// Neighbours of an open record in the active-id list of its kind (0 = none).
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug)]
+pub struct ActiveLink {
+    pub prev: u64,
+    pub next: u64,
 }
 
 #[contracttype(export = false)]
@@ -331,6 +387,12 @@ pub enum FeatureKey {
     Permissions(Address),
     /// Delegated permissions (delegatee, delegator, permission as u32) -> DelegatedPermission
     DelegatedPermission(Address, Address, u32),
+    /// Custom role definition by name -> CustomRole
+    CustomRole(Symbol),
+    /// Names of all defined custom roles -> Vec<Symbol>
+    CustomRoleIndex,
+    /// Custom roles held by an address -> Vec<Symbol>
+    CustomRoleAssignments(Address),
     /// Auto-complete flag for a stream (stream id) -> bool (Issue #1359)
     StreamAutoComplete(u64),
     /// Subscription by ID -> Subscription
@@ -403,10 +465,40 @@ pub enum FeatureKey {
     GovernanceProposal(u64),
     /// Governance supermajority threshold (percentage) -> u32
     GovernanceThreshold,
-    /// Active governance proposal count -> u32
+    /// Legacy active governance proposal count, dropped on the next write -> u32
     ActiveGovernanceCount,
+    /// IDs of governance and parameter change proposals counted as in flight -> Vec<u64>
+    ActiveGovernanceIds,
     /// Next governance proposal ID -> u64
     NextGovernanceId,
+    /// Multi-field parameter change proposal by ID -> ParamChangeProposal
+    ParamChangeProposal(u64),
+    /// Signer election by ID -> SignerElection
+    SignerElection(u64),
+    /// ID of the election not yet applied or failed -> u64
+    ActiveSignerElection,
+    /// Candidates chosen by a voter (election_id, voter) -> Vec<Address>
+    ElectionBallot(u64, Address),
+    /// Term of a signer (Address) -> SignerTerm
+    SignerTerm(Address),
+    /// Admin rotation waiting out its delay -> PendingAdminRotation
+    PendingAdminRotation,
+    /// Signer inactivity policy -> InactivityPolicy
+    InactivityPolicy,
+    /// Inactive-signer removal proposal by ID -> SignerRemovalProposal
+    SignerRemoval(u64),
+    /// Guardian council membership and threshold -> GuardianCouncil
+    GuardianCouncil,
+    /// Guardian action by ID -> GuardianActionRecord
+    GuardianActionRecord(u64),
+    /// Guardian action currently holding a target -> u64 (action ID)
+    GuardianHold(GuardianAction),
+    /// Period usage of a capped grant (holder, permission) -> GrantUsage
+    GrantUsage(Address, u32),
+    /// Constraints of a direct grant (holder, permission) -> GrantConstraints
+    GrantConstraints(Address, u32),
+    /// Proposal already charged to a capped grant (holder, permission, proposal) -> bool
+    GrantCharged(Address, u32, u64),
     /// Deadline extension count per proposal -> u32
     DeadlineExtensionCount(u64),
     /// Staking tier for a proposer (Address) -> u32
@@ -422,6 +514,60 @@ pub enum FeatureKey {
     KeeperHooks(u32),
     /// Total keeper hook count across all event types -> u32
     KeeperHookCount,
+    /// Keeper bounty budget -> KeeperBudget
+    KeeperBudget,
+    // ---- Policy execution hooks ----
+    /// Registered policy hooks -> Vec<PolicyHook>
+    PolicyHooks,
+    /// Maximum policy hook calls per execution -> u32
+    HookCallBudget,
+    /// Extra approvals already demanded of a proposal by pre-hooks (proposal_id) -> u32
+    HookExtraApprovals(u64),
+    // ---- Recipient screening ----
+    /// External denylist registries -> ScreeningConfig
+    ScreeningConfig,
+    /// Per-ledger registry answer (registry, address) -> ScreeningResult (temporary)
+    ScreeningResult(Address, Address),
+    // ---- Payment anomaly detection ----
+    /// Anomaly thresholds and consequences -> AnomalyPolicy
+    AnomalyPolicy,
+    /// Executed-amount history of a recipient (recipient) -> AmountStats
+    RecipientAmountStats(Address),
+    /// Executed-amount history of a token (token) -> AmountStats
+    TokenAmountStats(Address),
+    /// Anomalies found for a proposal at creation (proposal_id) -> AnomalyAssessment
+    AnomalyAssessment(u64),
+    // ---- Impact score model ----
+    /// Configured impact scoring -> ImpactScoreModel
+    ImpactScoreModel,
+    /// Impact score computed at creation (proposal_id) -> ImpactScore
+    ProposalImpact(u64),
+    // ---- Rolling-window spending limits ----
+    /// Vault-wide spending window mode -> SpendingWindowMode
+    SpendingWindowMode,
+    /// Per-token spending window mode override (token) -> SpendingWindowMode
+    TokenSpendingWindowMode(Address),
+    // ---- Per-recipient caps and first-payment cool-off ----
+    /// Vault-wide per-recipient safeguards -> RecipientLimitConfig
+    RecipientLimitConfig,
+    /// Per-recipient cap override (recipient) -> i128
+    RecipientCap(Address),
+    // ---- Committed liabilities register ----
+    /// Handling of proposals that exceed the free balance -> SolvencyPolicy
+    SolvencyPolicy,
+    // ---- Active-id indexes ----
+    /// Membership of an open record in its kind's active list -> ActiveLink
+    ActiveLink(ActiveSet, u64),
+    /// First and last IDs of a kind's active list -> (u64, u64)
+    ActiveEnds(ActiveSet),
+    // ---- Reject votes ----
+    /// Reject votes that immediately reject a proposal (0 = disabled) -> u32
+    RejectionThreshold,
+    /// Reject votes cast by a signer (signer) -> u32
+    RejectionsGiven(Address),
+    // ---- Optimistic execution ----
+    /// Vault-wide optimistic proposal settings -> OptimisticConfig
+    OptimisticConfig,
 }
 
 /// TTL constants (in ledgers, ~5 seconds each)
@@ -472,6 +618,14 @@ pub fn get_full_quorum_threshold(env: &Env) -> i128 {
 // Vesting schedules
 // ============================================================================
 
+/// ID the next vesting schedule will receive (without allocating it).
+pub fn peek_next_vesting_id(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&VestingKey::NextId)
+        .unwrap_or(1)
+}
+
 pub fn next_vesting_id(env: &Env) -> u64 {
     let id = env
         .storage()
@@ -488,6 +642,12 @@ pub fn set_vesting_schedule(env: &Env, schedule: &VestingSchedule) {
     env.storage()
         .persistent()
         .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+    sync_active_id(
+        env,
+        ActiveSet::Vesting,
+        schedule.id,
+        vesting_is_open(schedule),
+    );
 }
 
 pub fn get_vesting_schedule(env: &Env, id: u64) -> Option<VestingSchedule> {
@@ -640,6 +800,24 @@ pub fn add_role_index_address(env: &Env, addr: &Address) {
     }
 }
 
+pub fn get_pending_admin_rotation(env: &Env) -> Option<PendingAdminRotation> {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::PendingAdminRotation)
+}
+
+pub fn set_pending_admin_rotation(env: &Env, rotation: &PendingAdminRotation) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::PendingAdminRotation, rotation);
+}
+
+pub fn remove_pending_admin_rotation(env: &Env) {
+    env.storage()
+        .instance()
+        .remove(&FeatureKey::PendingAdminRotation);
+}
+
 pub fn get_role_assignments(env: &Env) -> Vec<RoleAssignment> {
     let index = get_role_index(env);
     let mut assignments = Vec::new(env);
@@ -684,10 +862,20 @@ pub fn proposal_exists(env: &Env, id: u64) -> bool {
 
 pub fn set_proposal(env: &Env, proposal: &Proposal) {
     let key = DataKey::Proposal(proposal.id);
+    let previous: Option<Proposal> = env.storage().persistent().get(&key);
+    if previous.is_none_or(|p| p.status != proposal.status) {
+        crate::events::emit_proposal_status(env, proposal.id, &proposal.status);
+    }
     env.storage().persistent().set(&key, proposal);
     env.storage()
         .persistent()
         .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+    sync_active_id(
+        env,
+        ActiveSet::Proposal,
+        proposal.id,
+        proposal_is_open(proposal),
+    );
     // Maintain StatusIndex
     let status_u32 = proposal.status.clone() as u32;
     let idx_key = DataKey::StatusIndex(status_u32);
@@ -854,6 +1042,7 @@ pub fn add_daily_spent(env: &Env, day: u64, amount: i128) {
     let current = get_daily_spent(env, day);
     let key = DataKey::DailySpent(day);
     env.storage().temporary().set(&key, &(current + amount));
+    crate::events::emit_daily_spent_updated(env, day, current + amount);
     env.storage()
         .temporary()
         .extend_ttl(&key, DAY_IN_LEDGERS * 2, DAY_IN_LEDGERS * 2);
@@ -877,6 +1066,7 @@ pub fn try_deduct_daily_spent(env: &Env, day: u64, amount: i128) -> bool {
     }
     let key = DataKey::DailySpent(day);
     env.storage().temporary().set(&key, &(current - amount));
+    crate::events::emit_daily_spent_updated(env, day, current - amount);
     env.storage()
         .temporary()
         .extend_ttl(&key, DAY_IN_LEDGERS * 2, DAY_IN_LEDGERS * 2);
@@ -905,6 +1095,7 @@ pub fn add_weekly_spent(env: &Env, week: u64, amount: i128) {
     let current = get_weekly_spent(env, week);
     let key = DataKey::WeeklySpent(week);
     env.storage().temporary().set(&key, &(current + amount));
+    crate::events::emit_weekly_spent_updated(env, week, current + amount);
     env.storage()
         .temporary()
         .extend_ttl(&key, DAY_IN_LEDGERS * 14, DAY_IN_LEDGERS * 14);
@@ -921,6 +1112,7 @@ pub fn try_deduct_weekly_spent(env: &Env, week: u64, amount: i128) -> bool {
     }
     let key = DataKey::WeeklySpent(week);
     env.storage().temporary().set(&key, &(current - amount));
+    crate::events::emit_weekly_spent_updated(env, week, current - amount);
     env.storage()
         .temporary()
         .extend_ttl(&key, DAY_IN_LEDGERS * 14, DAY_IN_LEDGERS * 14);
@@ -952,6 +1144,12 @@ pub fn set_recurring_payment(env: &Env, payment: &crate::types::RecurringPayment
     env.storage()
         .persistent()
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+    sync_active_id(
+        env,
+        ActiveSet::Recurring,
+        payment.id,
+        recurring_is_open(payment),
+    );
 }
 
 pub fn get_recurring_payment(
@@ -1053,6 +1251,7 @@ pub fn set_streaming_payment(env: &Env, stream: &crate::types::StreamingPayment)
     env.storage()
         .persistent()
         .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+    sync_active_id(env, ActiveSet::Stream, stream.id, stream_is_open(stream));
 }
 
 pub fn get_streaming_payment(
@@ -1648,15 +1847,22 @@ pub fn increment_audit_id(env: &Env) -> u64 {
     id
 }
 
-pub fn get_last_audit_hash(env: &Env) -> u64 {
-    env.storage()
-        .instance()
-        .get(&DataKey::LastAuditHash)
-        .unwrap_or(0)
+/// Head of a pre-upgrade 64-bit audit chain, removed once read so the chain
+/// is bridged only once.
+fn take_legacy_audit_hash(env: &Env) -> Option<u64> {
+    let hash = env.storage().instance().get(&DataKey::LastAuditHash);
+    if hash.is_some() {
+        env.storage().instance().remove(&DataKey::LastAuditHash);
+    }
+    hash
 }
 
-pub fn set_last_audit_hash(env: &Env, hash: u64) {
-    env.storage().instance().set(&DataKey::LastAuditHash, &hash);
+pub fn get_audit_chain_head(env: &Env) -> Option<BytesN<32>> {
+    env.storage().instance().get(&DataKey::AuditChainHead)
+}
+
+fn set_audit_chain_head(env: &Env, hash: &BytesN<32>) {
+    env.storage().instance().set(&DataKey::AuditChainHead, hash);
 }
 // Attachments
 // ============================================================================
@@ -2241,95 +2447,128 @@ pub fn set_delegated_permission(env: &Env, delegation: &DelegatedPermission) {
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
-pub fn set_audit_entry(env: &Env, entry: &AuditEntry) {
-    let key = DataKey::AuditEntry(entry.id);
-    env.storage().persistent().set(&key, entry);
+pub fn get_custom_role(env: &Env, name: &Symbol) -> Option<CustomRole> {
     env.storage()
         .persistent()
-        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+        .get(&FeatureKey::CustomRole(name.clone()))
 }
 
-pub fn get_audit_entry(env: &Env, id: u64) -> Result<AuditEntry, VaultError> {
+pub fn set_custom_role(env: &Env, role: &CustomRole) {
+    let key = FeatureKey::CustomRole(role.name.clone());
+    env.storage().persistent().set(&key, role);
     env.storage()
         .persistent()
-        .get(&DataKey::AuditEntry(id))
-        .ok_or(VaultError::ProposalNotFound)
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
-/// Compute audit hash using SHA256 over deterministic serialization
-///
-/// Serialization format (documented for upgrade compatibility):
-/// - id: u64 (8 bytes, little-endian)
-/// - action: u32 (4 bytes, little-endian)
-/// - actor: Address bytes (32 bytes)
-/// - target: u64 (8 bytes, little-endian)
-/// - timestamp: u64 (8 bytes, little-endian)
-/// - prev_hash: u64 (8 bytes, little-endian)
-///
-/// Total: 68 bytes deterministic input to SHA256
-pub fn compute_audit_hash(
-    env: &Env,
-    id: u64,
-    action: &crate::types::AuditAction,
-    actor: &Address,
-    target: u64,
-    timestamp: u64,
-    prev_hash: u64,
-) -> u64 {
-    use soroban_sdk::Bytes;
-
-    // Create deterministic serialization (68 bytes total)
-    let mut data = Bytes::new(env);
-
-    // id: u64 (8 bytes, little-endian)
-    data.extend_from_array(&id.to_le_bytes());
+pub fn remove_custom_role(env: &Env, name: &Symbol) {
+    env.storage()
+        .persistent()
+        .remove(&FeatureKey::CustomRole(name.clone()));
+}
 
-    // action: u32 (4 bytes, little-endian)
-    data.extend_from_array(&(action.clone() as u32).to_le_bytes());
+pub fn get_custom_role_index(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::CustomRoleIndex)
+        .unwrap_or_else(|| Vec::new(env))
+}
 
-    // actor: Address XDR bytes
-    data.append(&actor.clone().to_xdr(env));
+pub fn set_custom_role_index(env: &Env, names: &Vec<Symbol>) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::CustomRoleIndex, names);
+}
 
-    // target: u64 (8 bytes, little-endian)
-    data.extend_from_array(&target.to_le_bytes());
+pub fn get_custom_role_assignments(env: &Env, addr: &Address) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::CustomRoleAssignments(addr.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
 
-    // timestamp: u64 (8 bytes, little-endian)
-    data.extend_from_array(&timestamp.to_le_bytes());
+pub fn set_custom_role_assignments(env: &Env, addr: &Address, names: &Vec<Symbol>) {
+    let key = FeatureKey::CustomRoleAssignments(addr.clone());
+    if names.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, names);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
 
-    // prev_hash: u64 (8 bytes, little-endian)
-    data.extend_from_array(&prev_hash.to_le_bytes());
+pub fn set_audit_entry(env: &Env, entry: &AuditEntry) {
+    let key = DataKey::AuditEntry(entry.id);
+    env.storage().persistent().set(&key, entry);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
 
-    // Compute SHA256 hash
-    let hash_bytes = env.crypto().sha256(&data);
+pub fn get_audit_entry(env: &Env, id: u64) -> Result<AuditEntry, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AuditEntry(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
 
-    // Convert first 8 bytes of hash to u64 (little-endian)
-    let hash_array = hash_bytes.to_array();
-    u64::from_le_bytes(hash_array[0..8].try_into().unwrap())
+/// SHA-256 over the XDR of `entry` with its `hash` field zeroed. The XDR
+/// covers every other field, including `prev_hash`, so the chain is linked.
+pub fn compute_audit_hash(env: &Env, entry: &AuditEntry) -> BytesN<32> {
+    let mut unsealed = entry.clone();
+    unsealed.hash = BytesN::from_array(env, &[0u8; 32]);
+    env.crypto().sha256(&unsealed.to_xdr(env)).into()
 }
 
 pub fn create_audit_entry(
     env: &Env,
     action: crate::types::AuditAction,
     actor: &Address,
-    target: u64,
+    target: AuditTarget,
 ) {
-    let id = increment_audit_id(env);
-    let timestamp = env.ledger().sequence() as u64;
-    let prev_hash = get_last_audit_hash(env);
-    let hash = compute_audit_hash(env, id, &action, actor, target, timestamp, prev_hash);
-
-    let entry = AuditEntry {
-        id,
+    let prev_hash = match get_audit_chain_head(env) {
+        Some(head) => head,
+        None => match take_legacy_audit_hash(env) {
+            Some(legacy) => bridge_legacy_audit_chain(env, legacy),
+            None => BytesN::from_array(env, &[0u8; 32]),
+        },
+    };
+    let mut entry = AuditEntry {
+        id: increment_audit_id(env),
         action,
         actor: actor.clone(),
         target,
-        timestamp,
+        timestamp: env.ledger().sequence() as u64,
         prev_hash,
-        hash,
+        hash: BytesN::from_array(env, &[0u8; 32]),
     };
+    entry.hash = compute_audit_hash(env, &entry);
 
     set_audit_entry(env, &entry);
-    set_last_audit_hash(env, hash);
+    set_audit_chain_head(env, &entry.hash);
+}
+
+/// Close a pre-upgrade 64-bit chain with a bridging checkpoint over entries
+/// `1..next_audit_id`. Its commitment becomes the `prev_hash` of the first
+/// SHA-256 entry. Checkpoints and entries older than the bridge keep their
+/// legacy layout and are not readable through the current types.
+fn bridge_legacy_audit_chain(env: &Env, legacy_hash: u64) -> BytesN<32> {
+    let last_id = get_next_audit_id(env).saturating_sub(1);
+    let mut data = soroban_sdk::Bytes::new(env);
+    data.extend_from_array(&legacy_hash.to_le_bytes());
+    data.extend_from_array(&last_id.to_le_bytes());
+    let commitment: BytesN<32> = env.crypto().sha256(&data).into();
+    append_audit_checkpoint(
+        env,
+        1,
+        last_id,
+        commitment.clone(),
+        commitment.clone(),
+        true,
+    );
+    commitment
 }
 
 // ============================================================================
@@ -2444,7 +2683,7 @@ pub fn increment_dead_letter_count(env: &Env) -> u64 {
 // Escrow
 // ============================================================================
 
-fn get_next_escrow_id(env: &Env) -> u64 {
+pub fn get_next_escrow_id(env: &Env) -> u64 {
     env.storage()
         .instance()
         .get(&FeatureKey::Counter(CounterKey::Escrow))
@@ -2465,6 +2704,7 @@ pub fn set_escrow(env: &Env, escrow: &Escrow) {
     env.storage()
         .persistent()
         .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+    sync_active_id(env, ActiveSet::Escrow, escrow.id, escrow_is_open(escrow));
 }
 
 pub fn get_escrow(env: &Env, id: u64) -> Result<Escrow, VaultError> {
@@ -2931,6 +3171,12 @@ pub fn set_subscription(env: &Env, sub: &Subscription) {
     env.storage()
         .persistent()
         .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+    sync_active_id(
+        env,
+        ActiveSet::Subscription,
+        sub.id,
+        subscription_is_open(sub),
+    );
 }
 
 pub fn get_insurance_claim(env: &Env, claim_id: u64) -> Result<InsuranceClaim, VaultError> {
@@ -3076,6 +3322,331 @@ pub fn refund_token_spending_limits(
         .extend_ttl(&key_weekly, DAY_IN_LEDGERS * 14, DAY_IN_LEDGERS * 14);
 }
 
+// ============================================================================
+// Rolling-window spending limits
+// ============================================================================
+
+/// Width of one rolling-window sub-bucket in seconds (1 hour).
+pub const ROLLING_SLOT_SECS: u64 = 3_600;
+/// Number of sub-buckets in the rolling daily window (24h).
+pub const ROLLING_DAY_SLOTS: u64 = 24;
+/// Number of sub-buckets in the rolling weekly window (7d). Older slots are pruned.
+pub const ROLLING_WEEK_SLOTS: u64 = 168;
+
+/// Current rolling-window sub-bucket (hour number since epoch).
+pub fn get_rolling_slot(env: &Env) -> u64 {
+    env.ledger().timestamp() / ROLLING_SLOT_SECS
+}
+
+/// Vault-wide window mode for the aggregate daily/weekly limits (default: Calendar).
+pub fn get_spending_window_mode(env: &Env) -> SpendingWindowMode {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::SpendingWindowMode)
+        .unwrap_or(SpendingWindowMode::Calendar)
+}
+
+pub fn set_spending_window_mode(env: &Env, mode: SpendingWindowMode) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::SpendingWindowMode, &mode);
+}
+
+/// Window mode for a token's own limits. Falls back to the vault-wide mode
+/// when the token has no explicit override.
+pub fn get_token_spending_window_mode(env: &Env, token: &Address) -> SpendingWindowMode {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::TokenSpendingWindowMode(token.clone()))
+        .unwrap_or_else(|| get_spending_window_mode(env))
+}
+
+pub fn set_token_spending_window_mode(env: &Env, token: &Address, mode: SpendingWindowMode) {
+    let key = FeatureKey::TokenSpendingWindowMode(token.clone());
+    env.storage().persistent().set(&key, &mode);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+fn rolling_spent_key(token: &Option<Address>) -> DataKey {
+    match token {
+        Some(t) => DataKey::TokenRollingSpent(t.clone()),
+        None => DataKey::RollingSpent,
+    }
+}
+
+fn rolling_scope_mode(env: &Env, token: &Option<Address>) -> SpendingWindowMode {
+    match token {
+        Some(t) => get_token_spending_window_mode(env, t),
+        None => get_spending_window_mode(env),
+    }
+}
+
+fn get_rolling_slots(env: &Env, key: &DataKey) -> Map<u64, i128> {
+    env.storage()
+        .temporary()
+        .get(key)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Write the slot map back, dropping sub-buckets that fell out of the weekly
+/// window. The entry lives in temporary storage: once nothing has been spent
+/// for a week it may expire, which is equivalent to an empty window.
+fn set_rolling_slots(env: &Env, key: &DataKey, slots: &Map<u64, i128>) {
+    let oldest = get_rolling_slot(env).saturating_sub(ROLLING_WEEK_SLOTS - 1);
+    let mut pruned = Map::new(env);
+    for (slot, amount) in slots.iter() {
+        if slot >= oldest && amount > 0 {
+            pruned.set(slot, amount);
+        }
+    }
+    env.storage().temporary().set(key, &pruned);
+    env.storage()
+        .temporary()
+        .extend_ttl(key, DAY_IN_LEDGERS * 8, DAY_IN_LEDGERS * 8);
+}
+
+/// Sum of the last `window_slots` sub-buckets (including the current one) for
+/// the vault (`token = None`) or a single token.
+pub fn get_rolling_spent(env: &Env, token: &Option<Address>, window_slots: u64) -> i128 {
+    let oldest = get_rolling_slot(env).saturating_sub(window_slots - 1);
+    let mut total = 0i128;
+    for (slot, amount) in get_rolling_slots(env, &rolling_spent_key(token)).iter() {
+        if slot >= oldest {
+            total = total.saturating_add(amount);
+        }
+    }
+    total
+}
+
+/// Record `amount` in `slot` if the scope is in Rolling mode. Calendar-mode
+/// scopes are left untouched so no extra storage is paid for.
+pub fn add_rolling_spent(env: &Env, token: &Option<Address>, slot: u64, amount: i128) {
+    if rolling_scope_mode(env, token) != SpendingWindowMode::Rolling {
+        return;
+    }
+    let key = rolling_spent_key(token);
+    let mut slots = get_rolling_slots(env, &key);
+    let current = slots.get(slot).unwrap_or(0);
+    slots.set(slot, current.saturating_add(amount));
+    set_rolling_slots(env, &key, &slots);
+}
+
+/// Credit `amount` back to `slot`, never letting it go negative. A slot that has
+/// already aged out of the window is a no-op, mirroring how a refund into a
+/// past calendar bucket no longer affects today's limit.
+pub fn refund_rolling_spent(env: &Env, token: &Option<Address>, slot: u64, amount: i128) {
+    let key = rolling_spent_key(token);
+    let mut slots = get_rolling_slots(env, &key);
+    if let Some(current) = slots.get(slot) {
+        slots.set(slot, current.saturating_sub(amount).max(0));
+        set_rolling_slots(env, &key, &slots);
+    }
+}
+
+/// Aggregate amount counted against the daily limit right now: the calendar
+/// bucket `day` in Calendar mode, or the last 24h in Rolling mode.
+pub fn get_effective_daily_spent(env: &Env, day: u64) -> i128 {
+    match get_spending_window_mode(env) {
+        SpendingWindowMode::Calendar => get_daily_spent(env, day),
+        SpendingWindowMode::Rolling => get_rolling_spent(env, &None, ROLLING_DAY_SLOTS),
+    }
+}
+
+/// Aggregate amount counted against the weekly limit right now.
+pub fn get_effective_weekly_spent(env: &Env, week: u64) -> i128 {
+    match get_spending_window_mode(env) {
+        SpendingWindowMode::Calendar => get_weekly_spent(env, week),
+        SpendingWindowMode::Rolling => get_rolling_spent(env, &None, ROLLING_WEEK_SLOTS),
+    }
+}
+
+/// Per-token amount counted against the token's daily limit right now.
+pub fn get_effective_token_daily_spent(env: &Env, token: &Address, day: u64) -> i128 {
+    match get_token_spending_window_mode(env, token) {
+        SpendingWindowMode::Calendar => get_token_daily_spent(env, token, day),
+        SpendingWindowMode::Rolling => {
+            get_rolling_spent(env, &Some(token.clone()), ROLLING_DAY_SLOTS)
+        }
+    }
+}
+
+/// Per-token amount counted against the token's weekly limit right now.
+pub fn get_effective_token_weekly_spent(env: &Env, token: &Address, week: u64) -> i128 {
+    match get_token_spending_window_mode(env, token) {
+        SpendingWindowMode::Calendar => get_token_weekly_spent(env, token, week),
+        SpendingWindowMode::Rolling => {
+            get_rolling_spent(env, &Some(token.clone()), ROLLING_WEEK_SLOTS)
+        }
+    }
+}
+
+/// Remember which rolling sub-bucket a proposal reserved its spend in, so a
+/// later refund credits the same slot. Stored with the same TTL as the slots.
+pub fn set_proposal_spend_slot(env: &Env, proposal_id: u64, slot: u64) {
+    let key = DataKey::ProposalSpendSlot(proposal_id);
+    env.storage().temporary().set(&key, &slot);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, DAY_IN_LEDGERS * 8, DAY_IN_LEDGERS * 8);
+}
+
+pub fn get_proposal_spend_slot(env: &Env, proposal_id: u64) -> Option<u64> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::ProposalSpendSlot(proposal_id))
+}
+
+/// Refund a proposal's rolling-window reservation (vault-wide and per-token).
+pub fn refund_rolling_spending_limits(env: &Env, proposal_id: u64, token: &Address, amount: i128) {
+    if let Some(slot) = get_proposal_spend_slot(env, proposal_id) {
+        refund_rolling_spent(env, &None, slot, amount);
+        refund_rolling_spent(env, &Some(token.clone()), slot, amount);
+    }
+}
+
+// ============================================================================
+// Per-recipient caps and first-payment cool-off
+// ============================================================================
+
+/// Longest supported per-recipient window; bounds the size of the daily map.
+pub const MAX_RECIPIENT_WINDOW_DAYS: u32 = 90;
+
+pub fn get_recipient_limit_config(env: &Env) -> RecipientLimitConfig {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::RecipientLimitConfig)
+        .unwrap_or_default()
+}
+
+pub fn set_recipient_limit_config(env: &Env, config: &RecipientLimitConfig) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::RecipientLimitConfig, config);
+}
+
+pub fn set_recipient_cap_override(env: &Env, recipient: &Address, cap: i128) {
+    let key = FeatureKey::RecipientCap(recipient.clone());
+    env.storage().persistent().set(&key, &cap);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn remove_recipient_cap_override(env: &Env, recipient: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&FeatureKey::RecipientCap(recipient.clone()));
+}
+
+/// Effective cumulative cap for `recipient`: its override if set, otherwise the
+/// vault default (0 = no cap).
+pub fn get_recipient_cap(env: &Env, recipient: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::RecipientCap(recipient.clone()))
+        .unwrap_or_else(|| get_recipient_limit_config(env).default_cap)
+}
+
+fn get_recipient_received_days(env: &Env, recipient: &Address) -> Map<u64, i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecipientReceived(recipient.clone()))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Write the daily map back, dropping days that fell out of the window.
+fn set_recipient_received_days(env: &Env, recipient: &Address, days: &Map<u64, i128>) {
+    let window = get_recipient_limit_config(env).window_days as u64;
+    let oldest = get_day_number(env).saturating_sub(window.saturating_sub(1));
+    let mut pruned = Map::new(env);
+    for (day, amount) in days.iter() {
+        if day >= oldest && amount > 0 {
+            pruned.set(day, amount);
+        }
+    }
+    let key = DataKey::RecipientReceived(recipient.clone());
+    env.storage().persistent().set(&key, &pruned);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+/// Amount received or reserved by `recipient` within the configured window.
+pub fn get_recipient_received(env: &Env, recipient: &Address) -> i128 {
+    let window = get_recipient_limit_config(env).window_days as u64;
+    let oldest = get_day_number(env).saturating_sub(window.saturating_sub(1));
+    let mut total = 0i128;
+    for (day, amount) in get_recipient_received_days(env, recipient).iter() {
+        if day >= oldest {
+            total = total.saturating_add(amount);
+        }
+    }
+    total
+}
+
+pub fn add_recipient_received(env: &Env, recipient: &Address, day: u64, amount: i128) {
+    let mut days = get_recipient_received_days(env, recipient);
+    let current = days.get(day).unwrap_or(0);
+    days.set(day, current.saturating_add(amount));
+    set_recipient_received_days(env, recipient, &days);
+}
+
+/// Credit `amount` back to the day it was reserved in. A day that has already
+/// been pruned is a no-op.
+pub fn refund_recipient_received(env: &Env, recipient: &Address, day: u64, amount: i128) {
+    let mut days = get_recipient_received_days(env, recipient);
+    if let Some(current) = days.get(day) {
+        days.set(day, current.saturating_sub(amount).max(0));
+        set_recipient_received_days(env, recipient, &days);
+    }
+}
+
+/// Ledger of the first completed payment to `recipient` (0 = never paid).
+pub fn get_recipient_first_paid(env: &Env, recipient: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecipientFirstPaid(recipient.clone()))
+        .unwrap_or(0)
+}
+
+/// Record that `recipient` has been paid. Only the first call has any effect.
+pub fn mark_recipient_paid(env: &Env, recipient: &Address) {
+    let key = DataKey::RecipientFirstPaid(recipient.clone());
+    if !env.storage().persistent().has(&key) {
+        env.storage()
+            .persistent()
+            .set(&key, &(env.ledger().sequence() as u64));
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_proposal_safeguards(env: &Env, proposal_id: u64) -> ProposalSafeguards {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProposalSafeguards(proposal_id))
+        .unwrap_or_default()
+}
+
+/// Add to a proposal's safeguards; requirements from different sources stack.
+pub fn add_proposal_safeguards(env: &Env, proposal_id: u64, extra: &ProposalSafeguards) {
+    let mut safeguards = get_proposal_safeguards(env, proposal_id);
+    safeguards.extra_approvals = safeguards
+        .extra_approvals
+        .saturating_add(extra.extra_approvals);
+    safeguards.extra_delay_ledgers = safeguards
+        .extra_delay_ledgers
+        .saturating_add(extra.extra_delay_ledgers);
+    let key = DataKey::ProposalSafeguards(proposal_id);
+    env.storage().persistent().set(&key, &safeguards);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
 // ============================================================================
 // Bridge Storage
 // ============================================================================
@@ -3719,23 +4290,351 @@ pub fn collect_htag_descendants(env: &Env, tag_id: u64, result: &mut Vec<u64>, l
 }
 
 // ============================================================================
-// Issue #1085: Gas Cost Estimation Oracle Storage
+// Committed Liabilities Register
 // ============================================================================
 
-pub fn get_cost_model(env: &Env) -> CostModel {
+/// Outstanding obligations of one kind in `token`.
+///
+/// Vesting keeps its historical `VestingKey::Reserved` counter; every other
+/// kind lives under `DataKey::Liability`.
+pub fn get_liability(env: &Env, token: &Address, kind: LiabilityKind) -> i128 {
+    if kind == LiabilityKind::Vesting {
+        return get_reserved_vesting(env, token);
+    }
     env.storage()
-        .instance()
-        .get(&FeatureKey::CostModel)
-        .unwrap_or_else(CostModel::default)
+        .persistent()
+        .get(&DataKey::Liability(token.clone(), kind as u32))
+        .unwrap_or(0)
 }
 
-pub fn set_cost_model(env: &Env, model: &CostModel) {
-    env.storage().instance().set(&FeatureKey::CostModel, model);
+fn set_liability(env: &Env, token: &Address, kind: LiabilityKind, amount: i128) {
+    if kind == LiabilityKind::Vesting {
+        set_reserved_vesting(env, token, amount);
+        return;
+    }
+    let key = DataKey::Liability(token.clone(), kind as u32);
+    env.storage().persistent().set(&key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
-// ============================================================================
-// Issue #1367: Gas-Price Oracle Storage
-// ============================================================================
+/// Record a new obligation.
+pub fn add_liability(env: &Env, token: &Address, kind: LiabilityKind, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let current = get_liability(env, token, kind);
+    set_liability(env, token, kind, current.saturating_add(amount));
+}
+
+/// Retire (part of) an obligation once it is paid out or cancelled. Floored at 0.
+pub fn release_liability(env: &Env, token: &Address, kind: LiabilityKind, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let current = get_liability(env, token, kind);
+    set_liability(env, token, kind, (current - amount).max(0));
+}
+
+pub fn get_total_liabilities(env: &Env, token: &Address) -> i128 {
+    get_liability(env, token, LiabilityKind::Stream)
+        .saturating_add(get_liability(env, token, LiabilityKind::Escrow))
+        .saturating_add(get_liability(env, token, LiabilityKind::Vesting))
+        .saturating_add(get_liability(env, token, LiabilityKind::FundingRound))
+        .saturating_add(get_liability(env, token, LiabilityKind::Recurring))
+        .saturating_add(get_liability(env, token, LiabilityKind::Subscription))
+}
+
+pub fn get_solvency_policy(env: &Env) -> SolvencyPolicy {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::SolvencyPolicy)
+        .unwrap_or(SolvencyPolicy::Block)
+}
+
+pub fn set_solvency_policy(env: &Env, policy: &SolvencyPolicy) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::SolvencyPolicy, policy);
+}
+
+// ============================================================================
+// Reject Votes
+// ============================================================================
+
+pub fn get_proposal_rejections(env: &Env, proposal_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProposalRejections(proposal_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_proposal_rejections(env: &Env, proposal_id: u64, rejections: &Vec<Address>) {
+    let key = DataKey::ProposalRejections(proposal_id);
+    env.storage().persistent().set(&key, rejections);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn get_rejection_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::RejectionThreshold)
+        .unwrap_or(0)
+}
+
+pub fn set_rejection_threshold(env: &Env, threshold: u32) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::RejectionThreshold, &threshold);
+}
+
+pub fn get_rejections_given(env: &Env, signer: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::RejectionsGiven(signer.clone()))
+        .unwrap_or(0)
+}
+
+pub fn increment_rejections_given(env: &Env, signer: &Address) {
+    let key = FeatureKey::RejectionsGiven(signer.clone());
+    let count = get_rejections_given(env, signer).saturating_add(1);
+    env.storage().persistent().set(&key, &count);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+// ============================================================================
+// Optimistic Execution
+// ============================================================================
+
+pub fn get_optimistic_config(env: &Env) -> OptimisticConfig {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::OptimisticConfig)
+        .unwrap_or(OptimisticConfig {
+            enabled: false,
+            max_amount: 0,
+            tier_cap_multiplier: 0,
+            objection_threshold: 1,
+        })
+}
+
+pub fn set_optimistic_config(env: &Env, config: &OptimisticConfig) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::OptimisticConfig, config);
+}
+
+pub fn get_optimistic_state(env: &Env, proposal_id: u64) -> Option<OptimisticState> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OptimisticState(proposal_id))
+}
+
+pub fn set_optimistic_state(env: &Env, proposal_id: u64, state: &OptimisticState) {
+    let key = DataKey::OptimisticState(proposal_id);
+    env.storage().persistent().set(&key, state);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+// ============================================================================
+// Topic-Scoped Delegation
+// ============================================================================
+
+pub fn get_topic_delegations(env: &Env, delegator: &Address) -> Vec<TopicDelegation> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TopicDelegations(delegator.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_topic_delegations(env: &Env, delegator: &Address, delegations: &Vec<TopicDelegation>) {
+    let key = DataKey::TopicDelegations(delegator.clone());
+    if delegations.is_empty() {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, delegations);
+    }
+}
+
+pub fn get_proposal_direct_voters(env: &Env, proposal_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProposalDirectVoters(proposal_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn add_proposal_direct_voter(env: &Env, proposal_id: u64, voter: &Address) {
+    let mut voters = get_proposal_direct_voters(env, proposal_id);
+    if voters.contains(voter) {
+        return;
+    }
+    voters.push_back(voter.clone());
+    let key = DataKey::ProposalDirectVoters(proposal_id);
+    env.storage().persistent().set(&key, &voters);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+// ============================================================================
+// Tag Budget Envelopes
+// ============================================================================
+
+pub fn get_tag_budget(env: &Env, tag_id: u64) -> Option<TagBudget> {
+    env.storage().persistent().get(&DataKey::TagBudget(tag_id))
+}
+
+pub fn set_tag_budget(env: &Env, tag_id: u64, budget: &TagBudget) {
+    let key = DataKey::TagBudget(tag_id);
+    env.storage().persistent().set(&key, budget);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+    let mut tags = get_budgeted_tags(env);
+    if !tags.contains(tag_id) {
+        tags.push_back(tag_id);
+        set_budgeted_tags(env, &tags);
+    }
+}
+
+pub fn remove_tag_budget(env: &Env, tag_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TagBudget(tag_id));
+    let mut tags = get_budgeted_tags(env);
+    if let Some(idx) = tags.first_index_of(tag_id) {
+        tags.remove(idx);
+        set_budgeted_tags(env, &tags);
+    }
+}
+
+pub fn get_budgeted_tags(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BudgetedTags)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn set_budgeted_tags(env: &Env, tags: &Vec<u64>) {
+    env.storage().persistent().set(&DataKey::BudgetedTags, tags);
+    env.storage().persistent().extend_ttl(
+        &DataKey::BudgetedTags,
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL,
+    );
+}
+
+/// Days since epoch of the first day of `year`-`month` (proleptic Gregorian).
+fn days_from_civil(year: i64, month: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// (year, month) containing day `days` since epoch.
+fn civil_from_days(days: i64) -> (i64, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month)
+}
+
+/// First day of the budget period containing `day`, and first day of the next one.
+pub fn budget_period_bounds(day: u64, period: BudgetPeriod) -> (u64, u64) {
+    let (year, month) = civil_from_days(day as i64);
+    let (start_month, len) = match period {
+        BudgetPeriod::Monthly => (month, 1),
+        BudgetPeriod::Quarterly => (month - (month - 1) % 3, 3),
+    };
+    let (end_year, end_month) = if start_month + len > 12 {
+        (year + 1, start_month + len - 12)
+    } else {
+        (year, start_month + len)
+    };
+    (
+        days_from_civil(year, start_month) as u64,
+        days_from_civil(end_year, end_month) as u64,
+    )
+}
+
+pub fn get_tag_budget_used(env: &Env, tag_id: u64, period: BudgetPeriod, start_day: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TagBudgetUsed(tag_id, period as u32, start_day))
+        .unwrap_or(0)
+}
+
+/// Adjust an envelope's usage by `delta` (negative for refunds), never below 0.
+pub fn adjust_tag_budget_used(
+    env: &Env,
+    tag_id: u64,
+    period: BudgetPeriod,
+    start_day: u64,
+    delta: i128,
+) {
+    let key = DataKey::TagBudgetUsed(tag_id, period as u32, start_day);
+    let used = get_tag_budget_used(env, tag_id, period, start_day);
+    env.storage()
+        .persistent()
+        .set(&key, &used.saturating_add(delta).max(0));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_proposal_budget_reservations(env: &Env, proposal_id: u64) -> Vec<TagBudgetReservation> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProposalBudgetReservations(proposal_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_proposal_budget_reservations(
+    env: &Env,
+    proposal_id: u64,
+    reservations: &Vec<TagBudgetReservation>,
+) {
+    let key = DataKey::ProposalBudgetReservations(proposal_id);
+    env.storage().persistent().set(&key, reservations);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+// ============================================================================
+// Issue #1085: Gas Cost Estimation Oracle Storage
+// ============================================================================
+
+pub fn get_cost_model(env: &Env) -> CostModel {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::CostModel)
+        .unwrap_or_else(CostModel::default)
+}
+
+pub fn set_cost_model(env: &Env, model: &CostModel) {
+    env.storage().instance().set(&FeatureKey::CostModel, model);
+}
+
+// ============================================================================
+// Issue #1367: Gas-Price Oracle Storage
+// ============================================================================
 
 /// Retrieve the gas-price oracle configuration, if one has been set by an admin.
 pub fn get_gas_price_oracle_config(env: &Env) -> Option<GasPriceOracleConfig> {
@@ -4280,108 +5179,414 @@ pub fn set_governance_proposal(env: &Env, gp: &GovernanceProposal) {
         .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
-pub fn get_governance_threshold(env: &Env) -> u32 {
+pub fn get_param_change_proposal(env: &Env, id: u64) -> Option<ParamChangeProposal> {
     env.storage()
-        .instance()
-        .get(&FeatureKey::GovernanceThreshold)
-        .unwrap_or(67)
+        .persistent()
+        .get(&FeatureKey::ParamChangeProposal(id))
 }
 
-pub fn set_governance_threshold(env: &Env, threshold: u32) {
+pub fn set_param_change_proposal(env: &Env, proposal: &ParamChangeProposal) {
+    let key = FeatureKey::ParamChangeProposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
     env.storage()
-        .instance()
-        .set(&FeatureKey::GovernanceThreshold, &threshold);
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
-pub fn get_active_governance_count(env: &Env) -> u32 {
-    env.storage()
-        .instance()
-        .get(&FeatureKey::ActiveGovernanceCount)
-        .unwrap_or(0)
+// ============================================================================
+// Signer Elections
+// ============================================================================
+
+pub fn increment_signer_election_id(env: &Env) -> u64 {
+    let key = FeatureKey::Counter(CounterKey::SignerElection);
+    let id: u64 = env.storage().instance().get(&key).unwrap_or(1);
+    env.storage().instance().set(&key, &(id + 1));
+    id
 }
 
-pub fn set_active_governance_count(env: &Env, count: u32) {
+pub fn get_signer_election(env: &Env, id: u64) -> Result<SignerElection, VaultError> {
     env.storage()
-        .instance()
-        .set(&FeatureKey::ActiveGovernanceCount, &count);
+        .persistent()
+        .get(&FeatureKey::SignerElection(id))
+        .ok_or(VaultError::ElectionNotFound)
 }
 
-// ============================================================================
-// Pending Config Change (Issue #943)
-// ============================================================================
-
-pub fn get_pending_config_proposal(env: &Env) -> Option<u64> {
-    env.storage().instance().get(&FeatureKey::PendingConfig)
+pub fn set_signer_election(env: &Env, election: &SignerElection) {
+    let key = FeatureKey::SignerElection(election.id);
+    env.storage().persistent().set(&key, election);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
-pub fn set_pending_config_proposal(env: &Env, proposal_id: u64) {
+/// ID of the election still running or awaiting application (0 = none).
+pub fn get_active_signer_election(env: &Env) -> u64 {
     env.storage()
         .instance()
-        .set(&FeatureKey::PendingConfig, &proposal_id);
+        .get(&FeatureKey::ActiveSignerElection)
+        .unwrap_or(0)
 }
 
-pub fn clear_pending_config_proposal(env: &Env) {
-    env.storage().instance().remove(&FeatureKey::PendingConfig);
+pub fn set_active_signer_election(env: &Env, id: u64) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::ActiveSignerElection, &id);
 }
 
-// ============================================================================
-// Recipient whitelist helper
-// ============================================================================
+pub fn has_election_ballot(env: &Env, election_id: u64, voter: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&FeatureKey::ElectionBallot(election_id, voter.clone()))
+}
 
-pub fn is_recipient_whitelisted(env: &Env, recipient: &Address) -> bool {
-    match get_list_mode(env) {
-        ListMode::Disabled => true,
-        ListMode::Whitelist => is_whitelisted(env, recipient),
-        ListMode::Blacklist => !is_blacklisted(env, recipient),
-    }
+pub fn set_election_ballot(env: &Env, election_id: u64, voter: &Address, choices: &Vec<Address>) {
+    let key = FeatureKey::ElectionBallot(election_id, voter.clone());
+    env.storage().persistent().set(&key, choices);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
 // ============================================================================
-// Issue #1087: Audit Trail Compression
+// Signer Terms and Inactivity
 // ============================================================================
 
-pub fn get_next_audit_checkpoint_id(env: &Env) -> u64 {
-    env.storage()
-        .instance()
-        .get(&DataKey::NextAuditCheckpointId)
-        .unwrap_or(1)
-}
-
-pub fn increment_audit_checkpoint_id(env: &Env) -> u64 {
-    let id = get_next_audit_checkpoint_id(env);
+pub fn get_signer_term(env: &Env, signer: &Address) -> Option<SignerTerm> {
     env.storage()
-        .instance()
-        .set(&DataKey::NextAuditCheckpointId, &(id + 1));
-    id
+        .persistent()
+        .get(&FeatureKey::SignerTerm(signer.clone()))
 }
 
-pub fn set_audit_checkpoint(env: &Env, checkpoint: &AuditCheckpoint) {
-    let key = DataKey::AuditCheckpoint(checkpoint.id);
-    env.storage().persistent().set(&key, checkpoint);
+pub fn set_signer_term(env: &Env, signer: &Address, term: &SignerTerm) {
+    let key = FeatureKey::SignerTerm(signer.clone());
+    env.storage().persistent().set(&key, term);
     env.storage()
         .persistent()
         .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
-pub fn get_audit_checkpoint(env: &Env, id: u64) -> Option<AuditCheckpoint> {
+pub fn remove_signer_term(env: &Env, signer: &Address) {
     env.storage()
         .persistent()
-        .get(&DataKey::AuditCheckpoint(id))
+        .remove(&FeatureKey::SignerTerm(signer.clone()));
 }
 
-pub fn remove_audit_entry(env: &Env, id: u64) {
-    env.storage().persistent().remove(&DataKey::AuditEntry(id));
+/// The stored inactivity policy. Without one, removing a term-expired signer
+/// needs the full vault threshold.
+pub fn get_inactivity_policy(env: &Env) -> InactivityPolicy {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::InactivityPolicy)
+        .unwrap_or_else(|| InactivityPolicy {
+            inactive_after_ledgers: 0,
+            removal_threshold: get_config(env).map(|c| c.threshold).unwrap_or(1),
+            exclude_from_quorum: false,
+            activated_at: 0,
+        })
 }
 
-// ============================================================================
-// Issue #1100: Vault Merge Protocol Storage
-// ============================================================================
-
-pub fn get_next_merge_id(env: &Env) -> u64 {
+pub fn set_inactivity_policy(env: &Env, policy: &InactivityPolicy) {
     env.storage()
         .instance()
-        .get(&DataKey::NextMergeId)
-        .unwrap_or(1)
+        .set(&FeatureKey::InactivityPolicy, policy);
+}
+
+pub fn increment_signer_removal_id(env: &Env) -> u64 {
+    let key = FeatureKey::Counter(CounterKey::SignerRemoval);
+    let id: u64 = env.storage().instance().get(&key).unwrap_or(1);
+    env.storage().instance().set(&key, &(id + 1));
+    id
+}
+
+pub fn get_signer_removal(env: &Env, id: u64) -> Result<SignerRemovalProposal, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::SignerRemoval(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
+
+pub fn set_signer_removal(env: &Env, removal: &SignerRemovalProposal) {
+    let key = FeatureKey::SignerRemoval(removal.id);
+    env.storage().persistent().set(&key, removal);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+// ============================================================================
+// Guardian Council
+// ============================================================================
+
+pub fn get_guardian_council(env: &Env) -> Option<GuardianCouncil> {
+    env.storage().instance().get(&FeatureKey::GuardianCouncil)
+}
+
+pub fn set_guardian_council(env: &Env, council: &GuardianCouncil) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::GuardianCouncil, council);
+}
+
+pub fn increment_guardian_action_id(env: &Env) -> u64 {
+    let key = FeatureKey::Counter(CounterKey::GuardianAction);
+    let id: u64 = env.storage().instance().get(&key).unwrap_or(1);
+    env.storage().instance().set(&key, &(id + 1));
+    id
+}
+
+pub fn get_guardian_action(env: &Env, id: u64) -> Result<GuardianActionRecord, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::GuardianActionRecord(id))
+        .ok_or(VaultError::GuardianActionNotFound)
+}
+
+pub fn set_guardian_action(env: &Env, record: &GuardianActionRecord) {
+    let key = FeatureKey::GuardianActionRecord(record.id);
+    env.storage().persistent().set(&key, record);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_guardian_hold(env: &Env, action: &GuardianAction) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::GuardianHold(action.clone()))
+}
+
+pub fn set_guardian_hold(env: &Env, action: &GuardianAction, id: u64) {
+    let key = FeatureKey::GuardianHold(action.clone());
+    env.storage().persistent().set(&key, &id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn remove_guardian_hold(env: &Env, action: &GuardianAction) {
+    env.storage()
+        .persistent()
+        .remove(&FeatureKey::GuardianHold(action.clone()));
+}
+
+pub fn get_grant_usage(env: &Env, addr: &Address, permission: u32) -> Option<GrantUsage> {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::GrantUsage(addr.clone(), permission))
+}
+
+pub fn set_grant_usage(env: &Env, addr: &Address, permission: u32, usage: &GrantUsage) {
+    let key = FeatureKey::GrantUsage(addr.clone(), permission);
+    env.storage().persistent().set(&key, usage);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn is_grant_charged(env: &Env, addr: &Address, permission: u32, proposal_id: u64) -> bool {
+    env.storage().persistent().has(&FeatureKey::GrantCharged(
+        addr.clone(),
+        permission,
+        proposal_id,
+    ))
+}
+
+pub fn set_grant_charged(env: &Env, addr: &Address, permission: u32, proposal_id: u64) {
+    let key = FeatureKey::GrantCharged(addr.clone(), permission, proposal_id);
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+/// Constraints of `addr`'s grant of `permission`; grants stored without any
+/// (including those made before constraints existed) are unrestricted.
+pub fn get_grant_constraints(env: &Env, addr: &Address, permission: u32) -> GrantConstraints {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::GrantConstraints(addr.clone(), permission))
+        .unwrap_or_else(|| GrantConstraints::unrestricted(env))
+}
+
+pub fn set_grant_constraints(
+    env: &Env,
+    addr: &Address,
+    permission: u32,
+    constraints: &GrantConstraints,
+) {
+    let key = FeatureKey::GrantConstraints(addr.clone(), permission);
+    if constraints.is_unrestricted() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, constraints);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+/// Proposal IDs ever recorded under `status`. Entries are not removed when a
+/// proposal moves on, so callers must re-check the proposal's status.
+pub fn get_status_index(env: &Env, status: u32) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StatusIndex(status))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn get_governance_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::GovernanceThreshold)
+        .unwrap_or(67)
+}
+
+pub fn set_governance_threshold(env: &Env, threshold: u32) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::GovernanceThreshold, &threshold);
+}
+
+pub fn get_active_governance_ids(env: &Env) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::ActiveGovernanceIds)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_active_governance_ids(env: &Env, ids: &Vec<u64>) {
+    env.storage()
+        .instance()
+        .remove(&FeatureKey::ActiveGovernanceCount);
+    env.storage()
+        .instance()
+        .set(&FeatureKey::ActiveGovernanceIds, ids);
+}
+
+// ============================================================================
+// Pending Config Change (Issue #943)
+// ============================================================================
+
+pub fn get_pending_config_proposal(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&FeatureKey::PendingConfig)
+}
+
+pub fn set_pending_config_proposal(env: &Env, proposal_id: u64) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::PendingConfig, &proposal_id);
+}
+
+pub fn clear_pending_config_proposal(env: &Env) {
+    env.storage().instance().remove(&FeatureKey::PendingConfig);
+}
+
+// ============================================================================
+// Recipient whitelist helper
+// ============================================================================
+
+pub fn is_recipient_whitelisted(env: &Env, recipient: &Address) -> bool {
+    match get_list_mode(env) {
+        ListMode::Disabled => true,
+        ListMode::Whitelist => is_whitelisted(env, recipient),
+        ListMode::Blacklist => !is_blacklisted(env, recipient),
+    }
+}
+
+// ============================================================================
+// Issue #1087: Audit Trail Compression
+// ============================================================================
+
+pub fn get_next_audit_checkpoint_id(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::NextAuditCheckpointId)
+        .unwrap_or(1)
+}
+
+pub fn increment_audit_checkpoint_id(env: &Env) -> u64 {
+    let id = get_next_audit_checkpoint_id(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextAuditCheckpointId, &(id + 1));
+    id
+}
+
+pub fn set_audit_checkpoint(env: &Env, checkpoint: &AuditCheckpoint) {
+    let key = DataKey::AuditCheckpoint(checkpoint.id);
+    env.storage().persistent().set(&key, checkpoint);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_audit_checkpoint(env: &Env, id: u64) -> Option<AuditCheckpoint> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AuditCheckpoint(id))
+}
+
+/// Fold one checkpoint into the running accumulator.
+pub fn audit_accumulator(
+    env: &Env,
+    prev: &BytesN<32>,
+    to_entry_id: u64,
+    merkle_root: &BytesN<32>,
+    head_hash: &BytesN<32>,
+) -> BytesN<32> {
+    let mut data = soroban_sdk::Bytes::new(env);
+    data.append(&prev.clone().into());
+    data.extend_from_array(&to_entry_id.to_le_bytes());
+    data.append(&merkle_root.clone().into());
+    data.append(&head_hash.clone().into());
+    env.crypto().sha256(&data).into()
+}
+
+/// Store the next checkpoint, chaining its accumulator from the previous
+/// one. A legacy bridge starts a fresh accumulator.
+pub fn append_audit_checkpoint(
+    env: &Env,
+    from_entry_id: u64,
+    to_entry_id: u64,
+    merkle_root: BytesN<32>,
+    head_hash: BytesN<32>,
+    legacy_bridge: bool,
+) -> AuditCheckpoint {
+    let id = increment_audit_checkpoint_id(env);
+    let prev = if legacy_bridge || id == 1 {
+        BytesN::from_array(env, &[0u8; 32])
+    } else {
+        get_audit_checkpoint(env, id - 1)
+            .map(|c| c.accumulator)
+            .unwrap_or_else(|| BytesN::from_array(env, &[0u8; 32]))
+    };
+    let checkpoint = AuditCheckpoint {
+        id,
+        from_entry_id,
+        to_entry_id,
+        accumulator: audit_accumulator(env, &prev, to_entry_id, &merkle_root, &head_hash),
+        merkle_root,
+        created_at: env.ledger().sequence() as u64,
+        head_hash,
+        legacy_bridge,
+    };
+    set_audit_checkpoint(env, &checkpoint);
+    checkpoint
+}
+
+pub fn remove_audit_entry(env: &Env, id: u64) {
+    env.storage().persistent().remove(&DataKey::AuditEntry(id));
+}
+
+// ============================================================================
+// Issue #1100: Vault Merge Protocol Storage
+// ============================================================================
+
+pub fn get_next_merge_id(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::NextMergeId)
+        .unwrap_or(1)
 }
 
 pub fn increment_merge_id(env: &Env) -> u64 {
@@ -4583,6 +5788,8 @@ pub fn clear_proposal_in_progress(env: &Env, proposal_id: u64) {
 pub const MAX_KEEPER_HOOKS_PER_EVENT: u32 = 5;
 /// Maximum total keeper hooks across all event types per vault.
 pub const MAX_KEEPER_HOOKS_TOTAL: u32 = 20;
+/// Maximum work items returned or executed by a single keeper call.
+pub const MAX_KEEPER_WORK_ITEMS: u32 = 20;
 
 fn hook_event_key(event_type: &HookEventType) -> FeatureKey {
     FeatureKey::KeeperHooks(event_type.clone() as u32)
@@ -4625,3 +5832,401 @@ pub fn set_keeper_hook_count(env: &Env, count: u32) {
         PERSISTENT_TTL,
     );
 }
+
+pub fn get_keeper_budget(env: &Env) -> Option<KeeperBudget> {
+    env.storage().instance().get(&FeatureKey::KeeperBudget)
+}
+
+pub fn set_keeper_budget(env: &Env, budget: &KeeperBudget) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::KeeperBudget, budget);
+}
+
+// ============================================================================
+// Policy Execution Hooks
+// ============================================================================
+
+/// Maximum policy hooks registered per vault.
+pub const MAX_POLICY_HOOKS: u32 = 10;
+/// Policy hook calls allowed per execution unless configured otherwise.
+pub const DEFAULT_HOOK_CALL_BUDGET: u32 = 5;
+
+pub fn get_policy_hooks(env: &Env) -> Vec<PolicyHook> {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::PolicyHooks)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_policy_hooks(env: &Env, hooks: &Vec<PolicyHook>) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::PolicyHooks, hooks);
+}
+
+pub fn get_hook_call_budget(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::HookCallBudget)
+        .unwrap_or(DEFAULT_HOOK_CALL_BUDGET)
+}
+
+pub fn set_hook_call_budget(env: &Env, budget: u32) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::HookCallBudget, &budget);
+}
+
+pub fn get_hook_extra_approvals(env: &Env, proposal_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::HookExtraApprovals(proposal_id))
+        .unwrap_or(0)
+}
+
+pub fn set_hook_extra_approvals(env: &Env, proposal_id: u64, extra: u32) {
+    let key = FeatureKey::HookExtraApprovals(proposal_id);
+    env.storage().persistent().set(&key, &extra);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+// ============================================================================
+// Recipient Screening
+// ============================================================================
+
+/// Maximum screening registries configured per vault.
+pub const MAX_SCREENING_REGISTRIES: u32 = 5;
+/// Ledgers a cached screening answer is kept around (it is only used in its own ledger).
+const SCREENING_CACHE_TTL: u32 = 16;
+
+pub fn get_screening_config(env: &Env) -> Option<ScreeningConfig> {
+    env.storage().instance().get(&FeatureKey::ScreeningConfig)
+}
+
+pub fn set_screening_config(env: &Env, config: &ScreeningConfig) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::ScreeningConfig, config);
+}
+
+pub fn clear_screening_config(env: &Env) {
+    env.storage()
+        .instance()
+        .remove(&FeatureKey::ScreeningConfig);
+}
+
+/// Cached answer of `registry` for `addr`, if it was taken in the current ledger.
+pub fn get_screening_result(env: &Env, registry: &Address, addr: &Address) -> Option<bool> {
+    let result: ScreeningResult = env
+        .storage()
+        .temporary()
+        .get(&FeatureKey::ScreeningResult(registry.clone(), addr.clone()))?;
+    (result.ledger == env.ledger().sequence()).then_some(result.denied)
+}
+
+pub fn set_screening_result(env: &Env, registry: &Address, addr: &Address, denied: bool) {
+    let key = FeatureKey::ScreeningResult(registry.clone(), addr.clone());
+    env.storage().temporary().set(
+        &key,
+        &ScreeningResult {
+            ledger: env.ledger().sequence(),
+            denied,
+        },
+    );
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, SCREENING_CACHE_TTL, SCREENING_CACHE_TTL);
+}
+
+// ============================================================================
+// Payment Anomaly Detection
+// ============================================================================
+
+pub fn get_anomaly_policy(env: &Env) -> AnomalyPolicy {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::AnomalyPolicy)
+        .unwrap_or_default()
+}
+
+pub fn set_anomaly_policy(env: &Env, policy: &AnomalyPolicy) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::AnomalyPolicy, policy);
+}
+
+pub fn get_recipient_amount_stats(env: &Env, recipient: &Address) -> AmountStats {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::RecipientAmountStats(recipient.clone()))
+        .unwrap_or_default()
+}
+
+pub fn get_token_amount_stats(env: &Env, token: &Address) -> AmountStats {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::TokenAmountStats(token.clone()))
+        .unwrap_or_default()
+}
+
+/// Add an executed payment to the recipient and token statistics.
+pub fn record_executed_amount(env: &Env, recipient: &Address, token: &Address, amount: i128) {
+    for key in [
+        FeatureKey::RecipientAmountStats(recipient.clone()),
+        FeatureKey::TokenAmountStats(token.clone()),
+    ] {
+        let mut stats: AmountStats = env.storage().persistent().get(&key).unwrap_or_default();
+        stats.record(amount);
+        env.storage().persistent().set(&key, &stats);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+    }
+}
+
+pub fn get_anomaly_assessment(env: &Env, proposal_id: u64) -> Option<AnomalyAssessment> {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::AnomalyAssessment(proposal_id))
+}
+
+pub fn set_anomaly_assessment(env: &Env, proposal_id: u64, assessment: &AnomalyAssessment) {
+    let key = FeatureKey::AnomalyAssessment(proposal_id);
+    env.storage().persistent().set(&key, assessment);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+// ============================================================================
+// Impact Score Model
+// ============================================================================
+
+/// Maximum score bands in an impact score model.
+pub const MAX_IMPACT_BANDS: u32 = 5;
+/// Maximum tag risk entries in an impact score model.
+pub const MAX_IMPACT_TAG_RISKS: u32 = 20;
+
+pub fn get_impact_score_model(env: &Env) -> Option<ImpactScoreModel> {
+    env.storage().instance().get(&FeatureKey::ImpactScoreModel)
+}
+
+pub fn set_impact_score_model(env: &Env, model: &ImpactScoreModel) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::ImpactScoreModel, model);
+}
+
+pub fn clear_impact_score_model(env: &Env) {
+    env.storage()
+        .instance()
+        .remove(&FeatureKey::ImpactScoreModel);
+}
+
+pub fn get_proposal_impact(env: &Env, proposal_id: u64) -> Option<ImpactScore> {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::ProposalImpact(proposal_id))
+}
+
+pub fn set_proposal_impact(env: &Env, proposal_id: u64, score: &ImpactScore) {
+    let key = FeatureKey::ProposalImpact(proposal_id);
+    env.storage().persistent().set(&key, score);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+// ============================================================================
+// Active-id indexes
+// ============================================================================
+
+fn proposal_is_open(proposal: &Proposal) -> bool {
+    matches!(
+        proposal.status,
+        ProposalStatus::Pending | ProposalStatus::Approved | ProposalStatus::Scheduled
+    )
+}
+
+fn recurring_is_open(payment: &crate::types::RecurringPayment) -> bool {
+    payment.status != crate::types::RecurringStatus::Stopped
+}
+
+fn stream_is_open(stream: &crate::types::StreamingPayment) -> bool {
+    matches!(
+        stream.status,
+        crate::types::StreamStatus::Active | crate::types::StreamStatus::Paused
+    )
+}
+
+fn subscription_is_open(sub: &Subscription) -> bool {
+    matches!(
+        sub.status,
+        crate::types::SubscriptionStatus::Active
+            | crate::types::SubscriptionStatus::Paused
+            | crate::types::SubscriptionStatus::Suspended
+    )
+}
+
+fn vesting_is_open(schedule: &VestingSchedule) -> bool {
+    !schedule.cancelled && schedule.claimed < schedule.total
+}
+
+fn escrow_is_open(escrow: &Escrow) -> bool {
+    !matches!(
+        escrow.status,
+        crate::types::EscrowStatus::Released | crate::types::EscrowStatus::Refunded
+    )
+}
+
+/// Whether record `id` of `set` exists and is still open.
+fn record_is_open(env: &Env, set: ActiveSet, id: u64) -> bool {
+    let store = env.storage().persistent();
+    match set {
+        ActiveSet::Proposal => store
+            .get::<_, Proposal>(&DataKey::Proposal(id))
+            .is_some_and(|p| proposal_is_open(&p)),
+        ActiveSet::Recurring => store
+            .get::<_, crate::types::RecurringPayment>(&DataKey::Recurring(id))
+            .is_some_and(|p| recurring_is_open(&p)),
+        ActiveSet::Stream => store
+            .get::<_, crate::types::StreamingPayment>(&DataKey::Stream(id))
+            .is_some_and(|s| stream_is_open(&s)),
+        ActiveSet::Subscription => store
+            .get::<_, Subscription>(&FeatureKey::Subscription(id))
+            .is_some_and(|s| subscription_is_open(&s)),
+        ActiveSet::Vesting => store
+            .get::<_, VestingSchedule>(&VestingKey::Schedule(id))
+            .is_some_and(|s| vesting_is_open(&s)),
+        ActiveSet::Escrow => store
+            .get::<_, Escrow>(&FeatureKey::Escrow(id))
+            .is_some_and(|e| escrow_is_open(&e)),
+    }
+}
+
+fn next_record_id(env: &Env, set: ActiveSet) -> u64 {
+    match set {
+        ActiveSet::Proposal => get_next_proposal_id(env),
+        ActiveSet::Recurring => get_next_recurring_id(env),
+        ActiveSet::Stream => get_next_stream_id(env),
+        ActiveSet::Subscription => get_next_subscription_id(env),
+        ActiveSet::Vesting => peek_next_vesting_id(env),
+        ActiveSet::Escrow => get_next_escrow_id(env),
+    }
+}
+
+/// Maximum number of IDs `seed_active_ids` may inspect in one call.
+pub const MAX_ACTIVE_SEED_RANGE: u64 = 200;
+
+fn get_active_link(env: &Env, set: ActiveSet, id: u64) -> Option<ActiveLink> {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::ActiveLink(set, id))
+}
+
+fn set_active_link(env: &Env, set: ActiveSet, id: u64, link: &ActiveLink) {
+    let key = FeatureKey::ActiveLink(set, id);
+    env.storage().persistent().set(&key, link);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+fn get_active_ends(env: &Env, set: ActiveSet) -> (u64, u64) {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::ActiveEnds(set))
+        .unwrap_or((0, 0))
+}
+
+fn set_active_ends(env: &Env, set: ActiveSet, ends: (u64, u64)) {
+    let key = FeatureKey::ActiveEnds(set);
+    env.storage().persistent().set(&key, &ends);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+/// Append `id` to the active list of `set`.
+fn link_active_id(env: &Env, set: ActiveSet, id: u64) {
+    let (head, tail) = get_active_ends(env, set);
+    if let Some(mut last) = get_active_link(env, set, tail) {
+        last.next = id;
+        set_active_link(env, set, tail, &last);
+    }
+    set_active_link(
+        env,
+        set,
+        id,
+        &ActiveLink {
+            prev: tail,
+            next: 0,
+        },
+    );
+    set_active_ends(env, set, (if head == 0 { id } else { head }, id));
+}
+
+/// Remove `id` from the active list of `set`.
+fn unlink_active_id(env: &Env, set: ActiveSet, id: u64, link: ActiveLink) {
+    let (mut head, mut tail) = get_active_ends(env, set);
+    match get_active_link(env, set, link.prev) {
+        Some(mut prev) => {
+            prev.next = link.next;
+            set_active_link(env, set, link.prev, &prev);
+        }
+        None => head = link.next,
+    }
+    match get_active_link(env, set, link.next) {
+        Some(mut next) => {
+            next.prev = link.prev;
+            set_active_link(env, set, link.next, &next);
+        }
+        None => tail = link.prev,
+    }
+    env.storage()
+        .persistent()
+        .remove(&FeatureKey::ActiveLink(set, id));
+    set_active_ends(env, set, (head, tail));
+}
+
+/// IDs of the open records of `set`, in the order they joined the index.
+///
+/// Records opened before the index existed only appear once an admin has
+/// seeded them with [`seed_active_ids`].
+pub fn get_active_ids(env: &Env, set: ActiveSet) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    let mut id = get_active_ends(env, set).0;
+    while let Some(link) = get_active_link(env, set, id) {
+        ids.push_back(id);
+        id = link.next;
+    }
+    ids
+}
+
+/// Add or drop `id` from the index of `set` after the record was saved.
+fn sync_active_id(env: &Env, set: ActiveSet, id: u64, open: bool) {
+    match (open, get_active_link(env, set, id)) {
+        (true, None) => link_active_id(env, set, id),
+        (false, Some(link)) => unlink_active_id(env, set, id, link),
+        _ => {}
+    }
+}
+
+/// Index the open records of `set` with IDs in `from..to` that are not
+/// indexed yet, returning how many were added.
+///
+/// Migrates vaults whose records predate the index; the range is capped at
+/// `MAX_ACTIVE_SEED_RANGE` so each call stays within the read budget.
+pub fn seed_active_ids(env: &Env, set: ActiveSet, from: u64, to: u64) -> u32 {
+    let mut added = 0;
+    for id in from.max(1)..to.min(next_record_id(env, set)) {
+        if get_active_link(env, set, id).is_none() && record_is_open(env, set, id) {
+            link_active_id(env, set, id);
+            added += 1;
+        }
+    }
+    added
+}
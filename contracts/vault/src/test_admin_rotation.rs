@@ -0,0 +1,199 @@
+//! Tests for the two-step, delayed admin rotation.
+#![cfg(test)]
+
+use crate::types::{AdminRotationAction, RetryConfig, Role, ThresholdStrategy, VelocityConfig};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Vec,
+};
+
+const DELAY: u64 = 1440;
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address) {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer = Address::generate(env);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: DELAY,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, signer)
+}
+
+#[test]
+fn test_grant_admin_accepted_by_new_admin_after_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer) = setup(&env);
+    let candidate = Address::generate(&env);
+
+    let grant = AdminRotationAction::GrantAdmin(candidate.clone());
+    client.propose_admin_rotation(&admin, &grant);
+    let pending = client.get_pending_admin_rotation().unwrap();
+    assert_eq!(pending.action, grant);
+    assert_eq!(pending.executable_at, 100 + DELAY);
+    assert_eq!(
+        client.try_propose_admin_rotation(&admin, &grant),
+        Err(Ok(VaultError::AdminRotationPending))
+    );
+
+    assert_eq!(
+        client.try_accept_admin_rotation(&candidate),
+        Err(Ok(VaultError::TimelockNotExpired))
+    );
+    env.ledger().set_sequence_number(100 + DELAY as u32);
+    assert_eq!(
+        client.try_accept_admin_rotation(&admin),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    client.accept_admin_rotation(&candidate);
+    assert_eq!(client.get_role(&candidate), Role::Admin);
+    assert!(client.get_pending_admin_rotation().is_none());
+
+    // A signer can cancel a rotation during the delay.
+    let other = Address::generate(&env);
+    client.propose_admin_rotation(&admin, &AdminRotationAction::GrantAdmin(other.clone()));
+    client.cancel_admin_rotation(&signer);
+    assert!(client.get_pending_admin_rotation().is_none());
+    assert_eq!(
+        client.try_accept_admin_rotation(&other),
+        Err(Ok(VaultError::NoPendingAdminRotation))
+    );
+    assert_eq!(
+        client.try_cancel_admin_rotation(&Address::generate(&env)),
+        Err(Ok(VaultError::NoPendingAdminRotation))
+    );
+}
+
+#[test]
+fn test_revoke_admin_keeps_one_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _signer) = setup(&env);
+
+    assert_eq!(
+        client.try_propose_admin_rotation(&admin, &AdminRotationAction::RevokeAdmin(admin.clone())),
+        Err(Ok(VaultError::InvalidStatusTransition))
+    );
+
+    let second = Address::generate(&env);
+    client.propose_admin_rotation(&admin, &AdminRotationAction::GrantAdmin(second.clone()));
+    env.ledger().set_sequence_number(100 + DELAY as u32);
+    client.accept_admin_rotation(&second);
+
+    let revoke = AdminRotationAction::RevokeAdmin(admin.clone());
+    client.propose_admin_rotation(&second, &revoke);
+    // The admin being removed can neither cancel nor accept their own removal.
+    assert_eq!(
+        client.try_cancel_admin_rotation(&admin),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    env.ledger().set_sequence_number(100 + 2 * DELAY as u32);
+    assert_eq!(
+        client.try_accept_admin_rotation(&admin),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    client.accept_admin_rotation(&second);
+    assert_eq!(client.get_role(&admin), Role::Member);
+}
+
+#[test]
+fn test_set_role_cannot_grant_or_revoke_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer) = setup(&env);
+
+    assert_eq!(
+        client.try_set_role(&admin, &signer, &Role::Admin),
+        Err(Ok(VaultError::AdminRotationRequired))
+    );
+
+    let second = Address::generate(&env);
+    client.propose_admin_rotation(&admin, &AdminRotationAction::GrantAdmin(second.clone()));
+    env.ledger().set_sequence_number(100 + DELAY as u32);
+    client.accept_admin_rotation(&second);
+    assert_eq!(
+        client.try_set_role(&admin, &second, &Role::Member),
+        Err(Ok(VaultError::AdminRotationRequired))
+    );
+
+    // Stepping down from Admin is still immediate.
+    client.set_role(&second, &second, &Role::Treasurer);
+    assert_eq!(client.get_role(&second), Role::Treasurer);
+}
+
+#[test]
+fn test_signer_update_waits_for_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer) = setup(&env);
+    let newcomer = Address::generate(&env);
+
+    let mut signers = client.get_config().signers;
+    signers.push_back(newcomer.clone());
+    client.update_config_signers(&admin, &signers);
+    assert_eq!(client.get_config().signers.len(), 2);
+    assert_eq!(
+        client.get_pending_admin_rotation().unwrap().action,
+        AdminRotationAction::UpdateSigners(signers.clone())
+    );
+
+    env.ledger().set_sequence_number(100 + DELAY as u32);
+    // Only an admin accepts a signer-set change.
+    assert_eq!(
+        client.try_accept_admin_rotation(&signer),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    client.accept_admin_rotation(&admin);
+    assert_eq!(client.get_config().signers, signers);
+
+    // An empty signer set is rejected when proposed.
+    assert_eq!(
+        client.try_update_config_signers(&admin, &Vec::new(&env)),
+        Err(Ok(VaultError::NoSigners))
+    );
+}
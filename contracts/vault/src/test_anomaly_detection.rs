@@ -0,0 +1,310 @@
+//! Tests for anomaly-driven approval requirements based on payment history.
+#![cfg(test)]
+
+use crate::types::{
+    AnomalyPolicy, AnomalyReason, ConditionLogic, Priority, ProposalStatus, RetryConfig,
+    ScheduledTransferConfig, ThresholdStrategy, VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    signer: Address,
+    guardian: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer = Address::generate(env);
+    let guardian = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: soroban_sdk::vec![env, guardian.clone()],
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    Setup {
+        client,
+        admin,
+        signer,
+        guardian,
+        token,
+    }
+}
+
+fn propose(s: &Setup<'_>, recipient: &Address, amount: i128) -> u64 {
+    let env = &s.client.env;
+    s.client.propose_transfer(
+        &s.admin,
+        recipient,
+        &s.token,
+        &amount,
+        &Symbol::new(env, "pay"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    )
+}
+
+#[test]
+fn test_outsized_payment_needs_extra_approval_and_veto_ack() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let payee = Address::generate(&env);
+
+    s.client.set_anomaly_policy(
+        &s.admin,
+        &AnomalyPolicy {
+            flag_new_recipient: false,
+            max_multiplier: 10,
+            mean_multiplier: 0,
+            min_history: 1,
+            extra_approvals: 1,
+            extra_delay_ledgers: 0,
+            require_veto_ack: true,
+        },
+    );
+
+    // Without history nothing is flagged
+    let first = propose(&s, &payee, 100);
+    assert!(s.client.get_proposal_risk_report(&first).reasons.is_empty());
+    s.client.approve_proposal(&s.admin, &first);
+    s.client.execute_proposal(&s.admin, &first);
+    let stats = s.client.get_recipient_amount_stats(&payee);
+    assert_eq!((stats.count, stats.total, stats.max), (1, 100, 100));
+    assert_eq!(s.client.get_token_amount_stats(&s.token).count, 1);
+
+    let routine = propose(&s, &payee, 1_000);
+    assert!(s
+        .client
+        .get_proposal_risk_report(&routine)
+        .reasons
+        .is_empty());
+
+    let outsized = propose(&s, &payee, 1_001);
+    let report = s.client.get_proposal_risk_report(&outsized);
+    assert_eq!(
+        report.reasons,
+        soroban_sdk::vec![
+            &env,
+            AnomalyReason::AboveRecipientMax,
+            AnomalyReason::AboveTokenMax
+        ]
+    );
+    assert_eq!(report.recipient_stats.max, 100);
+    assert_eq!(report.safeguards.extra_approvals, 1);
+    assert!(report.veto_ack_required);
+
+    s.client.approve_proposal(&s.admin, &outsized);
+    assert_eq!(
+        s.client.get_proposal(&outsized).status,
+        ProposalStatus::Pending
+    );
+    s.client.approve_proposal(&s.signer, &outsized);
+    assert_eq!(
+        s.client.try_execute_proposal(&s.admin, &outsized),
+        Err(Ok(VaultError::AnomalyAckRequired))
+    );
+
+    assert_eq!(
+        s.client.try_acknowledge_anomaly(&s.signer, &outsized),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    s.client.acknowledge_anomaly(&s.guardian, &outsized);
+    assert_eq!(
+        s.client.try_acknowledge_anomaly(&s.guardian, &outsized),
+        Err(Ok(VaultError::AnomalyAckNotRequired))
+    );
+    assert_eq!(
+        s.client.get_proposal_risk_report(&outsized).acknowledged_by,
+        Some(s.guardian.clone())
+    );
+
+    s.client.execute_proposal(&s.admin, &outsized);
+    assert_eq!(s.client.get_recipient_amount_stats(&payee).max, 1_001);
+}
+
+#[test]
+fn test_new_recipient_gets_extra_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let policy = AnomalyPolicy {
+        flag_new_recipient: true,
+        extra_delay_ledgers: 50,
+        ..AnomalyPolicy::default()
+    };
+
+    assert_eq!(
+        s.client.try_set_anomaly_policy(&s.signer, &policy),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    s.client.set_anomaly_policy(&s.admin, &policy);
+    assert_eq!(s.client.get_anomaly_policy(), policy);
+
+    let id = propose(&s, &Address::generate(&env), 100);
+    let report = s.client.get_proposal_risk_report(&id);
+    assert_eq!(
+        report.reasons,
+        soroban_sdk::vec![&env, AnomalyReason::NewRecipient]
+    );
+    assert_eq!(report.safeguards.extra_delay_ledgers, 50);
+    assert!(!report.veto_ack_required);
+    assert_eq!(report.acknowledged_by, None);
+}
+
+#[test]
+fn test_veto_ack_requires_a_veto_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let requires_ack = AnomalyPolicy {
+        max_multiplier: 10,
+        require_veto_ack: true,
+        ..AnomalyPolicy::default()
+    };
+
+    s.client.set_anomaly_policy(&s.admin, &requires_ack);
+    assert_eq!(
+        s.client.try_remove_veto_address(&s.admin, &s.guardian),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+
+    s.client
+        .set_anomaly_policy(&s.admin, &AnomalyPolicy::default());
+    s.client.remove_veto_address(&s.admin, &s.guardian);
+    assert_eq!(
+        s.client.try_set_anomaly_policy(&s.admin, &requires_ack),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_veto_ack_blocks_scheduled_and_batch_execution() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+    let s = setup(&env);
+    let payee = Address::generate(&env);
+    s.client.set_anomaly_policy(
+        &s.admin,
+        &AnomalyPolicy {
+            max_multiplier: 10,
+            min_history: 1,
+            require_veto_ack: true,
+            ..AnomalyPolicy::default()
+        },
+    );
+
+    let first = propose(&s, &payee, 100);
+    s.client.approve_proposal(&s.admin, &first);
+    s.client.execute_proposal(&s.admin, &first);
+
+    let scheduled = s.client.propose_scheduled_transfer(
+        &s.admin,
+        &payee,
+        &s.token,
+        &5_000,
+        &Symbol::new(&env, "pay"),
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0,
+        &ScheduledTransferConfig {
+            execution_time: 150,
+            execution_window_ledgers: 0,
+        },
+    );
+    assert!(
+        s.client
+            .get_proposal_risk_report(&scheduled)
+            .veto_ack_required
+    );
+    s.client.approve_proposal(&s.admin, &scheduled);
+    env.ledger().set_sequence_number(200);
+    assert_eq!(
+        s.client
+            .try_execute_scheduled_proposal(&s.admin, &scheduled),
+        Err(Ok(VaultError::AnomalyAckRequired))
+    );
+    s.client.acknowledge_anomaly(&s.guardian, &scheduled);
+    s.client.execute_scheduled_proposal(&s.admin, &scheduled);
+    assert_eq!(
+        s.client.get_proposal(&scheduled).status,
+        ProposalStatus::Executed
+    );
+
+    // Scheduled payouts feed the history like any other execution
+    let stats = s.client.get_recipient_amount_stats(&payee);
+    assert_eq!((stats.count, stats.max), (2, 5_000));
+
+    let batched = propose(&s, &payee, 50_001);
+    s.client.approve_proposal(&s.admin, &batched);
+    let ids = soroban_sdk::vec![&env, batched];
+    let (executed, failed) = s.client.batch_execute_proposals(&s.admin, &ids);
+    assert!(executed.is_empty());
+    assert_eq!(failed, 1);
+    assert_eq!(
+        s.client.get_proposal(&batched).status,
+        ProposalStatus::Approved
+    );
+
+    s.client.acknowledge_anomaly(&s.guardian, &batched);
+    let (executed, _) = s.client.batch_execute_proposals(&s.admin, &ids);
+    assert_eq!(executed, ids);
+    assert_eq!(s.client.get_recipient_amount_stats(&payee).count, 3);
+}
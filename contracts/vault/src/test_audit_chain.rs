@@ -0,0 +1,190 @@
+//! Tests for the SHA-256 audit chain, legacy bridging and checkpoint consistency proofs.
+#![cfg(test)]
+
+use crate::storage::DataKey;
+use crate::types::{RetryConfig, ThresholdStrategy, VelocityConfig};
+use crate::{InitConfig, VaultDAO, VaultDAOClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env, Vec,
+};
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address) {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(Address::generate(env));
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, token)
+}
+
+/// Append audit entries until at least `count` exist.
+fn fill_audit_log(env: &Env, client: &VaultDAOClient<'_>, admin: &Address, count: u64) {
+    let addr = Address::generate(env);
+    while client.get_audit_entry_count() < count {
+        client.add_to_whitelist(admin, &addr);
+        client.remove_from_whitelist(admin, &addr);
+    }
+}
+
+#[test]
+fn test_entries_chain_with_sha256_hashes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+    fill_audit_log(&env, &client, &admin, 4);
+
+    let first = client.get_audit_entry(&1);
+    assert_eq!(first.prev_hash, BytesN::from_array(&env, &[0u8; 32]));
+    let count = client.get_audit_entry_count();
+    for id in 2..=count {
+        let prev = client.get_audit_entry(&(id - 1));
+        assert_eq!(client.get_audit_entry(&id).prev_hash, prev.hash);
+    }
+    client.verify_audit_chain(&1, &count);
+    assert_eq!(client.verify_audit_trail_full(), None);
+
+    // Rewriting any field of a stored entry breaks its hash.
+    let mut tampered = client.get_audit_entry(&2);
+    tampered.timestamp += 1;
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AuditEntry(2), &tampered);
+    });
+    assert_eq!(client.verify_audit_trail_full(), Some(2));
+}
+
+#[test]
+fn test_checkpoints_keep_chain_and_prove_consistency() {
+    let env = Env::default();
+    env.mock_all_auths();
+    // Archiving a 100-entry batch touches more ledger entries than a single
+    // mainnet invocation allows.
+    env.cost_estimate().disable_resource_limits();
+    let (client, admin, _) = setup(&env);
+    fill_audit_log(&env, &client, &admin, 100);
+
+    let first_head = client.get_audit_entry(&100).hash;
+    let first_id = client.create_audit_checkpoint(&admin);
+    let first = client.get_audit_checkpoint(&first_id);
+    assert_eq!(first.head_hash, first_head);
+    assert!(!first.legacy_bridge);
+    assert_eq!(client.get_audit_consistency_proof(&0, &first_id).len(), 1);
+
+    // The chain stays verifiable across the archived batch.
+    assert_eq!(client.get_audit_entry(&101).prev_hash, first_head);
+    client.verify_audit_chain(&101, &client.get_audit_entry_count());
+    assert_eq!(client.verify_audit_trail_full(), None);
+
+    fill_audit_log(&env, &client, &admin, 200);
+    let second_id = client.create_audit_checkpoint(&admin);
+
+    let proof = client.get_audit_consistency_proof(&first_id, &second_id);
+    assert_eq!(proof.len(), 1);
+    assert!(client.verify_audit_consistency(&first_id, &first.accumulator, &second_id, &proof));
+
+    // An auditor holding a different old root is rejected.
+    let forged = BytesN::from_array(&env, &[7u8; 32]);
+    assert!(!client.verify_audit_consistency(&first_id, &forged, &second_id, &proof));
+
+    // As is a proof whose batch contents were rewritten.
+    let mut rewritten = proof.clone();
+    let mut step = rewritten.get(0).unwrap();
+    step.merkle_root = forged;
+    rewritten.set(0, step);
+    assert!(!client.verify_audit_consistency(
+        &first_id,
+        &first.accumulator,
+        &second_id,
+        &rewritten
+    ));
+
+    // The full history from the empty log also folds to the latest root.
+    let full = client.get_audit_consistency_proof(&0, &second_id);
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    assert!(client.verify_audit_consistency(&0, &zero, &second_id, &full));
+}
+
+#[test]
+fn test_legacy_chain_is_bridged_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+    fill_audit_log(&env, &client, &admin, 2);
+    let legacy_count = client.get_audit_entry_count();
+
+    // Simulate a vault upgraded from the 64-bit chain.
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&DataKey::AuditChainHead);
+        env.storage()
+            .instance()
+            .set(&DataKey::LastAuditHash, &0xdead_beef_u64);
+    });
+
+    fill_audit_log(&env, &client, &admin, legacy_count + 2);
+    let bridge = client.get_audit_checkpoint(&1);
+    assert!(bridge.legacy_bridge);
+    assert_eq!(bridge.from_entry_id, 1);
+    assert_eq!(bridge.to_entry_id, legacy_count);
+    assert_eq!(
+        client.get_audit_entry(&(legacy_count + 1)).prev_hash,
+        bridge.head_hash
+    );
+    assert_eq!(client.verify_audit_trail_full(), None);
+    assert!(!client.verify_audit_entry(&1, &bridge.head_hash, &Vec::new(&env), &0));
+
+    // Later entries keep chaining from the SHA-256 head.
+    fill_audit_log(&env, &client, &admin, legacy_count + 6);
+    assert!(client.try_get_audit_checkpoint(&2).is_err());
+    client.verify_audit_chain(&(legacy_count + 1), &client.get_audit_entry_count());
+}
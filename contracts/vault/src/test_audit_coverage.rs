@@ -0,0 +1,144 @@
+//! Tests that state-changing entry points append typed entries to the audit chain.
+#![cfg(test)]
+
+use crate::types::{
+    AuditAction, AuditEntry, AuditTarget, Permission, RetryConfig, ThresholdStrategy,
+    VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address) {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(Address::generate(env));
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, token)
+}
+
+fn last_entry(client: &VaultDAOClient<'_>) -> AuditEntry {
+    let count = client.get_audit_entry_count();
+    client.get_audit_entry(&count)
+}
+
+#[test]
+fn test_stream_lifecycle_is_audited() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+    StellarAssetClient::new(&env, &token).mint(&admin, &10_000);
+    let recipient = Address::generate(&env);
+
+    let stream_id = client.create_stream(&admin, &recipient, &token, &1, &10_000, &10_000);
+    let created = last_entry(&client);
+    assert_eq!(created.action, AuditAction::CreateStream);
+    assert_eq!(created.actor, admin);
+    assert_eq!(created.target, AuditTarget::Stream(stream_id));
+
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.claim_stream(&recipient, &stream_id);
+    assert_eq!(last_entry(&client).action, AuditAction::ClaimStream);
+
+    client.cancel_stream(&admin, &stream_id);
+    let cancelled = last_entry(&client);
+    assert_eq!(cancelled.action, AuditAction::CancelStream);
+    assert_eq!(cancelled.target, AuditTarget::Stream(stream_id));
+    assert_eq!(client.verify_audit_trail_full(), None);
+}
+
+#[test]
+fn test_permission_and_hook_changes_carry_typed_targets() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _token) = setup(&env);
+    let operator = Address::generate(&env);
+    let hook = Address::generate(&env);
+
+    client.grant_permission(&admin, &operator, &Permission::ManageLists, &None);
+    assert_eq!(
+        last_entry(&client).target,
+        AuditTarget::Permission(operator.clone(), Permission::ManageLists)
+    );
+    client.revoke_permission(&admin, &operator, &Permission::ManageLists);
+    assert_eq!(last_entry(&client).action, AuditAction::RevokePermission);
+
+    client.register_pre_hook(&admin, &hook);
+    let registered = last_entry(&client);
+    assert_eq!(registered.action, AuditAction::RegisterHook);
+    assert_eq!(registered.target, AuditTarget::Address(hook.clone()));
+    client.remove_pre_hook(&admin, &hook);
+    assert_eq!(last_entry(&client).action, AuditAction::RemoveHook);
+
+    let count = client.get_audit_entry_count();
+    assert!(client.verify_audit_trail(&1, &count));
+}
+
+#[test]
+fn test_pause_and_unpause_are_audited() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _token) = setup(&env);
+
+    let responders = Vec::from_array(&env, [admin.clone(), Address::generate(&env)]);
+    client.configure_emergency(&admin, &responders, &0);
+    assert_eq!(last_entry(&client).action, AuditAction::UpdateConfig);
+    client.pause_vault(&admin, &Symbol::new(&env, "incident"));
+    let paused = last_entry(&client);
+    assert_eq!(paused.action, AuditAction::PauseVault);
+    assert_eq!(paused.target, AuditTarget::None);
+    client.unpause_vault(&admin);
+    assert_eq!(last_entry(&client).action, AuditAction::UnpauseVault);
+}
@@ -0,0 +1,320 @@
+//! Tests for capability tokens acting as scoped session keys for streams and proposals.
+#![cfg(test)]
+
+use crate::types::{
+    Capability, CapabilityToken, ProposalStatus, RetryConfig, ThresholdStrategy, VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env, Symbol, Vec,
+};
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    bot: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let bot = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+    StellarAssetClient::new(env, &token).mint(&bot, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    Setup {
+        client,
+        admin,
+        bot,
+        token,
+    }
+}
+
+fn grant(s: &Setup<'_>, id: u8, capabilities: Vec<Capability>) -> BytesN<32> {
+    let env = &s.client.env;
+    let token_id = BytesN::from_array(env, &[id; 32]);
+    s.client.grant_capability(
+        &s.admin,
+        &CapabilityToken {
+            id: token_id.clone(),
+            granted_to: s.bot.clone(),
+            capabilities,
+            expires_at: 0,
+            max_uses: 0,
+            uses_count: 7,
+            amount_used: 1_000,
+            revoked: false,
+        },
+    );
+    token_id
+}
+
+#[test]
+fn test_capability_creates_stream_within_scope_without_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let payee = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    assert_eq!(
+        s.client
+            .try_create_stream(&s.bot, &payee, &s.token, &1, &100, &100),
+        Err(Ok(VaultError::InsufficientRole))
+    );
+
+    let token_id = grant(
+        &s,
+        1,
+        soroban_sdk::vec![
+            &env,
+            Capability::InitiateStream(500),
+            Capability::Recipients(soroban_sdk::vec![&env, payee.clone()]),
+            Capability::AmountBudget(800),
+        ],
+    );
+    // Counters supplied at grant time are ignored
+    let granted = s.client.get_capability(&token_id).unwrap();
+    assert_eq!(granted.uses_count, 0);
+    assert_eq!(granted.amount_used, 0);
+
+    let stream_id = s
+        .client
+        .create_stream_with_capability(&s.bot, &token_id, &payee, &s.token, &5, &500, &100);
+    let stream = s.client.get_stream(&stream_id);
+    assert_eq!(stream.sender, s.bot);
+    assert_eq!(stream.total_amount, 500);
+
+    // Per-use ceiling, recipient allowlist and cumulative budget all apply
+    assert_eq!(
+        s.client.try_create_stream_with_capability(
+            &s.bot, &token_id, &payee, &s.token, &6, &600, &100,
+        ),
+        Err(Ok(VaultError::CapabilityNotGranted))
+    );
+    assert_eq!(
+        s.client.try_create_stream_with_capability(
+            &s.bot, &token_id, &other, &s.token, &1, &100, &100,
+        ),
+        Err(Ok(VaultError::CapabilityNotGranted))
+    );
+    assert_eq!(
+        s.client.try_create_stream_with_capability(
+            &s.bot, &token_id, &payee, &s.token, &4, &400, &100,
+        ),
+        Err(Ok(VaultError::CapabilityBudgetExceeded))
+    );
+    s.client
+        .create_stream_with_capability(&s.bot, &token_id, &payee, &s.token, &3, &300, &100);
+
+    let used = s.client.get_capability(&token_id).unwrap();
+    assert_eq!(used.uses_count, 2);
+    assert_eq!(used.amount_used, 800);
+
+    // A proposal is a different action and is not covered
+    assert_eq!(
+        s.client.try_propose_transfer_with_capability(
+            &s.bot,
+            &token_id,
+            &payee,
+            &s.token,
+            &10,
+            &Symbol::new(&env, "bot"),
+        ),
+        Err(Ok(VaultError::CapabilityNotGranted))
+    );
+}
+
+#[test]
+fn test_capability_proposal_follows_normal_approval_flow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let payee = Address::generate(&env);
+    let other_token = env
+        .register_stellar_asset_contract_v2(s.admin.clone())
+        .address();
+
+    let token_id = grant(
+        &s,
+        2,
+        soroban_sdk::vec![
+            &env,
+            Capability::CreateProposal(1_000),
+            Capability::Tokens(soroban_sdk::vec![&env, s.token.clone()]),
+        ],
+    );
+
+    assert_eq!(
+        s.client.try_propose_transfer_with_capability(
+            &s.bot,
+            &token_id,
+            &payee,
+            &other_token,
+            &100,
+            &Symbol::new(&env, "bot"),
+        ),
+        Err(Ok(VaultError::CapabilityNotGranted))
+    );
+    // A bare use cannot prove the token restriction is satisfied
+    assert_eq!(
+        s.client
+            .try_use_capability(&s.bot, &token_id, &Capability::CreateProposal(100)),
+        Err(Ok(VaultError::CapabilityNotGranted))
+    );
+
+    let proposal_id = s.client.propose_transfer_with_capability(
+        &s.bot,
+        &token_id,
+        &payee,
+        &s.token,
+        &100,
+        &Symbol::new(&env, "bot"),
+    );
+    let proposal = s.client.get_proposal(&proposal_id);
+    assert_eq!(proposal.proposer, s.bot);
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+
+    s.client.approve_proposal(&s.admin, &proposal_id);
+    s.client.execute_proposal(&s.admin, &proposal_id);
+    assert_eq!(
+        s.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+
+    s.client.revoke_capability(&s.admin, &token_id);
+    assert_eq!(
+        s.client.try_propose_transfer_with_capability(
+            &s.bot,
+            &token_id,
+            &payee,
+            &s.token,
+            &100,
+            &Symbol::new(&env, "bot"),
+        ),
+        Err(Ok(VaultError::CapabilityRevoked))
+    );
+}
+
+#[test]
+fn test_capability_scope_rejections_do_not_consume() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let payee = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let other_token = env
+        .register_stellar_asset_contract_v2(s.admin.clone())
+        .address();
+    StellarAssetClient::new(&env, &other_token).mint(&s.bot, &100_000);
+
+    let token_id = grant(
+        &s,
+        3,
+        soroban_sdk::vec![
+            &env,
+            Capability::CreateProposal(1_000),
+            Capability::InitiateStream(1_000),
+            Capability::Recipients(soroban_sdk::vec![&env, payee.clone()]),
+            Capability::Tokens(soroban_sdk::vec![&env, s.token.clone()]),
+            Capability::AmountBudget(500),
+        ],
+    );
+    let memo = Symbol::new(&env, "bot");
+
+    // Recipient outside the allowlist
+    assert_eq!(
+        s.client.try_propose_transfer_with_capability(
+            &s.bot, &token_id, &stranger, &s.token, &100, &memo,
+        ),
+        Err(Ok(VaultError::CapabilityNotGranted))
+    );
+    // Token outside the allowlist
+    assert_eq!(
+        s.client.try_create_stream_with_capability(
+            &s.bot,
+            &token_id,
+            &payee,
+            &other_token,
+            &1,
+            &100,
+            &100,
+        ),
+        Err(Ok(VaultError::CapabilityNotGranted))
+    );
+    // Amount beyond the remaining budget
+    assert_eq!(
+        s.client.try_propose_transfer_with_capability(
+            &s.bot, &token_id, &payee, &s.token, &600, &memo,
+        ),
+        Err(Ok(VaultError::CapabilityBudgetExceeded))
+    );
+
+    // None of the rejected uses count against the token
+    let unused = s.client.get_capability(&token_id).unwrap();
+    assert_eq!(unused.uses_count, 0);
+    assert_eq!(unused.amount_used, 0);
+
+    s.client
+        .propose_transfer_with_capability(&s.bot, &token_id, &payee, &s.token, &300, &memo);
+    assert_eq!(
+        s.client.try_create_stream_with_capability(
+            &s.bot, &token_id, &payee, &s.token, &3, &300, &100,
+        ),
+        Err(Ok(VaultError::CapabilityBudgetExceeded))
+    );
+    let used = s.client.get_capability(&token_id).unwrap();
+    assert_eq!(used.uses_count, 1);
+    assert_eq!(used.amount_used, 300);
+}
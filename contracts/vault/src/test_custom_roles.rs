@@ -0,0 +1,363 @@
+//! Tests for custom roles, built-in role presets and permission resolution.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, DisputeOutcome, GrantConstraints, ListMode, Permission, PermissionScope,
+    Priority, ProposalStatus, RetryConfig, Role, ThresholdStrategy, VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address) {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(Address::generate(env));
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, token)
+}
+
+fn unscoped(env: &Env) -> PermissionScope {
+    PermissionScope {
+        max_amount: 0,
+        tokens: Vec::new(env),
+        tag_ids: Vec::new(env),
+    }
+}
+
+fn try_propose(
+    client: &VaultDAOClient<'_>,
+    proposer: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<u64, VaultError> {
+    client
+        .try_propose_transfer(
+            proposer,
+            &Address::generate(&client.env),
+            token,
+            &amount,
+            &Symbol::new(&client.env, "pay"),
+            &Priority::Normal,
+            &Vec::new(&client.env),
+            &ConditionLogic::And,
+            &0i128,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_custom_role_grants_and_loses_permissions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _token) = setup(&env);
+    let member = Address::generate(&env);
+    let listed = Address::generate(&env);
+    let name = Symbol::new(&env, "compliance");
+
+    assert_eq!(
+        client.try_add_to_whitelist(&member, &listed),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    client.define_custom_role(
+        &admin,
+        &name,
+        &Vec::from_array(&env, [Permission::ManageLists]),
+        &unscoped(&env),
+    );
+    client.assign_custom_role(&admin, &member, &name);
+    assert_eq!(
+        client.get_custom_roles_of(&member),
+        Vec::from_array(&env, [name.clone()])
+    );
+    client.add_to_whitelist(&member, &listed);
+    assert!(client.has_permission(&member, &Permission::ManageLists));
+    assert!(!client.has_permission(&member, &Permission::ManageConfig));
+
+    // Removing the definition revokes it from every holder at once.
+    client.remove_custom_role(&admin, &name);
+    assert!(client.list_custom_roles().is_empty());
+    assert_eq!(
+        client.try_add_to_whitelist(&member, &listed),
+        Err(Ok(VaultError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_scoped_role_limits_amount_token_and_tags() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+    let other_token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let grants = Address::generate(&env);
+
+    client.define_custom_role(
+        &admin,
+        &Symbol::new(&env, "small_grants"),
+        &Vec::from_array(&env, [Permission::CreateProposal]),
+        &PermissionScope {
+            max_amount: 1_000,
+            tokens: Vec::from_array(&env, [token.clone()]),
+            tag_ids: Vec::new(&env),
+        },
+    );
+    client.assign_custom_role(&admin, &grants, &Symbol::new(&env, "small_grants"));
+
+    try_propose(&client, &grants, &token, 1_000).unwrap();
+    assert_eq!(
+        try_propose(&client, &grants, &token, 1_001),
+        Err(VaultError::InsufficientRole)
+    );
+    assert_eq!(
+        try_propose(&client, &grants, &other_token, 10),
+        Err(VaultError::InsufficientRole)
+    );
+    // A scoped role does not satisfy an unscoped check.
+    assert!(!client.has_permission(&grants, &Permission::CreateProposal));
+
+    // Tag scopes cover child tags too.
+    let eng = client.create_tag(&admin, &Symbol::new(&env, "eng"), &None);
+    let backend = client.create_tag(&admin, &Symbol::new(&env, "backend"), &Some(eng));
+    let steward = Address::generate(&env);
+    client.define_custom_role(
+        &admin,
+        &Symbol::new(&env, "eng_steward"),
+        &Vec::from_array(&env, [Permission::CancelProposal]),
+        &PermissionScope {
+            max_amount: 0,
+            tokens: Vec::new(&env),
+            tag_ids: Vec::from_array(&env, [eng]),
+        },
+    );
+    client.assign_custom_role(&admin, &steward, &Symbol::new(&env, "eng_steward"));
+
+    let untagged = try_propose(&client, &admin, &token, 500).unwrap();
+    let tagged = try_propose(&client, &admin, &token, 500).unwrap();
+    client.assign_tags(&admin, &tagged, &Vec::from_array(&env, [backend]));
+    let reason = Symbol::new(&env, "off_scope");
+    assert_eq!(
+        client.try_cancel_proposal(&steward, &untagged, &reason),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    client.cancel_proposal(&steward, &tagged, &reason);
+    assert_eq!(
+        client.get_proposal(&tagged).status,
+        ProposalStatus::Rejected
+    );
+}
+
+#[test]
+fn test_dispute_outcome_resolved_through_permission_grant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+    let arbitrator = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&admin, &100);
+
+    let proposal_id = try_propose(&client, &admin, &token, 100).unwrap();
+    let dispute_id = client.raise_dispute(
+        &admin,
+        &proposal_id,
+        &None,
+        &Symbol::new(&env, "fraud"),
+        &Vec::new(&env),
+        &token,
+        &100,
+    );
+    assert_eq!(
+        client.try_resolve_dispute_with_outcome(
+            &arbitrator,
+            &dispute_id,
+            &DisputeOutcome::DrawDispute
+        ),
+        Err(Ok(VaultError::Unauthorized))
+    );
+
+    client.grant_permission(&admin, &arbitrator, &Permission::ResolveDisputes, &None);
+    client.resolve_dispute_with_outcome(&arbitrator, &dispute_id, &DisputeOutcome::DrawDispute);
+    assert_eq!(client.get_dispute(&dispute_id).arbitrator, arbitrator);
+}
+
+#[test]
+fn test_grants_and_delegations_resolve_through_same_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _token) = setup(&env);
+    let operator = Address::generate(&env);
+
+    client.grant_permission(&admin, &operator, &Permission::ManageLists, &Some(200));
+    client.set_list_mode(&operator, &ListMode::Whitelist);
+    env.ledger().set_sequence_number(200);
+    assert_eq!(
+        client.try_set_list_mode(&operator, &ListMode::Disabled),
+        Err(Ok(VaultError::Unauthorized))
+    );
+
+    client.delegate_permission(&admin, &operator, &Permission::ManageLists, &300);
+    client.set_list_mode(&operator, &ListMode::Disabled);
+    env.ledger().set_sequence_number(300);
+    assert_eq!(
+        client.try_set_list_mode(&operator, &ListMode::Whitelist),
+        Err(Ok(VaultError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_admin_equivalent_permissions_require_rotation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _token) = setup(&env);
+    let operator = Address::generate(&env);
+
+    for permission in [
+        Permission::ManageRoles,
+        Permission::ManageConfig,
+        Permission::ManageSigners,
+    ] {
+        assert_eq!(
+            client.try_grant_permission(&admin, &operator, &permission, &None),
+            Err(Ok(VaultError::AdminRotationRequired))
+        );
+        assert_eq!(
+            client.try_grant_scoped_permission(
+                &admin,
+                &operator,
+                &permission,
+                &None,
+                &GrantConstraints::unrestricted(&env)
+            ),
+            Err(Ok(VaultError::AdminRotationRequired))
+        );
+        assert_eq!(
+            client.try_delegate_permission(&admin, &operator, &permission, &300),
+            Err(Ok(VaultError::AdminRotationRequired))
+        );
+        assert_eq!(
+            client.try_define_custom_role(
+                &admin,
+                &Symbol::new(&env, "operator"),
+                &Vec::from_array(&env, [permission]),
+                &unscoped(&env)
+            ),
+            Err(Ok(VaultError::CannotAssignHigherRole))
+        );
+        assert!(!client.has_permission(&operator, &permission));
+    }
+}
+
+#[test]
+fn test_presets_and_role_definition_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _token) = setup(&env);
+    let member = Address::generate(&env);
+
+    let treasurer = client.get_role_preset(&Role::Treasurer);
+    assert!(treasurer.contains(Permission::CreateProposal));
+    assert!(!treasurer.contains(Permission::ManageConfig));
+    assert_eq!(client.get_role_preset(&Role::Admin).len(), 17);
+    assert!(client
+        .get_role_preset(&Role::DisputeArbitrator)
+        .contains(Permission::ResolveDisputes));
+
+    let perms = Vec::from_array(&env, [Permission::ViewMetrics]);
+    assert_eq!(
+        client.try_define_custom_role(
+            &admin,
+            &Symbol::new(&env, "treasurer"),
+            &perms,
+            &unscoped(&env)
+        ),
+        Err(Ok(VaultError::RoleNameReserved))
+    );
+    assert_eq!(
+        client.try_define_custom_role(
+            &admin,
+            &Symbol::new(&env, "gatekeeper"),
+            &Vec::from_array(&env, [Permission::ManageRoles]),
+            &unscoped(&env)
+        ),
+        Err(Ok(VaultError::CannotAssignHigherRole))
+    );
+    assert_eq!(
+        client.try_define_custom_role(
+            &member,
+            &Symbol::new(&env, "viewer"),
+            &perms,
+            &unscoped(&env)
+        ),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_assign_custom_role(&admin, &member, &Symbol::new(&env, "viewer")),
+        Err(Ok(VaultError::CustomRoleNotFound))
+    );
+
+    for name in ["r1", "r2", "r3", "r4", "r5"] {
+        client.define_custom_role(&admin, &Symbol::new(&env, name), &perms, &unscoped(&env));
+    }
+    for name in ["r1", "r2", "r3", "r4"] {
+        client.assign_custom_role(&admin, &member, &Symbol::new(&env, name));
+    }
+    assert_eq!(
+        client.try_assign_custom_role(&admin, &member, &Symbol::new(&env, "r5")),
+        Err(Ok(VaultError::TooManyCustomRoles))
+    );
+    client.unassign_custom_role(&admin, &member, &Symbol::new(&env, "r1"));
+    client.assign_custom_role(&admin, &member, &Symbol::new(&env, "r5"));
+}
@@ -0,0 +1,168 @@
+//! Tests for the versioned event schema and the typed event decoder.
+#![cfg(test)]
+
+use crate::decode::{self, DecodeError, Event, FullQuorumThresholdEvent, InitializedEvent};
+use crate::events::EVENT_SCHEMA_VERSION;
+use crate::types::{RetryConfig, ThresholdStrategy, VelocityConfig};
+use crate::{InitConfig, VaultDAO, VaultDAOClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token::StellarAssetClient,
+    vec,
+    xdr::{Limits, WriteXdr},
+    Address, Env, IntoVal, Symbol, Val, Vec,
+};
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address) {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(Address::generate(env));
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, token)
+}
+
+#[test]
+fn test_published_events_decode_to_typed_payloads() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+
+    client.set_full_quorum_threshold(&admin, &500);
+
+    let events = env.events().all().filter_by_contract(&client.address);
+    assert!(!events.events().is_empty());
+    let mut saw_threshold = false;
+    for event in events.events() {
+        let typed = decode::decode_contract_event(&env, event).unwrap();
+        let bytes = event.to_xdr(Limits::none()).unwrap();
+        assert_eq!(decode::decode_xdr(&env, &bytes), Ok(typed.clone()));
+        if let Event::FullQuorumThreshold(payload) = typed {
+            assert_eq!(payload, FullQuorumThresholdEvent { threshold: 500 });
+            saw_threshold = true;
+        }
+    }
+    assert!(saw_threshold);
+}
+
+#[test]
+fn test_decode_rejects_unknown_versions_names_and_payloads() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let payload: Val = InitializedEvent {
+        admin: admin.clone(),
+        threshold: 2,
+    }
+    .into_val(&env);
+    let topics = |name: &str, version: u32| -> Vec<Val> {
+        vec![
+            &env,
+            Symbol::new(&env, name).into_val(&env),
+            version.into_val(&env),
+        ]
+    };
+
+    assert_eq!(
+        decode::decode(&env, &topics("initialized", EVENT_SCHEMA_VERSION), &payload),
+        Ok(Event::Initialized(InitializedEvent {
+            admin,
+            threshold: 2
+        }))
+    );
+    assert_eq!(
+        decode::decode(
+            &env,
+            &topics("initialized", EVENT_SCHEMA_VERSION + 1),
+            &payload
+        ),
+        Err(DecodeError::UnsupportedVersion(EVENT_SCHEMA_VERSION + 1))
+    );
+    assert_eq!(
+        decode::decode(
+            &env,
+            &topics("no_such_event", EVENT_SCHEMA_VERSION),
+            &payload
+        ),
+        Err(DecodeError::UnknownEvent("no_such_event".into()))
+    );
+    assert_eq!(
+        decode::decode(
+            &env,
+            &topics("proposal_created", EVENT_SCHEMA_VERSION),
+            &payload
+        ),
+        Err(DecodeError::InvalidPayload("proposal_created".into()))
+    );
+
+    let unversioned: Vec<Val> = vec![&env, Symbol::new(&env, "initialized").into_val(&env)];
+    assert_eq!(
+        decode::decode(&env, &unversioned, &payload),
+        Err(DecodeError::MalformedTopics)
+    );
+    assert_eq!(
+        decode::decode_xdr(&env, &[0xff, 0x00, 0x01]),
+        Err(DecodeError::InvalidXdr)
+    );
+}
+
+#[test]
+fn test_event_names_are_unique_symbols() {
+    let names = Event::NAMES;
+    for (i, name) in names.iter().enumerate() {
+        assert!(name.len() <= 32, "{name} does not fit in a Symbol");
+        assert!(
+            name.bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_'),
+            "{name} is not snake_case"
+        );
+        assert!(!names[i + 1..].contains(name), "{name} is published twice");
+    }
+}
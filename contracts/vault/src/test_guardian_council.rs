@@ -0,0 +1,336 @@
+//! Tests for the guardian council's emergency actions and signer ratification.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, GuardianAction, GuardianActionStatus, Priority, ProposalStatus, RetryConfig,
+    ScheduledTransferConfig, ThresholdStrategy, VaultSubsystem, VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+const WINDOW: u64 = 100;
+const TIMELOCK: u64 = 200;
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    signer_b: Address,
+    guardians: Vec<Address>,
+    token: Address,
+}
+
+fn setup(env: &Env, guardian_threshold: u32) -> Setup<'_> {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer_b = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer_b.clone());
+    signers.push_back(Address::generate(env));
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 2,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 500,
+            timelock_delay: TIMELOCK,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    let guardians = Vec::from_array(env, [Address::generate(env), Address::generate(env)]);
+    client.set_guardian_council(&admin, &guardians, &guardian_threshold, &WINDOW);
+
+    Setup {
+        client,
+        admin,
+        signer_b,
+        guardians,
+        token,
+    }
+}
+
+fn approved_proposal(s: &Setup<'_>, amount: i128) -> u64 {
+    let env = &s.client.env;
+    let id = s.client.propose_transfer(
+        &s.admin,
+        &Address::generate(env),
+        &s.token,
+        &amount,
+        &Symbol::new(env, "pay"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    s.client.approve_proposal(&s.admin, &id);
+    s.client.approve_proposal(&s.signer_b, &id);
+    id
+}
+
+#[test]
+fn test_vault_pause_lapses_unless_ratified() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 2);
+    let g1 = s.guardians.get(0).unwrap();
+    let g2 = s.guardians.get(1).unwrap();
+
+    assert_eq!(
+        s.client
+            .try_propose_guardian_action(&s.admin, &GuardianAction::PauseVault),
+        Err(Ok(VaultError::NotGuardian))
+    );
+    let id = s
+        .client
+        .propose_guardian_action(&g1, &GuardianAction::PauseVault);
+    assert!(!s.client.get_pause_state().is_paused);
+    s.client.approve_guardian_action(&g2, &id);
+    assert!(s.client.get_pause_state().is_paused);
+    let record = s.client.get_guardian_action(&id);
+    assert_eq!(record.status, GuardianActionStatus::Active);
+    assert_eq!(record.ratify_by, 100 + WINDOW);
+
+    assert_eq!(
+        s.client.try_expire_guardian_action(&id),
+        Err(Ok(VaultError::TimelockNotExpired))
+    );
+    env.ledger().set_sequence_number(101 + WINDOW as u32);
+    assert_eq!(
+        s.client.try_ratify_guardian_action(&s.admin, &id),
+        Err(Ok(VaultError::GuardianActionNotActive))
+    );
+    s.client.expire_guardian_action(&id);
+    assert!(!s.client.get_pause_state().is_paused);
+    assert_eq!(
+        s.client.get_guardian_action(&id).status,
+        GuardianActionStatus::Expired
+    );
+}
+
+#[test]
+fn test_ratified_hold_cancels_approved_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 1);
+    let guardian = s.guardians.get(0).unwrap();
+
+    let held = approved_proposal(&s, 600);
+    let action = s
+        .client
+        .propose_guardian_action(&guardian, &GuardianAction::CancelProposal(held));
+    s.client.ratify_guardian_action(&s.admin, &action);
+    s.client.ratify_guardian_action(&s.signer_b, &action);
+    assert_eq!(
+        s.client.get_guardian_action(&action).status,
+        GuardianActionStatus::Ratified
+    );
+    assert_eq!(
+        s.client.get_proposal(&held).status,
+        ProposalStatus::Cancelled
+    );
+
+    // An unratified hold lapses after the window without anyone expiring it.
+    let delayed = approved_proposal(&s, 600);
+    s.client
+        .propose_guardian_action(&guardian, &GuardianAction::CancelProposal(delayed));
+    env.ledger().set_sequence_number(100 + WINDOW as u32);
+    assert_eq!(
+        s.client.try_execute_proposal(&s.admin, &delayed),
+        Err(Ok(VaultError::GuardianHoldActive))
+    );
+    env.ledger().set_sequence_number(100 + TIMELOCK as u32);
+    s.client.execute_proposal(&s.admin, &delayed);
+    assert_eq!(
+        s.client.get_proposal(&delayed).status,
+        ProposalStatus::Executed
+    );
+}
+
+#[test]
+fn test_frozen_stream_stays_frozen_until_lifted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 1);
+    let guardian = s.guardians.get(0).unwrap();
+    StellarAssetClient::new(&env, &s.token).mint(&s.admin, &10_000);
+    let recipient = Address::generate(&env);
+    let stream_id = s
+        .client
+        .create_stream(&s.admin, &recipient, &s.token, &1, &10_000, &10_000);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+
+    let action = s
+        .client
+        .propose_guardian_action(&guardian, &GuardianAction::FreezeStream(stream_id));
+    assert_eq!(
+        s.client.try_claim_stream(&recipient, &stream_id),
+        Err(Ok(VaultError::GuardianHoldActive))
+    );
+    s.client.ratify_guardian_action(&s.admin, &action);
+    s.client.ratify_guardian_action(&s.signer_b, &action);
+
+    // Ratified holds outlive the window.
+    env.ledger().set_sequence_number(200 + WINDOW as u32);
+    assert_eq!(
+        s.client.try_claim_stream(&recipient, &stream_id),
+        Err(Ok(VaultError::GuardianHoldActive))
+    );
+    assert_eq!(
+        s.client.try_lift_guardian_action(&guardian, &action),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    s.client.lift_guardian_action(&s.admin, &action);
+    assert!(s.client.claim_stream(&recipient, &stream_id) > 0);
+}
+
+#[test]
+fn test_guardian_council_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 1);
+    let guardian = s.guardians.get(0).unwrap();
+
+    assert_eq!(
+        s.client
+            .try_set_guardian_council(&s.admin, &s.guardians, &3, &WINDOW),
+        Err(Ok(VaultError::ThresholdTooHigh))
+    );
+    assert_eq!(
+        s.client
+            .try_set_guardian_council(&s.admin, &s.guardians, &1, &0),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_set_guardian_council(&guardian, &s.guardians, &1, &WINDOW),
+        Err(Ok(VaultError::Unauthorized))
+    );
+
+    // Only approved proposals still inside their timelock can be held.
+    let id = approved_proposal(&s, 100);
+    assert_eq!(
+        s.client
+            .try_propose_guardian_action(&guardian, &GuardianAction::CancelProposal(id)),
+        Err(Ok(VaultError::ChallengeWindowClosed))
+    );
+
+    let subsystem = GuardianAction::PauseSubsystem(VaultSubsystem::Proposals);
+    let action = s.client.propose_guardian_action(&guardian, &subsystem);
+    assert_eq!(
+        s.client.try_propose_guardian_action(&guardian, &subsystem),
+        Err(Ok(VaultError::GuardianHoldActive))
+    );
+    assert_eq!(
+        s.client.try_execute_proposal(&s.admin, &id),
+        Err(Ok(VaultError::GuardianHoldActive))
+    );
+    assert_eq!(
+        s.client
+            .try_ratify_guardian_action(&Address::generate(&env), &action),
+        Err(Ok(VaultError::NotASigner))
+    );
+}
+
+#[test]
+fn test_holds_gate_batch_and_scheduled_execution() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 1);
+    let guardian = s.guardians.get(0).unwrap();
+
+    // A held proposal is skipped by the batch while the others still run.
+    let held = approved_proposal(&s, 600);
+    let free = approved_proposal(&s, 600);
+    env.ledger().set_sequence_number(250);
+    s.client
+        .propose_guardian_action(&guardian, &GuardianAction::CancelProposal(held));
+    env.ledger().set_sequence_number(100 + TIMELOCK as u32);
+    let (executed, failed) = s
+        .client
+        .batch_execute_proposals(&s.admin, &Vec::from_array(&env, [held, free]));
+    assert_eq!(executed, Vec::from_array(&env, [free]));
+    assert_eq!(failed, 1);
+    assert_eq!(
+        s.client.get_proposal(&held).status,
+        ProposalStatus::Approved
+    );
+
+    // Pausing the proposal subsystem also stops scheduled runs.
+    let scheduled = s.client.propose_scheduled_transfer(
+        &s.admin,
+        &Address::generate(&env),
+        &s.token,
+        &100,
+        &Symbol::new(&env, "pay"),
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0,
+        &ScheduledTransferConfig {
+            execution_time: 400,
+            execution_window_ledgers: 0,
+        },
+    );
+    s.client.approve_proposal(&s.admin, &scheduled);
+    s.client.approve_proposal(&s.signer_b, &scheduled);
+    s.client.propose_guardian_action(
+        &guardian,
+        &GuardianAction::PauseSubsystem(VaultSubsystem::Proposals),
+    );
+    env.ledger().set_sequence_number(400);
+    assert_eq!(
+        s.client
+            .try_execute_scheduled_proposal(&s.admin, &scheduled),
+        Err(Ok(VaultError::GuardianHoldActive))
+    );
+
+    // The hold lapses once its ratification window passes.
+    env.ledger().set_sequence_number(401);
+    s.client.execute_scheduled_proposal(&s.admin, &scheduled);
+    assert_eq!(
+        s.client.get_proposal(&scheduled).status,
+        ProposalStatus::Executed
+    );
+}
@@ -0,0 +1,275 @@
+//! Tests for the configurable impact score model and its score bands.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, ImpactBand, ImpactScoreModel, Priority, RetryConfig, ThresholdStrategy,
+    VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+    StellarAssetClient::new(env, &token).mint(&admin, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 500,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    Setup {
+        client,
+        admin,
+        token,
+    }
+}
+
+fn propose(s: &Setup<'_>, amount: i128, insurance: i128) -> Result<u64, VaultError> {
+    let env = &s.client.env;
+    match s.client.try_propose_transfer(
+        &s.admin,
+        &Address::generate(env),
+        &s.token,
+        &amount,
+        &Symbol::new(env, "pay"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &insurance,
+    ) {
+        Ok(Ok(id)) => Ok(id),
+        Err(Ok(err)) => Err(err),
+        _ => panic!("unexpected invocation failure"),
+    }
+}
+
+/// Half treasury impact, half recipient age, with two bands.
+fn banded_model(env: &Env) -> ImpactScoreModel {
+    ImpactScoreModel {
+        treasury_weight: 50,
+        recipient_weight: 0,
+        complexity_weight: 0,
+        recipient_age_weight: 50,
+        recipient_maturity_ledgers: 1_000,
+        bands: soroban_sdk::vec![
+            env,
+            ImpactBand {
+                min_score: 40,
+                extra_approvals: 1,
+                timelock_multiplier_bps: 20_000,
+                insurance_bps: 0,
+            },
+            ImpactBand {
+                min_score: 70,
+                extra_approvals: 0,
+                timelock_multiplier_bps: 10_000,
+                insurance_bps: 1_000,
+            },
+        ],
+        ..ImpactScoreModel::legacy(env)
+    }
+}
+
+#[test]
+fn test_score_bands_replace_high_impact_switch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    assert_eq!(
+        s.client.get_impact_score_model(),
+        ImpactScoreModel::legacy(&env)
+    );
+
+    s.client
+        .set_impact_score_model(&s.admin, &banded_model(&env));
+
+    // New recipient, small amount: 50 points from recipient age -> first band
+    let id = propose(&s, 600, 0).unwrap();
+    let impact = s.client.get_proposal_impact(&id).unwrap();
+    assert_eq!(impact.recipient_age_score, 100);
+    assert_eq!(impact.total_score, 50);
+    assert_eq!(s.client.get_proposal(&id).unlock_ledger, 100 + 200);
+    assert_eq!(s.client.get_proposal_safeguards(&id).extra_approvals, 1);
+    assert_eq!(s.client.get_proposal_risk_report(&id).impact, impact);
+
+    // Half the treasury to a new recipient: the top band needs 10% insurance,
+    // and the +48h timelock for scores above high_impact_threshold is gone
+    assert_eq!(
+        propose(&s, 50_000, 0),
+        Err(VaultError::InsuranceInsufficient)
+    );
+    let id = propose(&s, 50_000, 5_000).unwrap();
+    assert_eq!(s.client.get_proposal_impact(&id).unwrap().total_score, 73);
+    assert_eq!(s.client.get_proposal(&id).unlock_ledger, 100 + 100);
+    assert_eq!(s.client.get_proposal_safeguards(&id).extra_approvals, 0);
+}
+
+#[test]
+fn test_impact_model_validation_and_clear() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        s.client
+            .try_set_impact_score_model(&outsider, &banded_model(&env)),
+        Err(Ok(VaultError::Unauthorized))
+    );
+
+    let mut model = banded_model(&env);
+    model.treasury_weight = 40;
+    assert_eq!(
+        s.client.try_set_impact_score_model(&s.admin, &model),
+        Err(Ok(VaultError::InvalidImpactModel))
+    );
+
+    let mut model = banded_model(&env);
+    let top = model.bands.pop_back().unwrap();
+    model.bands.push_front(top);
+    assert_eq!(
+        s.client.try_set_impact_score_model(&s.admin, &model),
+        Err(Ok(VaultError::InvalidImpactModel))
+    );
+
+    let mut model = banded_model(&env);
+    model.usd_weight = 10;
+    model.treasury_weight = 40;
+    assert_eq!(
+        s.client.try_set_impact_score_model(&s.admin, &model),
+        Err(Ok(VaultError::InvalidImpactModel))
+    );
+
+    s.client
+        .set_impact_score_model(&s.admin, &banded_model(&env));
+    s.client.clear_impact_score_model(&s.admin);
+    assert_eq!(
+        s.client.get_impact_score_model(),
+        ImpactScoreModel::legacy(&env)
+    );
+    let id = propose(&s, 600, 0).unwrap();
+    assert_eq!(s.client.get_proposal_safeguards(&id).extra_approvals, 0);
+}
+
+#[test]
+fn test_invalid_weights_and_bands_keep_previous_model() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client
+        .set_impact_score_model(&s.admin, &banded_model(&env));
+
+    let mut invalid = Vec::new(&env);
+
+    // Weights that only reach 100 by wrapping around
+    let mut model = banded_model(&env);
+    model.treasury_weight = u32::MAX;
+    model.recipient_weight = 51;
+    invalid.push_back(model);
+
+    // Tag risk above 100
+    let mut model = banded_model(&env);
+    model.recipient_age_weight = 40;
+    model.tag_risk_weight = 10;
+    model.tag_risk.set(1, 101);
+    invalid.push_back(model);
+
+    // Band thresholds above 100 or repeated
+    let mut model = banded_model(&env);
+    let mut band = model.bands.get(1).unwrap();
+    band.min_score = 101;
+    model.bands.set(1, band);
+    invalid.push_back(model);
+
+    let mut model = banded_model(&env);
+    let mut band = model.bands.get(1).unwrap();
+    band.min_score = 40;
+    model.bands.set(1, band);
+    invalid.push_back(model);
+
+    // A band may only lengthen the timelock and cannot demand over 100% insurance
+    let mut model = banded_model(&env);
+    let mut band = model.bands.get(0).unwrap();
+    band.timelock_multiplier_bps = 9_999;
+    model.bands.set(0, band);
+    invalid.push_back(model);
+
+    let mut model = banded_model(&env);
+    let mut band = model.bands.get(1).unwrap();
+    band.insurance_bps = 10_001;
+    model.bands.set(1, band);
+    invalid.push_back(model);
+
+    // More bands than allowed
+    let mut model = banded_model(&env);
+    let top = model.bands.get(1).unwrap();
+    for step in 1..=4 {
+        let mut band = top.clone();
+        band.min_score = 70 + step * 5;
+        model.bands.push_back(band);
+    }
+    invalid.push_back(model);
+
+    for model in invalid.iter() {
+        assert_eq!(
+            s.client.try_set_impact_score_model(&s.admin, &model),
+            Err(Ok(VaultError::InvalidImpactModel))
+        );
+    }
+    assert_eq!(s.client.get_impact_score_model(), banded_model(&env));
+}
@@ -0,0 +1,377 @@
+//! Tests for the keeper work queue and bounties paid by `execute_due`.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, EscrowStatus, HookEventType, KeeperBudget, KeeperRun, KeeperWork, Milestone,
+    Priority, ProposalStatus, RetryConfig, ScheduledTransferConfig, StreamStatus,
+    ThresholdStrategy, VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, Symbol, Vec,
+};
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    keeper: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let keeper = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+    StellarAssetClient::new(env, &token).mint(&admin, &10_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    Setup {
+        client,
+        admin,
+        keeper,
+        token,
+    }
+}
+
+fn register_hook(s: &Setup<'_>, event_type: HookEventType, max_fee: i128) {
+    let env = &s.client.env;
+    s.client.register_keeper_hook(
+        &s.admin,
+        &s.keeper,
+        &event_type,
+        &Address::generate(env),
+        &max_fee,
+    );
+}
+
+#[test]
+fn test_due_work_spans_subsystems_and_pays_capped_bounty() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let payee = Address::generate(&env);
+    let memo = Symbol::new(&env, "pay");
+
+    // Scheduled proposal executable from ledger 150
+    let scheduled = s.client.propose_scheduled_transfer(
+        &s.admin,
+        &payee,
+        &s.token,
+        &100,
+        &memo,
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0,
+        &ScheduledTransferConfig {
+            execution_time: 150,
+            execution_window_ledgers: 0,
+        },
+    );
+    s.client.approve_proposal(&s.admin, &scheduled);
+    assert_eq!(
+        s.client.get_proposal(&scheduled).status,
+        ProposalStatus::Scheduled
+    );
+
+    // Pending proposal that will outlive the approval timeout
+    s.client.update_approval_timeout(&s.admin, &500);
+    let stale = s.client.propose_transfer(
+        &s.admin,
+        &payee,
+        &s.token,
+        &200,
+        &memo,
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0,
+    );
+
+    // Recurring payment due at ledger 820, escrow expiring at ledger 300
+    let payment = s
+        .client
+        .schedule_payment(&s.admin, &payee, &s.token, &50, &memo, &720, &0, &0);
+    let mut milestones = Vec::new(&env);
+    milestones.push_back(Milestone {
+        id: 1,
+        percentage: 100,
+        release_ledger: 0,
+        is_completed: false,
+        completion_ledger: 0,
+    });
+    let escrow = s.client.create_escrow(
+        &s.admin,
+        &payee,
+        &s.token,
+        &1_000,
+        &milestones,
+        &200,
+        &Address::generate(&env),
+    );
+
+    assert_eq!(s.client.get_due_work(&20).len(), 0);
+
+    env.ledger().set_sequence_number(900);
+    let due = s.client.get_due_work(&20);
+    assert_eq!(
+        due,
+        soroban_sdk::vec![
+            &env,
+            KeeperWork::ScheduledProposal(scheduled),
+            KeeperWork::ExpireProposal(stale),
+            KeeperWork::RecurringPayment(payment),
+            KeeperWork::EscrowTimeout(escrow),
+        ]
+    );
+    assert_eq!(s.client.get_due_work(&2).len(), 2);
+
+    register_hook(&s, HookEventType::ProposalReadyToExecute, 30);
+    register_hook(&s, HookEventType::RecurringDue, 5);
+    s.client.set_keeper_budget(
+        &s.admin,
+        &KeeperBudget {
+            token: s.token.clone(),
+            bounty_per_item: 20,
+            remaining: 100,
+        },
+    );
+
+    // 20 for the scheduled proposal, 5 (max_fee) for the recurring payment and
+    // nothing for the two item types the keeper has not registered for
+    let run = s.client.execute_due(&s.keeper, &10);
+    assert_eq!(
+        run,
+        KeeperRun {
+            executed: 4,
+            failed: 0,
+            bounty_paid: 25,
+        }
+    );
+    assert_eq!(TokenClient::new(&env, &s.token).balance(&s.keeper), 25);
+    assert_eq!(s.client.get_keeper_budget().unwrap().remaining, 75);
+
+    assert_eq!(
+        s.client.get_proposal(&scheduled).status,
+        ProposalStatus::Executed
+    );
+    assert_eq!(
+        s.client.get_proposal(&stale).status,
+        ProposalStatus::Expired
+    );
+    assert_eq!(
+        s.client.get_escrow_info(&escrow).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(s.client.get_due_work(&20).len(), 0);
+}
+
+#[test]
+fn test_stream_auto_complete_bounty_limited_by_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let payee = Address::generate(&env);
+
+    // Stream a second token the vault holds nothing else of
+    let stream_token = env
+        .register_stellar_asset_contract_v2(s.admin.clone())
+        .address();
+    StellarAssetClient::new(&env, &stream_token).mint(&s.admin, &1_000);
+    let stream_id = s
+        .client
+        .create_stream(&s.admin, &payee, &stream_token, &10, &1_000, &100);
+    s.client
+        .set_stream_auto_complete(&s.admin, &stream_id, &true);
+
+    // Simulate the vault losing most of the escrowed stream balance
+    env.as_contract(&s.client.address, || {
+        crate::token::transfer(&env, &stream_token, &payee, 600);
+    });
+
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(
+        s.client.get_due_work(&20),
+        soroban_sdk::vec![&env, KeeperWork::StreamAutoComplete(stream_id)]
+    );
+
+    register_hook(&s, HookEventType::StreamDue, 50);
+    s.client.set_keeper_budget(
+        &s.admin,
+        &KeeperBudget {
+            token: s.token.clone(),
+            bounty_per_item: 20,
+            remaining: 3,
+        },
+    );
+
+    let run = s.client.execute_due(&s.keeper, &10);
+    assert_eq!(run.executed, 1);
+    assert_eq!(run.bounty_paid, 3);
+    assert_eq!(s.client.get_keeper_budget().unwrap().remaining, 0);
+    assert_eq!(
+        s.client.get_stream(&stream_id).status,
+        StreamStatus::Completed
+    );
+
+    // Nothing left to do, and an empty run pays nothing
+    let idle = s.client.execute_due(&s.keeper, &10);
+    assert_eq!(
+        idle,
+        KeeperRun {
+            executed: 0,
+            failed: 0,
+            bounty_paid: 0,
+        }
+    );
+}
+
+#[test]
+fn test_unpayable_items_are_left_out_and_retries_earn_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let payee = Address::generate(&env);
+    let memo = Symbol::new(&env, "pay");
+
+    let scheduled = s.client.propose_scheduled_transfer(
+        &s.admin,
+        &payee,
+        &s.token,
+        &100,
+        &memo,
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0,
+        &ScheduledTransferConfig {
+            execution_time: 150,
+            execution_window_ledgers: 0,
+        },
+    );
+    s.client.approve_proposal(&s.admin, &scheduled);
+    s.client.update_approval_timeout(&s.admin, &500);
+    let stale = s.client.propose_transfer(
+        &s.admin,
+        &payee,
+        &s.token,
+        &200,
+        &memo,
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0,
+    );
+    // Both first due at ledger 820; the second allows one missed payment.
+    let retrying = s
+        .client
+        .schedule_payment(&s.admin, &payee, &s.token, &50, &memo, &720, &0, &0);
+    s.client
+        .schedule_payment(&s.admin, &payee, &s.token, &50, &memo, &720, &1, &0);
+
+    // Drain the vault so nothing can be paid
+    env.as_contract(&s.client.address, || {
+        crate::token::transfer(&env, &s.token, &s.admin, 100_000);
+    });
+
+    // The unfunded scheduled proposal and the payment past its missed cap
+    // cannot succeed, so they do not take up work slots.
+    env.ledger().set_sequence_number(2_300);
+    assert_eq!(
+        s.client.get_due_work(&20),
+        soroban_sdk::vec![
+            &env,
+            KeeperWork::ExpireProposal(stale),
+            KeeperWork::RecurringPayment(retrying),
+        ]
+    );
+
+    // A recurring payment that only schedules a retry counts as failed and
+    // earns no bounty, but its retry is kept.
+    register_hook(&s, HookEventType::RecurringDue, 5);
+    s.client.set_keeper_budget(
+        &s.admin,
+        &KeeperBudget {
+            token: s.token.clone(),
+            bounty_per_item: 20,
+            remaining: 100,
+        },
+    );
+    let run = s.client.execute_due(&s.keeper, &10);
+    assert_eq!(
+        run,
+        KeeperRun {
+            executed: 1,
+            failed: 1,
+            bounty_paid: 0,
+        }
+    );
+    assert_eq!(
+        s.client.get_proposal(&stale).status,
+        ProposalStatus::Expired
+    );
+    assert_eq!(s.client.get_recurring_payment(&retrying).retry_count, 1);
+    assert_eq!(s.client.get_due_work(&20).len(), 0);
+
+    // The scheduled proposal is picked up again once the vault is funded
+    StellarAssetClient::new(&env, &s.token).mint(&s.client.address, &1_000);
+    assert_eq!(
+        s.client.get_due_work(&20),
+        soroban_sdk::vec![&env, KeeperWork::ScheduledProposal(scheduled)]
+    );
+    assert_eq!(s.client.execute_due(&s.keeper, &10).executed, 1);
+    assert_eq!(
+        s.client.get_proposal(&scheduled).status,
+        ProposalStatus::Executed
+    );
+}
@@ -0,0 +1,321 @@
+//! Tests for the committed-liabilities register and free-balance checks.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, Priority, ProposalStatus, RetryConfig, SolvencyPolicy, SubscriptionTier,
+    ThresholdStrategy, VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+const VAULT_FUNDS: i128 = 10_000;
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address, Address) {
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &VAULT_FUNDS);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, signer, token)
+}
+
+fn try_propose(
+    client: &VaultDAOClient<'_>,
+    admin: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<u64, VaultError> {
+    client
+        .try_propose_transfer(
+            admin,
+            &Address::generate(&client.env),
+            token,
+            &amount,
+            &Symbol::new(&client.env, "pay"),
+            &Priority::Normal,
+            &Vec::new(&client.env),
+            &ConditionLogic::And,
+            &0i128,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+/// Vest `total` to a fresh beneficiary over ledgers 100..1100.
+fn vest(client: &VaultDAOClient<'_>, admin: &Address, token: &Address, total: i128) -> u64 {
+    let beneficiary = Address::generate(&client.env);
+    client.create_vesting_schedule(admin, &beneficiary, token, &total, &100, &100, &1_100)
+}
+
+#[test]
+fn test_streams_and_escrows_are_self_funded_liabilities() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _signer, token) = setup(&env);
+    StellarAssetClient::new(&env, &token).mint(&admin, &5_000);
+
+    let recipient = Address::generate(&env);
+    let stream_id = client.create_stream(&admin, &recipient, &token, &1, &1_000, &1_000);
+    client.create_escrow(
+        &admin,
+        &recipient,
+        &token,
+        &2_000,
+        &Vec::from_array(
+            &env,
+            [crate::types::Milestone {
+                id: 1,
+                percentage: 100,
+                release_ledger: 0,
+                is_completed: false,
+                completion_ledger: 0,
+            }],
+        ),
+        &1_000,
+        &admin,
+    );
+
+    let report = client.get_liabilities(&token);
+    assert_eq!(report.balance, VAULT_FUNDS + 3_000);
+    assert_eq!(report.streams, 1_000);
+    assert_eq!(report.escrows, 2_000);
+    assert_eq!(report.total, 3_000);
+    // Deposited funds back their own obligations.
+    assert_eq!(client.get_free_balance(&token), VAULT_FUNDS);
+
+    env.ledger().with_mut(|li| li.timestamp += 400);
+    assert_eq!(client.claim_stream(&recipient, &stream_id), 400);
+    assert_eq!(client.get_liabilities(&token).streams, 600);
+
+    client.cancel_stream(&admin, &stream_id);
+    assert_eq!(client.get_liabilities(&token).streams, 0);
+    assert_eq!(client.get_free_balance(&token), VAULT_FUNDS);
+}
+
+#[test]
+fn test_vesting_reduces_free_balance_and_blocks_proposals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _signer, token) = setup(&env);
+
+    vest(&client, &admin, &token, 8_000);
+    assert_eq!(client.get_liabilities(&token).vesting, 8_000);
+    assert_eq!(client.get_free_balance(&token), 2_000);
+
+    assert_eq!(
+        try_propose(&client, &admin, &token, 2_001),
+        Err(VaultError::InsufficientFreeBalance)
+    );
+    try_propose(&client, &admin, &token, 2_000).unwrap();
+}
+
+#[test]
+fn test_new_commitments_cannot_exceed_free_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _signer, token) = setup(&env);
+
+    vest(&client, &admin, &token, 8_000);
+    let result = client.try_create_vesting_schedule(
+        &admin,
+        &Address::generate(&env),
+        &token,
+        &2_001,
+        &100,
+        &100,
+        &1_100,
+    );
+    assert_eq!(result, Err(Ok(VaultError::InsufficientFreeBalance)));
+
+    // A recurring payment commits its next installment.
+    let recipient = Address::generate(&env);
+    let pay = Symbol::new(&env, "rent");
+    let result =
+        client.try_schedule_payment(&admin, &recipient, &token, &2_001, &pay, &720, &0, &0);
+    assert_eq!(result, Err(Ok(VaultError::InsufficientFreeBalance)));
+    let payment_id =
+        client.schedule_payment(&admin, &recipient, &token, &1_500, &pay, &720, &0, &0);
+    assert_eq!(client.get_liabilities(&token).recurring, 1_500);
+    assert_eq!(client.get_free_balance(&token), 500);
+
+    client.stop_recurring_payment(&admin, &payment_id);
+    assert_eq!(client.get_liabilities(&token).recurring, 0);
+}
+
+#[test]
+fn test_subscription_renewals_are_liabilities() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _signer, token) = setup(&env);
+    let outsider = Address::generate(&env);
+    let provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&outsider, &10_000);
+
+    // Renewals funded by an outside subscriber never touch the vault balance
+    client.create_subscription(
+        &outsider,
+        &provider,
+        &SubscriptionTier::Basic,
+        &token,
+        &5_000,
+        &100,
+        &false,
+        &10,
+    );
+    assert_eq!(client.get_liabilities(&token).subscriptions, 0);
+    assert_eq!(client.get_free_balance(&token), VAULT_FUNDS);
+
+    // The vault paying for a service itself commits its own funds
+    let subscriber = client.address.clone();
+    let sub_id = client.create_subscription(
+        &subscriber,
+        &provider,
+        &SubscriptionTier::Basic,
+        &token,
+        &1_000,
+        &100,
+        &false,
+        &10,
+    );
+    let report = client.get_liabilities(&token);
+    assert_eq!(report.subscriptions, 1_000);
+    assert_eq!(report.total, 1_000);
+    // The vault already paid its first period to the provider
+    let balance = VAULT_FUNDS - 1_000;
+    assert_eq!(client.get_free_balance(&token), balance - 1_000);
+    assert_eq!(
+        try_propose(&client, &admin, &token, balance - 999),
+        Err(VaultError::InsufficientFreeBalance)
+    );
+
+    // A renewal keeps the next one registered; an upgrade re-prices it.
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    client.renew_subscription(&subscriber, &sub_id);
+    assert_eq!(client.get_liabilities(&token).subscriptions, 1_000);
+    client.upgrade_subscription(&subscriber, &sub_id, &SubscriptionTier::Premium, &2_500);
+    assert_eq!(client.get_liabilities(&token).subscriptions, 2_500);
+
+    // Expiry releases it, reactivation registers it again, cancel releases it.
+    env.ledger().with_mut(|li| li.sequence_number += 200);
+    // Both subscriptions lapse; only the vault's one held a liability
+    assert_eq!(client.expire_overdue_subscriptions(&admin), 2);
+    assert_eq!(client.get_liabilities(&token).subscriptions, 0);
+    client.reactivate_subscription(&subscriber, &sub_id);
+    assert_eq!(client.get_liabilities(&token).subscriptions, 2_500);
+    client.cancel_subscription(&subscriber, &sub_id);
+    assert_eq!(client.get_liabilities(&token).subscriptions, 0);
+}
+
+#[test]
+fn test_override_policy_requires_extra_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer, token) = setup(&env);
+
+    vest(&client, &admin, &token, 8_000);
+    client.set_solvency_policy(&admin, &SolvencyPolicy::OverrideApprovals(1));
+
+    let covered = try_propose(&client, &admin, &token, 1_000).unwrap();
+    assert_eq!(client.get_proposal_safeguards(&covered).extra_approvals, 0);
+
+    let over = try_propose(&client, &admin, &token, 3_000).unwrap();
+    assert_eq!(client.get_proposal_safeguards(&over).extra_approvals, 1);
+    client.approve_proposal(&admin, &over);
+    assert_eq!(client.get_proposal(&over).status, ProposalStatus::Pending);
+    client.approve_proposal(&signer, &over);
+    assert_eq!(client.get_proposal(&over).status, ProposalStatus::Approved);
+}
+
+#[test]
+fn test_unencumbered_token_is_not_checked() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _signer, token) = setup(&env);
+
+    // Without liabilities a proposal may still be raised ahead of funding.
+    try_propose(&client, &admin, &token, VAULT_FUNDS * 2).unwrap();
+    assert_eq!(client.get_free_balance(&token), VAULT_FUNDS);
+
+    // Liabilities in another token do not bring this one under the check.
+    let other = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(&env, &other).mint(&client.address, &1_000);
+    vest(&client, &admin, &other, 1_000);
+    assert_eq!(client.get_free_balance(&other), 0);
+    assert_eq!(
+        try_propose(&client, &admin, &other, 1),
+        Err(VaultError::InsufficientFreeBalance)
+    );
+    try_propose(&client, &admin, &token, VAULT_FUNDS * 3).unwrap();
+    assert_eq!(client.get_liabilities(&token).total, 0);
+}
+
+#[test]
+fn test_set_solvency_policy_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer, _token) = setup(&env);
+
+    assert_eq!(client.get_solvency_policy(), SolvencyPolicy::Block);
+    assert_eq!(
+        client.try_set_solvency_policy(&signer, &SolvencyPolicy::OverrideApprovals(1)),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_solvency_policy(&admin, &SolvencyPolicy::OverrideApprovals(0)),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+}
@@ -0,0 +1,234 @@
+//! Tests for optimistic (objection-based) proposals.
+#![cfg(test)]
+
+use crate::types::{
+    OptimisticConfig, Priority, ProposalStatus, RetryConfig, Role, SignerTier, ThresholdStrategy,
+    VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+const VETO_WINDOW: u64 = 100;
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    signer_b: Address,
+    signer_c: Address,
+    vetoer: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer_b = Address::generate(env);
+    let signer_c = Address::generate(env);
+    let vetoer = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer_b.clone());
+    signers.push_back(signer_c.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 2,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::from_array(env, [vetoer.clone()]),
+            veto_window_ledgers: VETO_WINDOW,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    Setup {
+        client,
+        admin,
+        signer_b,
+        signer_c,
+        vetoer,
+        token,
+    }
+}
+
+fn enable(s: &Setup<'_>, objection_threshold: u32) {
+    s.client.set_optimistic_config(
+        &s.admin,
+        &OptimisticConfig {
+            enabled: true,
+            max_amount: 1_000,
+            tier_cap_multiplier: 3,
+            objection_threshold,
+        },
+    );
+}
+
+fn try_propose(s: &Setup<'_>, proposer: &Address, amount: i128) -> Result<u64, VaultError> {
+    let env = &s.client.env;
+    s.client
+        .try_propose_optimistic_transfer(
+            proposer,
+            &Address::generate(env),
+            &s.token,
+            &amount,
+            &Symbol::new(env, "routine"),
+            &Priority::Normal,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_unchallenged_proposal_executes_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    enable(&s, 1);
+
+    let id = try_propose(&s, &s.admin, 500).unwrap();
+    let proposal = s.client.get_proposal(&id);
+    assert_eq!(proposal.status, ProposalStatus::Approved);
+    assert_eq!(proposal.unlock_ledger, 100 + VETO_WINDOW + 1);
+    assert_eq!(
+        s.client.try_execute_proposal(&s.admin, &id),
+        Err(Ok(VaultError::TimelockNotExpired))
+    );
+
+    env.ledger()
+        .set_sequence_number(100 + VETO_WINDOW as u32 + 1);
+    assert_eq!(
+        s.client.try_object_to_proposal(&s.signer_b, &id),
+        Err(Ok(VaultError::ChallengeWindowClosed))
+    );
+    s.client.execute_proposal(&s.admin, &id);
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Executed);
+}
+
+#[test]
+fn test_objections_escalate_to_normal_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    enable(&s, 2);
+
+    let id = try_propose(&s, &s.admin, 500).unwrap();
+    s.client.object_to_proposal(&s.signer_b, &id);
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Approved);
+    assert_eq!(
+        s.client.try_object_to_proposal(&s.signer_b, &id),
+        Err(Ok(VaultError::AlreadyObjected))
+    );
+
+    s.client.object_to_proposal(&s.signer_c, &id);
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Pending);
+    assert!(s.client.get_optimistic_state(&id).unwrap().escalated);
+
+    // The escalated proposal needs the normal 2-of-3 approvals.
+    env.ledger()
+        .set_sequence_number(100 + VETO_WINDOW as u32 + 1);
+    assert_eq!(
+        s.client.try_execute_proposal(&s.admin, &id),
+        Err(Ok(VaultError::ProposalNotApproved))
+    );
+    s.client.approve_proposal(&s.admin, &id);
+    s.client.approve_proposal(&s.signer_b, &id);
+    s.client.execute_proposal(&s.admin, &id);
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Executed);
+}
+
+#[test]
+fn test_veto_address_can_veto_during_challenge() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    enable(&s, 1);
+
+    let id = try_propose(&s, &s.admin, 500).unwrap();
+    s.client.veto_proposal(&s.vetoer, &id);
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Vetoed);
+}
+
+#[test]
+fn test_optimistic_caps_and_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    assert_eq!(
+        try_propose(&s, &s.admin, 500),
+        Err(VaultError::OptimisticModeDisabled)
+    );
+    let mut optimistic = s.client.get_optimistic_config();
+    optimistic.enabled = true;
+    optimistic.max_amount = 1_000;
+    optimistic.objection_threshold = 0;
+    assert_eq!(
+        s.client.try_set_optimistic_config(&s.admin, &optimistic),
+        Err(Ok(VaultError::InvalidThresholdConfig))
+    );
+    optimistic.objection_threshold = 4;
+    assert_eq!(
+        s.client.try_set_optimistic_config(&s.admin, &optimistic),
+        Err(Ok(VaultError::ThresholdTooHigh))
+    );
+    assert_eq!(
+        s.client.try_set_optimistic_config(&s.signer_b, &optimistic),
+        Err(Ok(VaultError::Unauthorized))
+    );
+
+    enable(&s, 1);
+    assert_eq!(
+        try_propose(&s, &s.admin, 1_001),
+        Err(VaultError::ExceedsOptimisticCap)
+    );
+
+    // Junior with a 100 limit is capped at 3x in optimistic mode.
+    s.client.set_role(&s.admin, &s.signer_b, &Role::Treasurer);
+    s.client
+        .set_signer_tier(&s.admin, &s.signer_b, &SignerTier::Junior(100));
+    assert_eq!(
+        try_propose(&s, &s.signer_b, 301),
+        Err(VaultError::ExceedsOptimisticCap)
+    );
+    try_propose(&s, &s.signer_b, 300).unwrap();
+}
@@ -0,0 +1,270 @@
+//! Tests for typed multi-field governance parameter changes.
+#![cfg(test)]
+
+use crate::types::{
+    ConfigChange, ConfigFieldDiff, ProposalStatus, RetryConfig, ThresholdStrategy, VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Vec,
+};
+
+const LOW: u64 = 17_280;
+const MEDIUM: u64 = 51_840;
+const HIGH: u64 = 120_960;
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address) {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer = Address::generate(env);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, signer)
+}
+
+fn try_propose(
+    client: &VaultDAOClient<'_>,
+    proposer: &Address,
+    changes: &[ConfigChange],
+) -> Result<u64, VaultError> {
+    let mut batch = Vec::new(&client.env);
+    for change in changes {
+        batch.push_back(change.clone());
+    }
+    client
+        .try_propose_param_change(proposer, &batch)
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_batch_change_applies_atomically_after_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer) = setup(&env);
+
+    let id = try_propose(
+        &client,
+        &admin,
+        &[
+            ConfigChange::VetoWindow(50),
+            ConfigChange::SpendingLimit(5_000),
+        ],
+    )
+    .unwrap();
+    let proposal = client.get_param_change_proposal(&id).unwrap();
+    assert_eq!(
+        proposal.changes.get(0).unwrap(),
+        ConfigFieldDiff {
+            old_value: ConfigChange::VetoWindow(0),
+            new_value: ConfigChange::VetoWindow(50),
+        }
+    );
+    assert_eq!(proposal.timelock_ledgers, HIGH);
+
+    client.approve_param_change(&admin, &id);
+    client.approve_param_change(&signer, &id);
+    let proposal = client.get_param_change_proposal(&id).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Approved);
+    assert_eq!(proposal.unlock_ledger, 100 + HIGH);
+    assert_eq!(
+        client.try_execute_param_change(&admin, &id),
+        Err(Ok(VaultError::TimelockNotExpired))
+    );
+
+    env.ledger().set_sequence_number((100 + HIGH) as u32);
+    client.execute_param_change(&admin, &id);
+    let config = client.get_config();
+    assert_eq!(config.veto_window_ledgers, 50);
+    assert_eq!(config.spending_limit, 5_000);
+    assert_eq!(
+        client.get_param_change_proposal(&id).unwrap().status,
+        ProposalStatus::Executed
+    );
+}
+
+#[test]
+fn test_timelock_scales_with_sensitivity() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _signer) = setup(&env);
+
+    let low = try_propose(&client, &admin, &[ConfigChange::GracePeriod(200)]).unwrap();
+    let medium = try_propose(
+        &client,
+        &admin,
+        &[
+            ConfigChange::GracePeriod(300),
+            ConfigChange::ExecWindow(1_000),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        client
+            .get_param_change_proposal(&low)
+            .unwrap()
+            .timelock_ledgers,
+        LOW
+    );
+    assert_eq!(
+        client
+            .get_param_change_proposal(&medium)
+            .unwrap()
+            .timelock_ledgers,
+        MEDIUM
+    );
+}
+
+#[test]
+fn test_changes_validated_like_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _signer) = setup(&env);
+
+    assert_eq!(
+        try_propose(&client, &admin, &[ConfigChange::Threshold(3)]),
+        Err(VaultError::ThresholdTooHigh)
+    );
+    assert_eq!(
+        try_propose(&client, &admin, &[ConfigChange::QuorumPercentage(101)]),
+        Err(VaultError::QuorumTooHigh)
+    );
+    assert_eq!(
+        try_propose(&client, &admin, &[ConfigChange::GracePeriod(0)]),
+        Err(VaultError::InvalidAmount)
+    );
+    assert_eq!(
+        try_propose(&client, &admin, &[ConfigChange::AdminRotationDelay(10)]),
+        Err(VaultError::InvalidAmount)
+    );
+    assert_eq!(
+        try_propose(
+            &client,
+            &admin,
+            &[ConfigChange::DailyLimit(1), ConfigChange::DailyLimit(2)]
+        ),
+        Err(VaultError::DuplicateConfigChange)
+    );
+    assert_eq!(
+        try_propose(&client, &admin, &[]),
+        Err(VaultError::InvalidAmount)
+    );
+}
+
+#[test]
+fn test_stale_change_is_not_applied() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer) = setup(&env);
+
+    let first = try_propose(&client, &admin, &[ConfigChange::GracePeriod(200)]).unwrap();
+    let second = try_propose(
+        &client,
+        &admin,
+        &[
+            ConfigChange::RetryConfig(RetryConfig {
+                enabled: true,
+                max_retries: 3,
+                initial_backoff_ledgers: 10,
+                max_retry_delay: 100,
+            }),
+            ConfigChange::GracePeriod(300),
+        ],
+    )
+    .unwrap();
+    for id in [first, second] {
+        client.approve_param_change(&admin, &id);
+        client.approve_param_change(&signer, &id);
+    }
+
+    env.ledger().set_sequence_number((100 + LOW) as u32);
+    client.execute_param_change(&admin, &first);
+    assert_eq!(
+        client.try_execute_param_change(&admin, &second),
+        Err(Ok(VaultError::ConfigChangeStale))
+    );
+    // Nothing from the stale batch was applied.
+    let config = client.get_config();
+    assert_eq!(config.grace_period_ledgers, 200);
+    assert!(!config.retry_config.enabled);
+}
+
+#[test]
+fn test_expired_and_stale_proposals_stop_blocking_new_ones() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer) = setup(&env);
+
+    let applied = try_propose(&client, &admin, &[ConfigChange::GracePeriod(200)]).unwrap();
+    let stale = try_propose(&client, &admin, &[ConfigChange::GracePeriod(300)]).unwrap();
+    try_propose(&client, &admin, &[ConfigChange::SpendingLimit(5_000)]).unwrap();
+    assert_eq!(
+        try_propose(&client, &admin, &[ConfigChange::DailyLimit(1_000)]),
+        Err(VaultError::ConfigChangeInProgress)
+    );
+
+    // Executing one proposal makes the other change to the same field stale.
+    for id in [applied, stale] {
+        client.approve_param_change(&admin, &id);
+        client.approve_param_change(&signer, &id);
+    }
+    env.ledger().set_sequence_number((100 + LOW) as u32);
+    client.execute_param_change(&admin, &applied);
+    try_propose(&client, &admin, &[ConfigChange::DailyLimit(1_000)]).unwrap();
+    try_propose(&client, &admin, &[ConfigChange::WeeklyLimit(60_000_000)]).unwrap();
+    assert_eq!(
+        try_propose(&client, &admin, &[ConfigChange::VetoWindow(10)]),
+        Err(VaultError::ConfigChangeInProgress)
+    );
+
+    // Pending proposals that were never approved stop counting once they expire.
+    env.ledger()
+        .set_sequence_number((100 + LOW + HIGH + 1) as u32);
+    try_propose(&client, &admin, &[ConfigChange::VetoWindow(10)]).unwrap();
+}
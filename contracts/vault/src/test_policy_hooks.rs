@@ -0,0 +1,380 @@
+//! Tests for typed policy hooks: selectors, pre-hook decisions and the call budget.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, HookContext, HookDecision, PolicyHook, Priority, ProposalStatus, RetryConfig,
+    ScheduledTransferConfig, SignerTier, ThresholdStrategy, VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+#[contract]
+struct AllowHook;
+
+#[contractimpl]
+impl AllowHook {
+    pub fn pre_execute_policy(_env: Env, _ctx: HookContext) -> HookDecision {
+        HookDecision::Allow
+    }
+}
+
+#[contract]
+struct DenyHook;
+
+#[contractimpl]
+impl DenyHook {
+    pub fn pre_execute_policy(_env: Env, _ctx: HookContext) -> HookDecision {
+        HookDecision::Deny(7)
+    }
+}
+
+#[contract]
+struct ExtraApprovalHook;
+
+#[contractimpl]
+impl ExtraApprovalHook {
+    pub fn pre_execute_policy(env: Env, ctx: HookContext) -> HookDecision {
+        assert_eq!(ctx.memo, Symbol::new(&env, "big"));
+        HookDecision::RequireApprovals(1)
+    }
+}
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    signer: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    Setup {
+        client,
+        admin,
+        signer,
+        token,
+    }
+}
+
+fn policy_hook(env: &Env, contract: &Address, token: &Address, min_amount: i128) -> PolicyHook {
+    PolicyHook {
+        contract: contract.clone(),
+        is_pre: true,
+        tokens: soroban_sdk::vec![env, token.clone()],
+        tag_ids: Vec::new(env),
+        min_amount,
+        max_amount: 0,
+    }
+}
+
+fn approved_proposal(s: &Setup<'_>, amount: i128, memo: &str) -> u64 {
+    let env = &s.client.env;
+    let id = s.client.propose_transfer(
+        &s.admin,
+        &Address::generate(env),
+        &s.token,
+        &amount,
+        &Symbol::new(env, memo),
+        &Priority::Normal,
+        &Vec::new(env),
+        &crate::types::ConditionLogic::And,
+        &0i128,
+    );
+    s.client.approve_proposal(&s.admin, &id);
+    id
+}
+
+#[test]
+fn test_policy_pre_hooks_deny_or_require_more_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let deny = env.register(DenyHook, ());
+    let extra = env.register(ExtraApprovalHook, ());
+
+    assert_eq!(
+        s.client
+            .try_register_policy_hook(&s.signer, &policy_hook(&env, &deny, &s.token, 500)),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    s.client
+        .register_policy_hook(&s.admin, &policy_hook(&env, &deny, &s.token, 500));
+
+    // Below the selector's amount floor the hook is not consulted
+    let small = approved_proposal(&s, 100, "small");
+    s.client.execute_proposal(&s.admin, &small);
+    assert_eq!(
+        s.client.get_proposal(&small).status,
+        ProposalStatus::Executed
+    );
+
+    let big = approved_proposal(&s, 600, "big");
+    assert_eq!(
+        s.client.try_execute_proposal(&s.admin, &big),
+        Err(Ok(VaultError::PreHookDenied))
+    );
+    assert_eq!(s.client.get_proposal(&big).status, ProposalStatus::Approved);
+
+    s.client.remove_policy_hook(&s.admin, &deny, &true);
+    s.client
+        .register_policy_hook(&s.admin, &policy_hook(&env, &extra, &s.token, 500));
+
+    // The hook's requirement sends the proposal back for another approval
+    s.client.execute_proposal(&s.admin, &big);
+    assert_eq!(s.client.get_proposal(&big).status, ProposalStatus::Pending);
+    assert_eq!(s.client.get_proposal_safeguards(&big).extra_approvals, 1);
+
+    s.client.approve_proposal(&s.signer, &big);
+    s.client.execute_proposal(&s.admin, &big);
+    assert_eq!(s.client.get_proposal(&big).status, ProposalStatus::Executed);
+    // The same demand is not stacked twice
+    assert_eq!(s.client.get_proposal_safeguards(&big).extra_approvals, 1);
+}
+
+#[test]
+fn test_policy_hook_budget_and_failing_legacy_pre_hook() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let first = env.register(AllowHook, ());
+    let second = env.register(AllowHook, ());
+
+    assert_eq!(s.client.get_hook_call_budget(), 5);
+    s.client.set_hook_call_budget(&s.admin, &1);
+    s.client
+        .register_policy_hook(&s.admin, &policy_hook(&env, &first, &s.token, 0));
+    s.client
+        .register_policy_hook(&s.admin, &policy_hook(&env, &second, &s.token, 0));
+    assert_eq!(
+        s.client
+            .try_register_policy_hook(&s.admin, &policy_hook(&env, &first, &s.token, 0)),
+        Err(Ok(VaultError::HookAlreadyRegistered))
+    );
+
+    let id = approved_proposal(&s, 100, "budget");
+    assert_eq!(
+        s.client.try_execute_proposal(&s.admin, &id),
+        Err(Ok(VaultError::HookBudgetExceeded))
+    );
+
+    s.client.set_hook_call_budget(&s.admin, &2);
+    // A legacy pre-hook that cannot be called fails execution cleanly
+    s.client
+        .register_pre_hook(&s.admin, &Address::generate(&env));
+    assert_eq!(
+        s.client.try_execute_proposal(&s.admin, &id),
+        Err(Ok(VaultError::PreHookDenied))
+    );
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Approved);
+}
+
+fn scheduled_proposal(s: &Setup<'_>, amount: i128, execution_time: u64) -> u64 {
+    let env = &s.client.env;
+    let id = s.client.propose_scheduled_transfer(
+        &s.admin,
+        &Address::generate(env),
+        &s.token,
+        &amount,
+        &Symbol::new(env, "big"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0,
+        &ScheduledTransferConfig {
+            execution_time,
+            execution_window_ledgers: 0,
+        },
+    );
+    s.client.approve_proposal(&s.admin, &id);
+    id
+}
+
+#[test]
+fn test_policy_hooks_gate_scheduled_and_keeper_runs() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+    let s = setup(&env);
+    let deny = env.register(DenyHook, ());
+    let keeper = Address::generate(&env);
+    s.client
+        .register_policy_hook(&s.admin, &policy_hook(&env, &deny, &s.token, 500));
+
+    let id = scheduled_proposal(&s, 600, 150);
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Scheduled);
+    env.ledger().set_sequence_number(200);
+
+    assert_eq!(
+        s.client.try_execute_scheduled_proposal(&s.admin, &id),
+        Err(Ok(VaultError::PreHookDenied))
+    );
+    let run = s.client.execute_due(&keeper, &10);
+    assert_eq!(run.executed, 0);
+    assert_eq!(run.failed, 1);
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Scheduled);
+
+    // More matching hooks than the call budget allows also block the run
+    s.client.remove_policy_hook(&s.admin, &deny, &true);
+    s.client.set_hook_call_budget(&s.admin, &1);
+    let first = env.register(AllowHook, ());
+    let second = env.register(AllowHook, ());
+    s.client
+        .register_policy_hook(&s.admin, &policy_hook(&env, &first, &s.token, 0));
+    s.client
+        .register_policy_hook(&s.admin, &policy_hook(&env, &second, &s.token, 0));
+    assert_eq!(
+        s.client.try_execute_scheduled_proposal(&s.admin, &id),
+        Err(Ok(VaultError::HookBudgetExceeded))
+    );
+
+    s.client.set_hook_call_budget(&s.admin, &2);
+    assert_eq!(s.client.execute_due(&keeper, &10).executed, 1);
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Executed);
+}
+
+#[test]
+fn test_policy_hooks_block_unilateral_execution() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let deny = env.register(DenyHook, ());
+    s.client
+        .set_signer_tier(&s.admin, &s.admin, &SignerTier::Senior(10_000));
+    s.client
+        .register_policy_hook(&s.admin, &policy_hook(&env, &deny, &s.token, 500));
+
+    let propose = |amount: i128| {
+        s.client.propose_transfer(
+            &s.admin,
+            &Address::generate(&env),
+            &s.token,
+            &amount,
+            &Symbol::new(&env, "tier"),
+            &Priority::Normal,
+            &Vec::new(&env),
+            &ConditionLogic::And,
+            &0i128,
+        )
+    };
+
+    // No hook matches, so the tier still executes on creation
+    let small = propose(100);
+    assert_eq!(
+        s.client.get_proposal(&small).status,
+        ProposalStatus::Executed
+    );
+
+    // A matching hook routes the proposal through the vote, where it is denied
+    let big = propose(600);
+    assert_eq!(s.client.get_proposal(&big).status, ProposalStatus::Pending);
+    s.client.approve_proposal(&s.admin, &big);
+    assert_eq!(
+        s.client.try_execute_proposal(&s.admin, &big),
+        Err(Ok(VaultError::PreHookDenied))
+    );
+    assert_eq!(s.client.get_proposal(&big).status, ProposalStatus::Approved);
+}
+
+#[test]
+fn test_policy_hooks_gate_batch_execution() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let deny = env.register(DenyHook, ());
+    let extra = env.register(ExtraApprovalHook, ());
+    s.client
+        .register_policy_hook(&s.admin, &policy_hook(&env, &deny, &s.token, 500));
+
+    let small = approved_proposal(&s, 100, "small");
+    let big = approved_proposal(&s, 600, "big");
+
+    // The atomic batch aborts as a whole
+    let batch_id = s
+        .client
+        .create_batch(&s.admin, &soroban_sdk::vec![&env, small, big]);
+    assert_eq!(
+        s.client.try_execute_batch(&s.admin, &batch_id),
+        Err(Ok(VaultError::PreHookDenied))
+    );
+    assert_eq!(
+        s.client.get_proposal(&small).status,
+        ProposalStatus::Approved
+    );
+
+    // The best-effort batch skips only the denied proposal
+    let (executed, failed) = s
+        .client
+        .batch_execute_proposals(&s.admin, &soroban_sdk::vec![&env, small, big]);
+    assert_eq!(executed, soroban_sdk::vec![&env, small]);
+    assert_eq!(failed, 1);
+    assert_eq!(s.client.get_proposal(&big).status, ProposalStatus::Approved);
+
+    // A demand for more approvals is recorded and the proposal waits for it
+    s.client.remove_policy_hook(&s.admin, &deny, &true);
+    s.client
+        .register_policy_hook(&s.admin, &policy_hook(&env, &extra, &s.token, 500));
+    let (executed, failed) = s
+        .client
+        .batch_execute_proposals(&s.admin, &soroban_sdk::vec![&env, big]);
+    assert!(executed.is_empty());
+    assert_eq!(failed, 1);
+    assert_eq!(s.client.get_proposal(&big).status, ProposalStatus::Pending);
+    assert_eq!(s.client.get_proposal_safeguards(&big).extra_approvals, 1);
+}
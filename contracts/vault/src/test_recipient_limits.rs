@@ -0,0 +1,268 @@
+//! Tests for per-recipient cumulative caps and the first-payment cool-off.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, Priority, ProposalStatus, RecipientLimitConfig, RetryConfig, ThresholdStrategy,
+    VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+const DAY_SECS: u64 = 86_400;
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address, Address) {
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, signer, token)
+}
+
+fn try_propose(
+    client: &VaultDAOClient<'_>,
+    admin: &Address,
+    recipient: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<u64, VaultError> {
+    client
+        .try_propose_transfer(
+            admin,
+            recipient,
+            token,
+            &amount,
+            &Symbol::new(&client.env, "pay"),
+            &Priority::Normal,
+            &Vec::new(&client.env),
+            &ConditionLogic::And,
+            &0i128,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+fn limits(default_cap: i128, extra_approvals: u32, delay: u64) -> RecipientLimitConfig {
+    RecipientLimitConfig {
+        default_cap,
+        window_days: 7,
+        first_payment_delay_ledgers: delay,
+        first_payment_extra_approvals: extra_approvals,
+    }
+}
+
+#[test]
+fn test_recipient_cap_accumulates_across_proposals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(DAY_SECS * 10);
+    let (client, admin, _signer, token) = setup(&env);
+    client.set_recipient_limit_config(&admin, &limits(1_000, 0, 0));
+
+    let recipient = Address::generate(&env);
+    try_propose(&client, &admin, &recipient, &token, 600).unwrap();
+    assert_eq!(
+        try_propose(&client, &admin, &recipient, &token, 500),
+        Err(VaultError::ExceedsRecipientCap)
+    );
+    try_propose(&client, &admin, &recipient, &token, 400).unwrap();
+
+    let status = client.get_recipient_limit_status(&recipient);
+    assert_eq!(status.cap, 1_000);
+    assert_eq!(status.received, 1_000);
+    assert_eq!(status.remaining, 0);
+
+    // Other recipients are unaffected.
+    let other = Address::generate(&env);
+    try_propose(&client, &admin, &other, &token, 1_000).unwrap();
+}
+
+#[test]
+fn test_recipient_cap_refunded_on_cancel_and_window_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(DAY_SECS * 10);
+    let (client, admin, _signer, token) = setup(&env);
+    client.set_recipient_limit_config(&admin, &limits(1_000, 0, 0));
+
+    let recipient = Address::generate(&env);
+    let id = try_propose(&client, &admin, &recipient, &token, 1_000).unwrap();
+    client.cancel_proposal(&admin, &id, &Symbol::new(&env, "oops"));
+    assert_eq!(client.get_recipient_limit_status(&recipient).received, 0);
+
+    try_propose(&client, &admin, &recipient, &token, 999).unwrap();
+    assert_eq!(
+        try_propose(&client, &admin, &recipient, &token, 2),
+        Err(VaultError::ExceedsRecipientCap)
+    );
+
+    // Seven days later the reservation has left the window.
+    env.ledger().set_timestamp(DAY_SECS * 17);
+    assert_eq!(client.get_recipient_limit_status(&recipient).received, 0);
+    try_propose(&client, &admin, &recipient, &token, 998).unwrap();
+}
+
+#[test]
+fn test_recipient_cap_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _signer, token) = setup(&env);
+    client.set_recipient_limit_config(&admin, &limits(1_000, 0, 0));
+
+    let vendor = Address::generate(&env);
+    client.set_recipient_cap(&admin, &vendor, &5_000);
+    try_propose(&client, &admin, &vendor, &token, 4_000).unwrap();
+    assert_eq!(client.get_recipient_limit_status(&vendor).remaining, 1_000);
+
+    // Clearing the override falls back to the default cap.
+    client.set_recipient_cap(&admin, &vendor, &0);
+    assert_eq!(
+        try_propose(&client, &admin, &vendor, &token, 1),
+        Err(VaultError::ExceedsRecipientCap)
+    );
+}
+
+#[test]
+fn test_recipient_cap_applies_to_streams() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _signer, token) = setup(&env);
+    StellarAssetClient::new(&env, &token).mint(&admin, &10_000);
+    client.set_recipient_limit_config(&admin, &limits(1_000, 0, 0));
+
+    let recipient = Address::generate(&env);
+    try_propose(&client, &admin, &recipient, &token, 500).unwrap();
+    let result = client.try_create_stream(&admin, &recipient, &token, &1, &600, &600);
+    assert_eq!(result, Err(Ok(VaultError::ExceedsRecipientCap)));
+
+    let stream_id = client.create_stream(&admin, &recipient, &token, &1, &500, &500);
+    assert_eq!(
+        client.get_recipient_limit_status(&recipient).received,
+        1_000
+    );
+
+    client.cancel_stream(&admin, &stream_id);
+    assert_eq!(client.get_recipient_limit_status(&recipient).received, 500);
+}
+
+#[test]
+fn test_first_payment_requires_extra_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+    let (client, admin, signer, token) = setup(&env);
+    client.set_recipient_limit_config(&admin, &limits(0, 1, 0));
+
+    let recipient = Address::generate(&env);
+    let id = try_propose(&client, &admin, &recipient, &token, 100).unwrap();
+    assert_eq!(client.get_proposal_safeguards(&id).extra_approvals, 1);
+
+    client.approve_proposal(&admin, &id);
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Pending);
+    client.approve_proposal(&signer, &id);
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Approved);
+
+    client.execute_proposal(&admin, &id);
+    assert!(
+        client
+            .get_recipient_limit_status(&recipient)
+            .first_paid_ledger
+            > 0
+    );
+
+    // The recipient is now known: the vault threshold alone applies.
+    let id2 = try_propose(&client, &admin, &recipient, &token, 200).unwrap();
+    assert_eq!(client.get_proposal_safeguards(&id2).extra_approvals, 0);
+    client.approve_proposal(&admin, &id2);
+    assert_eq!(client.get_proposal(&id2).status, ProposalStatus::Approved);
+}
+
+#[test]
+fn test_first_payment_extra_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+    let (client, admin, _signer, token) = setup(&env);
+    client.set_recipient_limit_config(&admin, &limits(0, 0, 500));
+
+    let recipient = Address::generate(&env);
+    let id = try_propose(&client, &admin, &recipient, &token, 100).unwrap();
+    client.approve_proposal(&admin, &id);
+    assert_eq!(client.get_proposal(&id).unlock_ledger, 600);
+
+    let result = client.try_execute_proposal(&admin, &id);
+    assert_eq!(result, Err(Ok(VaultError::TimelockNotExpired)));
+
+    env.ledger().set_sequence_number(600);
+    client.execute_proposal(&admin, &id);
+}
+
+#[test]
+fn test_invalid_recipient_limit_config_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer, _token) = setup(&env);
+
+    let mut config = limits(1_000, 0, 0);
+    config.window_days = 0;
+    assert_eq!(
+        client.try_set_recipient_limit_config(&admin, &config),
+        Err(Ok(VaultError::InvalidRecipientLimitConfig))
+    );
+    assert_eq!(
+        client.try_set_recipient_limit_config(&signer, &limits(1_000, 0, 0)),
+        Err(Ok(VaultError::InsufficientRole))
+    );
+}
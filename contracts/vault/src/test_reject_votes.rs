@@ -0,0 +1,217 @@
+//! Tests for explicit reject votes, the rejection threshold and auto-reject.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, Priority, ProposalStatus, RetryConfig, ThresholdStrategy, VelocityConfig,
+    VoteChoice,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+const DAILY_LIMIT: i128 = 1_000;
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address, Address, Address) {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer_b = Address::generate(env);
+    let signer_c = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer_b.clone());
+    signers.push_back(signer_c.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 2,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 1_000,
+            spending_limit: DAILY_LIMIT,
+            daily_limit: DAILY_LIMIT,
+            weekly_limit: 50_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, signer_b, signer_c, token)
+}
+
+fn try_propose(
+    client: &VaultDAOClient<'_>,
+    proposer: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<u64, VaultError> {
+    client
+        .try_propose_transfer(
+            proposer,
+            &Address::generate(&client.env),
+            token,
+            &amount,
+            &Symbol::new(&client.env, "pay"),
+            &Priority::Normal,
+            &Vec::new(&client.env),
+            &ConditionLogic::And,
+            &0i128,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_reject_vote_counts_towards_quorum_and_reputation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer_b, _signer_c, token) = setup(&env);
+
+    let id = try_propose(&client, &admin, &token, 500).unwrap();
+    client.reject_proposal(&signer_b, &id);
+
+    // Two signers remain, enough to reach the 2-of-3 threshold.
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Pending);
+    assert_eq!(client.get_quorum_status(&id).0, 1);
+    assert_eq!(client.get_proposal_rejections(&id).len(), 1);
+
+    assert_eq!(client.get_rejections_given(&signer_b), 1);
+    assert_eq!(client.get_reputation(&signer_b).participation_count, 1);
+
+    assert_eq!(
+        client.try_reject_proposal(&signer_b, &id),
+        Err(Ok(VaultError::AlreadyRejected))
+    );
+    assert_eq!(
+        client.try_approve_proposal(&signer_b, &id),
+        Err(Ok(VaultError::AlreadyRejected))
+    );
+}
+
+#[test]
+fn test_unreachable_threshold_rejects_and_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer_b, signer_c, token) = setup(&env);
+
+    let id = try_propose(&client, &admin, &token, 800).unwrap();
+    assert_eq!(
+        try_propose(&client, &admin, &token, 300),
+        Err(VaultError::ExceedsDailyLimit)
+    );
+
+    client.reject_proposal(&signer_b, &id);
+    client.reject_proposal(&signer_c, &id);
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Rejected);
+    assert_eq!(client.get_reputation(&admin).proposals_rejected, 1);
+
+    // The daily reservation was released immediately.
+    try_propose(&client, &admin, &token, 900).unwrap();
+}
+
+#[test]
+fn test_reject_after_voting_deadline_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer_b, _signer_c, token) = setup(&env);
+
+    let id = try_propose(&client, &admin, &token, 800).unwrap();
+    env.ledger().set_sequence_number(1_200);
+    client.reject_proposal(&signer_b, &id);
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Rejected);
+
+    // Still the same day, but the reservation is gone.
+    try_propose(&client, &admin, &token, 900).unwrap();
+}
+
+#[test]
+fn test_rejection_threshold_rejects_immediately() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer_b, _signer_c, token) = setup(&env);
+
+    assert_eq!(client.get_rejection_threshold(), 0);
+    assert_eq!(
+        client.try_set_rejection_threshold(&signer_b, &1),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_rejection_threshold(&admin, &4),
+        Err(Ok(VaultError::ThresholdTooHigh))
+    );
+    client.set_rejection_threshold(&admin, &1);
+
+    let id = try_propose(&client, &admin, &token, 500).unwrap();
+    client.reject_proposal(&signer_b, &id);
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Rejected);
+    assert_eq!(
+        client.try_reject_proposal(&admin, &id),
+        Err(Ok(VaultError::ProposalNotPending))
+    );
+}
+
+#[test]
+fn test_change_vote_to_reject() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer_b, signer_c, token) = setup(&env);
+
+    let id = try_propose(&client, &admin, &token, 500).unwrap();
+    client.approve_proposal(&admin, &id);
+    client.approve_proposal(&signer_b, &id);
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Approved);
+
+    // Withdrawing an approval drops the proposal back to Pending.
+    client.change_vote(&signer_b, &id, &VoteChoice::Reject);
+    let proposal = client.get_proposal(&id);
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+    assert_eq!(proposal.approvals.len(), 1);
+    assert_eq!(client.get_quorum_status(&id).0, 2);
+    assert_eq!(
+        client.try_change_vote(&signer_b, &id, &VoteChoice::Reject),
+        Err(Ok(VaultError::AlreadyRejected))
+    );
+
+    // A rejecter can still come round.
+    client.change_vote(&signer_b, &id, &VoteChoice::Abstain);
+    assert!(client.get_proposal_rejections(&id).is_empty());
+
+    client.reject_proposal(&signer_c, &id);
+    client.change_vote(&signer_b, &id, &VoteChoice::Reject);
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Rejected);
+}
@@ -0,0 +1,444 @@
+//! Replays the vault's events into a model and checks it against contract storage.
+#![cfg(test)]
+
+extern crate std;
+
+use crate::replay::{Subject, VaultModel};
+use crate::types::{
+    AdminRotationAction, ConditionLogic, EscrowStatus, Milestone, Permission, PermissionScope,
+    Priority, ProposalStatus, RetryConfig, Role, StreamStatus, SubscriptionStatus,
+    SubscriptionTier, ThresholdStrategy, VelocityConfig, VoteChoice,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address) {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(Address::generate(env));
+    signers.push_back(Address::generate(env));
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 2,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 1_000,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, token)
+}
+
+/// Fold the events of the last invocation into `model`.
+fn sync(env: &Env, client: &VaultDAOClient<'_>, model: &mut VaultModel) {
+    let events = env.events().all().filter_by_contract(&client.address);
+    model.apply_contract_events(env, events.events()).unwrap();
+}
+
+fn assert_consistent(client: &VaultDAOClient<'_>, model: &VaultModel) {
+    let mismatches = model.check(client);
+    assert!(mismatches.is_empty(), "model diverged: {mismatches:?}");
+}
+
+fn propose(env: &Env, client: &VaultDAOClient<'_>, proposer: &Address, token: &Address) -> u64 {
+    client.propose_transfer(
+        proposer,
+        &Address::generate(env),
+        token,
+        &100,
+        &Symbol::new(env, "pay"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0,
+    )
+}
+
+#[test]
+fn test_replayed_proposals_and_spending_match_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let mut model = VaultModel::new();
+    let (client, admin, token) = setup(&env);
+    sync(&env, &client, &mut model);
+    let signers = client.get_signers();
+    let (second, third) = (signers.get(1).unwrap(), signers.get(2).unwrap());
+    for signer in [&second, &third] {
+        client.set_role(&admin, signer, &Role::Treasurer);
+        sync(&env, &client, &mut model);
+    }
+
+    let executed = propose(&env, &client, &admin, &token);
+    sync(&env, &client, &mut model);
+    client.approve_proposal(&admin, &executed);
+    sync(&env, &client, &mut model);
+    client.approve_proposal(&second, &executed);
+    sync(&env, &client, &mut model);
+    assert_consistent(&client, &model);
+    client.execute_proposal(&admin, &executed);
+    sync(&env, &client, &mut model);
+
+    let switched = propose(&env, &client, &admin, &token);
+    sync(&env, &client, &mut model);
+    client.abstain_proposal(&third, &switched);
+    sync(&env, &client, &mut model);
+    client.change_vote(&third, &switched, &VoteChoice::Approve);
+    sync(&env, &client, &mut model);
+    assert_consistent(&client, &model);
+
+    let cancelled = propose(&env, &client, &second, &token);
+    sync(&env, &client, &mut model);
+    client.cancel_proposal(&second, &cancelled, &Symbol::new(&env, "mistake"));
+    sync(&env, &client, &mut model);
+
+    assert_consistent(&client, &model);
+    assert_eq!(model.proposals.len(), 3);
+    assert!(!model.daily_spent.is_empty());
+
+    // A model that missed an event is caught.
+    let mut stale = model.clone();
+    stale.proposals.get_mut(&executed).unwrap().status = ProposalStatus::Approved;
+    stale.proposals.remove(&cancelled);
+    let mismatches = stale.check(&client);
+    assert_eq!(mismatches.len(), 2);
+    assert!(mismatches
+        .iter()
+        .all(|m| matches!(m.subject, Subject::Proposal(_))));
+}
+
+#[test]
+fn test_replayed_streams_and_escrows_match_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let mut model = VaultModel::new();
+    let (client, admin, token) = setup(&env);
+    sync(&env, &client, &mut model);
+    StellarAssetClient::new(&env, &token).mint(&admin, &20_000);
+    let recipient = Address::generate(&env);
+
+    let stream_id = client.create_stream(&admin, &recipient, &token, &1, &10_000, &10_000);
+    sync(&env, &client, &mut model);
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    client.claim_stream(&recipient, &stream_id);
+    sync(&env, &client, &mut model);
+    client.pause_stream(&admin, &stream_id);
+    sync(&env, &client, &mut model);
+    assert_consistent(&client, &model);
+    client.resume_stream(&admin, &stream_id);
+    sync(&env, &client, &mut model);
+    client.cancel_stream(&admin, &stream_id);
+    sync(&env, &client, &mut model);
+    assert_consistent(&client, &model);
+
+    let mut milestones = Vec::new(&env);
+    milestones.push_back(Milestone {
+        id: 1,
+        percentage: 100,
+        release_ledger: 0,
+        is_completed: false,
+        completion_ledger: 0,
+    });
+    let escrow_id = client.create_escrow(
+        &admin,
+        &recipient,
+        &token,
+        &1_000,
+        &milestones,
+        &10_000,
+        &Address::generate(&env),
+    );
+    sync(&env, &client, &mut model);
+    client.complete_milestone(&admin, &escrow_id, &1);
+    sync(&env, &client, &mut model);
+    assert_consistent(&client, &model);
+    client.release_escrow(&recipient, &escrow_id);
+    sync(&env, &client, &mut model);
+
+    assert_consistent(&client, &model);
+    assert_eq!(model.streams.len(), 1);
+    assert_eq!(model.escrows.len(), 1);
+}
+
+#[test]
+fn test_replayed_roles_grants_and_custom_roles_match_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let mut model = VaultModel::new();
+    let (client, admin, _token) = setup(&env);
+    sync(&env, &client, &mut model);
+    let (operator, successor) = (Address::generate(&env), Address::generate(&env));
+
+    client.set_role(&admin, &operator, &Role::Treasurer);
+    sync(&env, &client, &mut model);
+    client.set_role(&admin, &operator, &Role::Member);
+    sync(&env, &client, &mut model);
+
+    // Admin handed over through the delayed rotation, then taken back.
+    client.propose_admin_rotation(&admin, &AdminRotationAction::GrantAdmin(successor.clone()));
+    sync(&env, &client, &mut model);
+    env.ledger().with_mut(|l| l.sequence_number += 1_440);
+    client.accept_admin_rotation(&successor);
+    sync(&env, &client, &mut model);
+    assert_consistent(&client, &model);
+    client.propose_admin_rotation(&admin, &AdminRotationAction::RevokeAdmin(successor.clone()));
+    sync(&env, &client, &mut model);
+    env.ledger().with_mut(|l| l.sequence_number += 1_440);
+    client.accept_admin_rotation(&admin);
+    sync(&env, &client, &mut model);
+
+    client.grant_permission(&admin, &operator, &Permission::ManageLists, &None);
+    sync(&env, &client, &mut model);
+    client.grant_permission(&admin, &operator, &Permission::ViewMetrics, &None);
+    sync(&env, &client, &mut model);
+    client.revoke_permission(&admin, &operator, &Permission::ManageLists);
+    sync(&env, &client, &mut model);
+
+    let (auditor, steward) = (Symbol::new(&env, "auditor"), Symbol::new(&env, "steward"));
+    for name in [&auditor, &steward] {
+        client.define_custom_role(
+            &admin,
+            name,
+            &Vec::from_array(&env, [Permission::ViewMetrics]),
+            &PermissionScope {
+                max_amount: 0,
+                tokens: Vec::new(&env),
+                tag_ids: Vec::new(&env),
+            },
+        );
+        sync(&env, &client, &mut model);
+        client.assign_custom_role(&admin, &operator, name);
+        sync(&env, &client, &mut model);
+    }
+    client.unassign_custom_role(&admin, &operator, &auditor);
+    sync(&env, &client, &mut model);
+
+    assert_consistent(&client, &model);
+    assert_eq!(model.roles.get(&successor), Some(&(Role::Member as u32)));
+    assert_eq!(
+        model.grants.get(&operator),
+        Some(&std::vec![Permission::ViewMetrics as u32])
+    );
+    assert_eq!(model.custom_roles.get(&operator), Some(&std::vec![steward]));
+
+    // Missed revocations and unassignments are caught.
+    let mut stale = model.clone();
+    stale.roles.insert(successor.clone(), Role::Admin as u32);
+    stale
+        .grants
+        .get_mut(&operator)
+        .unwrap()
+        .push(Permission::ManageLists as u32);
+    stale.custom_roles.get_mut(&operator).unwrap().push(auditor);
+    let subjects: std::vec::Vec<Subject> = stale
+        .check(&client)
+        .into_iter()
+        .map(|m| m.subject)
+        .collect();
+    assert_eq!(
+        subjects,
+        std::vec![
+            Subject::Role(successor),
+            Subject::Grants(operator.clone()),
+            Subject::CustomRoles(operator),
+        ]
+    );
+}
+
+#[test]
+fn test_replayed_amendments_and_cancellations_match_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let mut model = VaultModel::new();
+    let (client, admin, token) = setup(&env);
+    sync(&env, &client, &mut model);
+
+    let amended = propose(&env, &client, &admin, &token);
+    sync(&env, &client, &mut model);
+    client.approve_proposal(&admin, &amended);
+    sync(&env, &client, &mut model);
+    client.amend_proposal(
+        &admin,
+        &amended,
+        &Address::generate(&env),
+        &250,
+        &Symbol::new(&env, "invoice"),
+        &Symbol::new(&env, "typo"),
+    );
+    sync(&env, &client, &mut model);
+    assert_consistent(&client, &model);
+
+    let cancelled = propose(&env, &client, &admin, &token);
+    sync(&env, &client, &mut model);
+    client.cancel_proposal(&admin, &cancelled, &Symbol::new(&env, "dup"));
+    sync(&env, &client, &mut model);
+
+    assert_consistent(&client, &model);
+    let p = model.proposals.get(&amended).unwrap();
+    assert_eq!((p.amount, p.amendments, p.approvals.len()), (250, 1, 0));
+    assert_eq!(p.memo, Some(Symbol::new(&env, "invoice")));
+    assert!(model.proposals[&cancelled].cancellation.is_some());
+
+    // A model that kept the old memo or missed the cancellation diverges.
+    let mut stale = model.clone();
+    stale.proposals.get_mut(&amended).unwrap().memo = Some(Symbol::new(&env, "pay"));
+    stale.proposals.get_mut(&cancelled).unwrap().cancellation = None;
+    let fields: std::vec::Vec<&str> = stale.check(&client).iter().map(|m| m.field).collect();
+    assert_eq!(fields, std::vec!["memo", "cancellation"]);
+}
+
+#[test]
+fn test_replayed_escrow_disputes_and_stream_cancellation_match_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let mut model = VaultModel::new();
+    let (client, admin, token) = setup(&env);
+    sync(&env, &client, &mut model);
+    StellarAssetClient::new(&env, &token).mint(&admin, &20_000);
+    let recipient = Address::generate(&env);
+
+    let milestones = Vec::from_array(
+        &env,
+        [Milestone {
+            id: 1,
+            percentage: 100,
+            release_ledger: 0,
+            is_completed: false,
+            completion_ledger: 0,
+        }],
+    );
+    let mut escrows = std::vec::Vec::new();
+    for _ in 0..2 {
+        escrows.push(client.create_escrow(
+            &admin,
+            &recipient,
+            &token,
+            &1_000,
+            &milestones,
+            &10_000,
+            &admin,
+        ));
+        sync(&env, &client, &mut model);
+    }
+    for (escrow_id, to_recipient) in escrows.iter().zip([true, false]) {
+        client.dispute_escrow(&admin, escrow_id, &Symbol::new(&env, "late"));
+        sync(&env, &client, &mut model);
+        assert_consistent(&client, &model);
+        client.resolve_escrow_dispute(&admin, escrow_id, &to_recipient);
+        sync(&env, &client, &mut model);
+    }
+    assert_consistent(&client, &model);
+    assert_eq!(model.escrows[&escrows[0]].status, EscrowStatus::Released);
+    assert_eq!(model.escrows[&escrows[1]].status, EscrowStatus::Refunded);
+
+    // A stream cancelled while paused.
+    let stream_id = client.create_stream(&admin, &recipient, &token, &1, &1_000, &1_000);
+    sync(&env, &client, &mut model);
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    client.pause_stream(&admin, &stream_id);
+    sync(&env, &client, &mut model);
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    client.cancel_stream(&admin, &stream_id);
+    sync(&env, &client, &mut model);
+
+    assert_consistent(&client, &model);
+    assert_eq!(model.streams[&stream_id].status, StreamStatus::Cancelled);
+
+    // Missing the dispute resolution leaves the escrow disputed in the model.
+    let mut stale = model.clone();
+    stale.escrows.get_mut(&escrows[1]).unwrap().status = EscrowStatus::Disputed;
+    let mismatches = stale.check(&client);
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].subject, Subject::Escrow(escrows[1]));
+}
+
+#[test]
+fn test_replayed_subscriptions_match_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let mut model = VaultModel::new();
+    let (client, admin, token) = setup(&env);
+    sync(&env, &client, &mut model);
+    let subscriber = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&subscriber, &10_000);
+
+    let id = client.create_subscription(
+        &subscriber,
+        &Address::generate(&env),
+        &SubscriptionTier::Basic,
+        &token,
+        &100,
+        &100,
+        &false,
+        &10,
+    );
+    sync(&env, &client, &mut model);
+    env.ledger().with_mut(|l| l.sequence_number += 100);
+    client.renew_subscription(&subscriber, &id);
+    sync(&env, &client, &mut model);
+    client.upgrade_subscription(&subscriber, &id, &SubscriptionTier::Premium, &300);
+    sync(&env, &client, &mut model);
+    client.pause_subscription(&subscriber, &id);
+    sync(&env, &client, &mut model);
+    assert_consistent(&client, &model);
+    client.resume_subscription(&subscriber, &id);
+    sync(&env, &client, &mut model);
+
+    env.ledger().with_mut(|l| l.sequence_number += 200);
+    client.expire_overdue_subscriptions(&admin);
+    sync(&env, &client, &mut model);
+    assert_consistent(&client, &model);
+    client.reactivate_subscription(&subscriber, &id);
+    sync(&env, &client, &mut model);
+    client.cancel_subscription(&subscriber, &id);
+    sync(&env, &client, &mut model);
+
+    assert_consistent(&client, &model);
+    let s = &model.subscriptions[&id];
+    assert_eq!((s.total_payments, s.amount_per_period), (3, 300));
+    assert_eq!(s.status, SubscriptionStatus::Cancelled);
+}
@@ -0,0 +1,225 @@
+//! Tests for rolling-window (sliding 24h / 7d) spending limits.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, Priority, RetryConfig, SpendingWindowMode, ThresholdStrategy, VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+const DAY_SECS: u64 = 86_400;
+const HOUR_SECS: u64 = 3_600;
+const DAILY_LIMIT: i128 = 1_000_000;
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address) {
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: DAILY_LIMIT,
+            daily_limit: DAILY_LIMIT,
+            weekly_limit: DAILY_LIMIT * 10,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, token)
+}
+
+fn try_propose(
+    client: &VaultDAOClient<'_>,
+    admin: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<u64, crate::VaultError> {
+    let recipient = Address::generate(&client.env);
+    client
+        .try_propose_transfer(
+            admin,
+            &recipient,
+            token,
+            &amount,
+            &Symbol::new(&client.env, "pay"),
+            &Priority::Normal,
+            &Vec::new(&client.env),
+            &ConditionLogic::And,
+            &0i128,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+/// One minute before midnight of day 10.
+fn just_before_midnight() -> u64 {
+    DAY_SECS * 11 - 60
+}
+
+#[test]
+fn test_calendar_mode_allows_spending_across_midnight() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(just_before_midnight());
+    let (client, admin, token) = setup(&env);
+
+    assert_eq!(
+        client.get_spending_window_mode(),
+        SpendingWindowMode::Calendar
+    );
+    try_propose(&client, &admin, &token, DAILY_LIMIT).unwrap();
+
+    env.ledger().set_timestamp(DAY_SECS * 11 + 60);
+    // Fresh calendar bucket: the full limit is available again two minutes later.
+    try_propose(&client, &admin, &token, DAILY_LIMIT).unwrap();
+}
+
+#[test]
+fn test_rolling_mode_blocks_spending_across_midnight() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(just_before_midnight());
+    let (client, admin, token) = setup(&env);
+
+    client.set_spending_window_mode(&admin, &SpendingWindowMode::Rolling);
+    try_propose(&client, &admin, &token, DAILY_LIMIT).unwrap();
+
+    env.ledger().set_timestamp(DAY_SECS * 11 + 60);
+    assert_eq!(
+        try_propose(&client, &admin, &token, 1),
+        Err(crate::VaultError::ExceedsDailyLimit)
+    );
+
+    let headroom = client.get_spending_headroom();
+    assert_eq!(headroom.mode, SpendingWindowMode::Rolling);
+    assert_eq!(headroom.daily_spent, DAILY_LIMIT);
+    assert_eq!(headroom.daily_remaining, 0);
+    assert_eq!(headroom.weekly_spent, DAILY_LIMIT);
+}
+
+#[test]
+fn test_rolling_window_frees_capacity_after_24_hours() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(DAY_SECS * 10 + HOUR_SECS * 5);
+    let (client, admin, token) = setup(&env);
+
+    client.set_spending_window_mode(&admin, &SpendingWindowMode::Rolling);
+    try_propose(&client, &admin, &token, DAILY_LIMIT / 2).unwrap();
+
+    env.ledger().set_timestamp(DAY_SECS * 10 + HOUR_SECS * 12);
+    try_propose(&client, &admin, &token, DAILY_LIMIT / 2).unwrap();
+    assert_eq!(client.get_spending_headroom().daily_remaining, 0);
+
+    // 24h after the first reservation only the second one is still in the window.
+    env.ledger().set_timestamp(DAY_SECS * 11 + HOUR_SECS * 5);
+    let headroom = client.get_spending_headroom();
+    assert_eq!(headroom.daily_spent, DAILY_LIMIT / 2);
+    assert_eq!(headroom.weekly_spent, DAILY_LIMIT);
+    try_propose(&client, &admin, &token, DAILY_LIMIT / 2).unwrap();
+}
+
+#[test]
+fn test_rolling_cancel_refunds_reserved_slot() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(just_before_midnight());
+    let (client, admin, token) = setup(&env);
+
+    client.set_spending_window_mode(&admin, &SpendingWindowMode::Rolling);
+    let id = try_propose(&client, &admin, &token, DAILY_LIMIT).unwrap();
+
+    env.ledger().set_timestamp(DAY_SECS * 11 + HOUR_SECS);
+    client.cancel_proposal(&admin, &id, &Symbol::new(&env, "oops"));
+
+    let headroom = client.get_spending_headroom();
+    assert_eq!(headroom.daily_spent, 0);
+    assert_eq!(headroom.daily_remaining, DAILY_LIMIT);
+    try_propose(&client, &admin, &token, DAILY_LIMIT).unwrap();
+}
+
+#[test]
+fn test_token_rolling_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(just_before_midnight());
+    let (client, admin, token) = setup(&env);
+
+    let token_daily = DAILY_LIMIT / 4;
+    client.add_supported_token(&admin, &token, &token_daily, &(token_daily * 7));
+    client.set_token_spending_window_mode(&admin, &token, &SpendingWindowMode::Rolling);
+    // Vault-wide limits stay on calendar buckets.
+    assert_eq!(
+        client.get_spending_window_mode(),
+        SpendingWindowMode::Calendar
+    );
+
+    try_propose(&client, &admin, &token, token_daily).unwrap();
+    env.ledger().set_timestamp(DAY_SECS * 11 + 60);
+
+    assert_eq!(
+        try_propose(&client, &admin, &token, 1),
+        Err(crate::VaultError::ExceedsTokenDailyLimit)
+    );
+    let headroom = client.get_token_spending_headroom(&token);
+    assert_eq!(headroom.mode, SpendingWindowMode::Rolling);
+    assert_eq!(headroom.daily_spent, token_daily);
+    assert_eq!(headroom.daily_remaining, 0);
+
+    // The aggregate calendar bucket rolled over at midnight.
+    let aggregate = client.get_spending_headroom();
+    assert_eq!(aggregate.daily_spent, 0);
+}
+
+#[test]
+fn test_set_spending_window_mode_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _token) = setup(&env);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_set_spending_window_mode(&outsider, &SpendingWindowMode::Rolling);
+    assert_eq!(result, Err(Ok(crate::VaultError::Unauthorized)));
+}
@@ -0,0 +1,351 @@
+//! Tests for the treasury runway forecast view.
+#![cfg(test)]
+
+use crate::mock_oracle::{MockOracle, MockOracleClient};
+use crate::storage;
+use crate::types::{
+    ActiveSet, ConditionLogic, Priority, RetryConfig, ThresholdStrategy, VaultOracleConfig,
+    VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+const VAULT_FUNDS: i128 = 10_000;
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address) {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &VAULT_FUNDS);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, token)
+}
+
+fn schedule_rent(client: &VaultDAOClient<'_>, admin: &Address, token: &Address, amount: i128) {
+    let env = &client.env;
+    client.schedule_payment(
+        admin,
+        &Address::generate(env),
+        token,
+        &amount,
+        &Symbol::new(env, "rent"),
+        &720,
+        &0,
+        &0,
+    );
+}
+
+#[test]
+fn test_recurring_and_approved_proposals_project_shortfall() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+
+    // Due at ledgers 820, 1540, 2260, ...
+    schedule_rent(&client, &admin, &token, 1_000);
+    let id = client.propose_transfer(
+        &admin,
+        &Address::generate(&env),
+        &token,
+        &4_000,
+        &Symbol::new(&env, "grant"),
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    client.approve_proposal(&admin, &id);
+
+    let tokens = Vec::from_array(&env, [token.clone()]);
+    let forecast = client.get_runway_forecast(&tokens, &1_440, &4, &false);
+    let runway = forecast.tokens.get(0).unwrap();
+    assert_eq!(runway.balance, VAULT_FUNDS);
+
+    let p0 = runway.periods.get(0).unwrap();
+    assert_eq!((p0.start_ledger, p0.end_ledger), (100, 1_540));
+    assert_eq!(p0.recurring, 1_000);
+    assert_eq!(p0.proposals, 4_000);
+    assert_eq!(p0.projected_balance, 5_000);
+
+    let p1 = runway.periods.get(1).unwrap();
+    assert_eq!(p1.recurring, 2_000);
+    assert_eq!(p1.proposals, 0);
+    assert_eq!(runway.periods.get(2).unwrap().projected_balance, 1_000);
+    assert_eq!(runway.periods.get(3).unwrap().projected_balance, -1_000);
+    assert_eq!(runway.first_negative_period, Some(3));
+    assert_eq!(forecast.first_negative_period, Some(3));
+}
+
+#[test]
+fn test_closed_records_leave_the_forecast() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+
+    schedule_rent(&client, &admin, &token, 1_000);
+    schedule_rent(&client, &admin, &token, 2_000);
+    let id = client.propose_transfer(
+        &admin,
+        &Address::generate(&env),
+        &token,
+        &4_000,
+        &Symbol::new(&env, "grant"),
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    client.approve_proposal(&admin, &id);
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            storage::get_active_ids(&env, ActiveSet::Recurring),
+            Vec::from_array(&env, [1u64, 2])
+        );
+        assert_eq!(
+            storage::get_active_ids(&env, ActiveSet::Proposal),
+            Vec::from_array(&env, [id])
+        );
+    });
+
+    client.stop_recurring_payment(&admin, &1);
+    client.execute_proposal(&admin, &id);
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            storage::get_active_ids(&env, ActiveSet::Recurring),
+            Vec::from_array(&env, [2u64])
+        );
+        assert!(storage::get_active_ids(&env, ActiveSet::Proposal).is_empty());
+    });
+
+    let tokens = Vec::from_array(&env, [token.clone()]);
+    let runway = client
+        .get_runway_forecast(&tokens, &1_440, &1, &false)
+        .tokens
+        .get(0)
+        .unwrap();
+    let p0 = runway.periods.get(0).unwrap();
+    assert_eq!(p0.recurring, 2_000);
+    assert_eq!(p0.proposals, 0);
+}
+
+#[test]
+fn test_stream_accrual_and_vesting_unlocks() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+    StellarAssetClient::new(&env, &token).mint(&admin, &10_000);
+
+    // 1 token per second for 10_000 seconds; 200 seconds already accrued.
+    let recipient = Address::generate(&env);
+    client.create_stream(&admin, &recipient, &token, &1, &10_000, &10_000);
+    env.ledger().with_mut(|li| li.timestamp += 200);
+
+    // Vests linearly over ledgers 100..1100.
+    client.create_vesting_schedule(
+        &admin,
+        &Address::generate(&env),
+        &token,
+        &8_000,
+        &100,
+        &100,
+        &1_100,
+    );
+
+    // 500-ledger periods = 2_500 seconds of stream accrual each.
+    let tokens = Vec::from_array(&env, [token.clone()]);
+    let runway = client
+        .get_runway_forecast(&tokens, &500, &3, &false)
+        .tokens
+        .get(0)
+        .unwrap();
+
+    let p0 = runway.periods.get(0).unwrap();
+    assert_eq!(p0.streams, 2_700);
+    assert_eq!(p0.vesting, 4_000);
+    let p1 = runway.periods.get(1).unwrap();
+    assert_eq!(p1.streams, 2_500);
+    assert_eq!(p1.vesting, 4_000);
+    let p2 = runway.periods.get(2).unwrap();
+    assert_eq!(p2.vesting, 0);
+    assert_eq!(p2.outflow, 2_500);
+    assert_eq!(p2.projected_balance, VAULT_FUNDS + 10_000 - 7_700 - 8_000);
+}
+
+#[test]
+fn test_usd_valuation_combines_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+    let reserve = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(&env, &reserve).mint(&client.address, &50_000);
+
+    let oracle = env.register(MockOracle, ());
+    // 1 USD per token (10^7 scaling)
+    MockOracleClient::new(&env, &oracle).set_price(&10_000_000, &100);
+    client.set_oracle_config(
+        &admin,
+        &VaultOracleConfig {
+            address: oracle,
+            base_symbol: Symbol::new(&env, "USD"),
+            max_staleness: 1_000,
+        },
+    );
+
+    schedule_rent(&client, &admin, &token, 5_000);
+    let tokens = Vec::from_array(&env, [token.clone(), reserve.clone()]);
+
+    // On its own the operating token runs dry in the second period...
+    let plain = client.get_runway_forecast(&tokens, &1_440, &2, &false);
+    assert_eq!(plain.tokens.get(0).unwrap().first_negative_period, Some(1));
+    assert_eq!(plain.first_negative_period, Some(1));
+    assert!(plain.usd_balances.is_empty());
+
+    // ...but the combined treasury stays solvent in USD terms.
+    let valued = client.get_runway_forecast(&tokens, &1_440, &2, &true);
+    assert_eq!(valued.usd_balances.get(0).unwrap(), 55_000);
+    assert_eq!(valued.usd_balances.get(1).unwrap(), 45_000);
+    assert_eq!(valued.first_negative_period, None);
+}
+
+#[test]
+fn test_runway_forecast_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, token) = setup(&env);
+    let tokens = Vec::from_array(&env, [token]);
+
+    assert_eq!(
+        client.try_get_runway_forecast(&tokens, &1_440, &0, &false),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_get_runway_forecast(&tokens, &0, &4, &false),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_get_runway_forecast(&tokens, &1_440, &4, &true),
+        Err(Ok(VaultError::OracleNotConfigured))
+    );
+}
+
+#[test]
+fn test_records_predating_the_index_are_seeded_in_ranges() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+
+    for amount in [1_000, 2_000, 3_000] {
+        schedule_rent(&client, &admin, &token, amount);
+    }
+    client.stop_recurring_payment(&admin, &2);
+
+    // Simulate a vault upgraded from a version without the index
+    env.as_contract(&client.address, || {
+        for id in [1u64, 3] {
+            env.storage()
+                .persistent()
+                .remove(&storage::FeatureKey::ActiveLink(ActiveSet::Recurring, id));
+        }
+        env.storage()
+            .persistent()
+            .remove(&storage::FeatureKey::ActiveEnds(ActiveSet::Recurring));
+        assert!(storage::get_active_ids(&env, ActiveSet::Recurring).is_empty());
+    });
+
+    assert_eq!(
+        client.try_seed_active_ids(&Address::generate(&env), &ActiveSet::Recurring, &1, &3),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_seed_active_ids(&admin, &ActiveSet::Recurring, &0, &201),
+        Err(Ok(VaultError::BatchTooLarge))
+    );
+
+    // Stopped payments are skipped and re-seeding a range adds nothing
+    assert_eq!(
+        client.seed_active_ids(&admin, &ActiveSet::Recurring, &1, &3),
+        1
+    );
+    assert_eq!(
+        client.seed_active_ids(&admin, &ActiveSet::Recurring, &1, &100),
+        1
+    );
+    assert_eq!(
+        client.seed_active_ids(&admin, &ActiveSet::Recurring, &1, &100),
+        0
+    );
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            storage::get_active_ids(&env, ActiveSet::Recurring),
+            Vec::from_array(&env, [1u64, 3])
+        );
+    });
+
+    // Unlinking from the middle and either end keeps the list intact
+    schedule_rent(&client, &admin, &token, 4_000);
+    client.stop_recurring_payment(&admin, &3);
+    client.stop_recurring_payment(&admin, &1);
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            storage::get_active_ids(&env, ActiveSet::Recurring),
+            Vec::from_array(&env, [4u64])
+        );
+    });
+    client.stop_recurring_payment(&admin, &4);
+    env.as_contract(&client.address, || {
+        assert!(storage::get_active_ids(&env, ActiveSet::Recurring).is_empty());
+    });
+}
@@ -0,0 +1,381 @@
+//! Tests for permission grants constrained by token, amount, recipient, tag and period.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, GrantConstraints, Permission, PermissionScope, Priority, ProposalStatus,
+    RetryConfig, ThresholdStrategy, VelocityConfig, VoteChoice, WhitelistEntry,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    signer_b: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer_b = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer_b.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 10_000,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    Setup {
+        client,
+        admin,
+        signer_b,
+        token,
+    }
+}
+
+fn constraints(env: &Env, max_amount: i128) -> GrantConstraints {
+    GrantConstraints {
+        scope: PermissionScope {
+            max_amount,
+            tokens: Vec::new(env),
+            tag_ids: Vec::new(env),
+        },
+        max_per_period: 0,
+        period_ledgers: 0,
+        recipients: Vec::new(env),
+        whitelist_labels: Vec::new(env),
+    }
+}
+
+fn try_propose(
+    s: &Setup<'_>,
+    proposer: &Address,
+    recipient: &Address,
+    amount: i128,
+) -> Result<u64, VaultError> {
+    let env = &s.client.env;
+    s.client
+        .try_propose_transfer(
+            proposer,
+            recipient,
+            &s.token,
+            &amount,
+            &Symbol::new(env, "pay"),
+            &Priority::Normal,
+            &Vec::new(env),
+            &ConditionLogic::And,
+            &0i128,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_grant_limits_token_amount_and_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let contractor = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let vendor = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    s.client.add_whitelist_entry(
+        &s.admin,
+        &vendor,
+        &WhitelistEntry {
+            label: Symbol::new(&env, "vendor"),
+            max_amount: 0,
+            expiry_ledger: 0,
+            approved_by: Vec::from_array(&env, [s.admin.clone()]),
+        },
+    );
+    let mut limits = constraints(&env, 1_000);
+    limits.scope.tokens = Vec::from_array(&env, [s.token.clone()]);
+    limits.recipients = Vec::from_array(&env, [alice.clone()]);
+    limits.whitelist_labels = Vec::from_array(&env, [Symbol::new(&env, "vendor")]);
+    s.client.grant_scoped_permission(
+        &s.admin,
+        &contractor,
+        &Permission::CreateProposal,
+        &None,
+        &limits,
+    );
+
+    try_propose(&s, &contractor, &alice, 1_000).unwrap();
+    try_propose(&s, &contractor, &vendor, 500).unwrap();
+    assert_eq!(
+        try_propose(&s, &contractor, &alice, 1_001),
+        Err(VaultError::GrantConstraintViolated)
+    );
+    assert_eq!(
+        try_propose(&s, &contractor, &stranger, 10),
+        Err(VaultError::GrantConstraintViolated)
+    );
+
+    // A constrained grant neither satisfies unscoped checks nor hides its limits.
+    assert!(!s
+        .client
+        .has_permission(&contractor, &Permission::CreateProposal));
+    assert_eq!(
+        s.client
+            .get_grant_constraints(&contractor, &Permission::CreateProposal),
+        limits
+    );
+}
+
+#[test]
+fn test_tag_scoped_grant_covers_tagged_proposals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let contractor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let ops = s
+        .client
+        .create_tag(&s.admin, &Symbol::new(&env, "ops"), &None);
+    let travel = s
+        .client
+        .create_tag(&s.admin, &Symbol::new(&env, "travel"), &Some(ops));
+    let marketing = s
+        .client
+        .create_tag(&s.admin, &Symbol::new(&env, "marketing"), &None);
+    let mut limits = constraints(&env, 0);
+    limits.scope.tag_ids = Vec::from_array(&env, [ops]);
+    s.client.grant_scoped_permission(
+        &s.admin,
+        &contractor,
+        &Permission::CreateProposal,
+        &None,
+        &limits,
+    );
+
+    let propose_tagged = |amount: i128, tag_ids: Vec<u64>| {
+        s.client
+            .try_propose_transfer_with_tags(
+                &contractor,
+                &recipient,
+                &s.token,
+                &amount,
+                &Symbol::new(&env, "pay"),
+                &Priority::Normal,
+                &Vec::new(&env),
+                &ConditionLogic::And,
+                &0i128,
+                &tag_ids,
+            )
+            .map(|r| r.unwrap())
+            .map_err(|e| e.unwrap())
+    };
+
+    // A child tag is covered through its ancestor.
+    propose_tagged(100, Vec::from_array(&env, [ops])).unwrap();
+    propose_tagged(200, Vec::from_array(&env, [travel])).unwrap();
+    assert_eq!(
+        propose_tagged(300, Vec::from_array(&env, [marketing])),
+        Err(VaultError::GrantConstraintViolated)
+    );
+    assert_eq!(
+        try_propose(&s, &contractor, &recipient, 400),
+        Err(VaultError::GrantConstraintViolated)
+    );
+}
+
+#[test]
+fn test_grant_period_cap_resets_each_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let contractor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let mut limits = constraints(&env, 0);
+    limits.max_per_period = 1_500;
+    limits.period_ledgers = 100;
+    s.client.grant_scoped_permission(
+        &s.admin,
+        &contractor,
+        &Permission::CreateProposal,
+        &None,
+        &limits,
+    );
+
+    try_propose(&s, &contractor, &recipient, 1_000).unwrap();
+    assert_eq!(
+        try_propose(&s, &contractor, &recipient, 600),
+        Err(VaultError::GrantConstraintViolated)
+    );
+    assert_eq!(
+        s.client
+            .get_grant_usage(&contractor, &Permission::CreateProposal)
+            .unwrap()
+            .used,
+        1_000
+    );
+
+    env.ledger().set_sequence_number(200);
+    try_propose(&s, &contractor, &recipient, 600).unwrap();
+    assert_eq!(
+        s.client
+            .get_grant_usage(&contractor, &Permission::CreateProposal)
+            .unwrap()
+            .used,
+        600
+    );
+
+    limits.period_ledgers = 0;
+    assert_eq!(
+        s.client.try_grant_scoped_permission(
+            &s.admin,
+            &contractor,
+            &Permission::CreateProposal,
+            &None,
+            &limits,
+        ),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_grants_constrain_approval_and_execution() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    s.client.grant_scoped_permission(
+        &s.admin,
+        &s.signer_b,
+        &Permission::ApproveProposal,
+        &None,
+        &constraints(&env, 500),
+    );
+    let large = try_propose(&s, &s.admin, &recipient, 600).unwrap();
+    let small = try_propose(&s, &s.admin, &recipient, 400).unwrap();
+
+    assert_eq!(
+        s.client.try_approve_proposal(&s.signer_b, &large),
+        Err(Ok(VaultError::GrantConstraintViolated))
+    );
+    assert_eq!(
+        s.client.try_check_permission_entry(
+            &s.signer_b,
+            &Permission::ApproveProposal,
+            &Some(large)
+        ),
+        Err(Ok(VaultError::GrantConstraintViolated))
+    );
+    s.client
+        .check_permission_entry(&s.signer_b, &Permission::ApproveProposal, &Some(small));
+    s.client.approve_proposal(&s.signer_b, &small);
+    s.client.approve_proposal(&s.admin, &large);
+
+    s.client.grant_scoped_permission(
+        &s.admin,
+        &s.signer_b,
+        &Permission::ExecuteProposal,
+        &None,
+        &constraints(&env, 300),
+    );
+    assert_eq!(
+        s.client.try_execute_proposal(&s.signer_b, &small),
+        Err(Ok(VaultError::GrantConstraintViolated))
+    );
+    s.client.execute_proposal(&s.admin, &small);
+    assert_eq!(
+        s.client.get_proposal(&small).status,
+        ProposalStatus::Executed
+    );
+}
+
+#[test]
+fn test_period_cap_is_charged_once_per_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let mut limits = constraints(&env, 0);
+    limits.max_per_period = 1_000;
+    limits.period_ledgers = 1_000;
+    s.client.grant_scoped_permission(
+        &s.admin,
+        &s.signer_b,
+        &Permission::ApproveProposal,
+        &None,
+        &limits,
+    );
+    let id = try_propose(&s, &s.admin, &recipient, 600).unwrap();
+    let usage = || {
+        s.client
+            .get_grant_usage(&s.signer_b, &Permission::ApproveProposal)
+            .unwrap()
+            .used
+    };
+
+    s.client.approve_proposal(&s.signer_b, &id);
+    assert_eq!(usage(), 600);
+
+    // Withdrawing and restoring the approval does not use the cap up again.
+    s.client.change_vote(&s.signer_b, &id, &VoteChoice::Reject);
+    s.client.change_vote(&s.signer_b, &id, &VoteChoice::Approve);
+    assert_eq!(usage(), 600);
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Approved);
+
+    // A different proposal is still held to what is left.
+    let other = try_propose(&s, &s.admin, &recipient, 500).unwrap();
+    assert_eq!(
+        s.client.try_approve_proposal(&s.signer_b, &other),
+        Err(Ok(VaultError::GrantConstraintViolated))
+    );
+}
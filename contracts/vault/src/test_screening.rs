@@ -0,0 +1,286 @@
+//! Tests for recipient screening through external denylist registries.
+#![cfg(test)]
+
+use crate::types::{ConditionLogic, Priority, RetryConfig, ThresholdStrategy, VelocityConfig};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+#[contracttype]
+enum RegistryKey {
+    Denied(Address),
+    Queries,
+}
+
+#[contract]
+struct Registry;
+
+#[contractimpl]
+impl Registry {
+    pub fn deny(env: Env, address: Address) {
+        env.storage()
+            .instance()
+            .set(&RegistryKey::Denied(address), &true);
+    }
+
+    pub fn is_denied(env: Env, address: Address) -> bool {
+        let queries: u32 = env
+            .storage()
+            .instance()
+            .get(&RegistryKey::Queries)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&RegistryKey::Queries, &(queries + 1));
+        env.storage().instance().has(&RegistryKey::Denied(address))
+    }
+
+    pub fn queries(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&RegistryKey::Queries)
+            .unwrap_or(0)
+    }
+}
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+    StellarAssetClient::new(env, &token).mint(&admin, &100_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    Setup {
+        client,
+        admin,
+        token,
+    }
+}
+
+fn propose(s: &Setup<'_>, recipient: &Address) -> Result<u64, VaultError> {
+    let env = &s.client.env;
+    match s.client.try_propose_transfer(
+        &s.admin,
+        recipient,
+        &s.token,
+        &100,
+        &Symbol::new(env, "pay"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    ) {
+        Ok(Ok(id)) => Ok(id),
+        Err(Ok(err)) => Err(err),
+        _ => panic!("unexpected invocation failure"),
+    }
+}
+
+#[test]
+fn test_screening_applies_at_creation_and_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let registry_id = env.register(Registry, ());
+    let registry = RegistryClient::new(&env, &registry_id);
+    let sanctioned = Address::generate(&env);
+    let payee = Address::generate(&env);
+    registry.deny(&sanctioned);
+
+    s.client.set_screening_config(
+        &s.admin,
+        &soroban_sdk::vec![&env, registry_id.clone()],
+        &true,
+    );
+
+    assert_eq!(propose(&s, &sanctioned), Err(VaultError::RecipientDenied));
+    assert_eq!(
+        s.client
+            .try_create_stream(&s.admin, &sanctioned, &s.token, &1, &100, &100),
+        Err(Ok(VaultError::RecipientDenied))
+    );
+
+    // Answers are reused within a ledger (the rejected calls above rolled back)
+    let id = propose(&s, &payee).unwrap();
+    s.client
+        .create_stream(&s.admin, &payee, &s.token, &1, &100, &100);
+    assert_eq!(registry.queries(), 1);
+    s.client.approve_proposal(&s.admin, &id);
+
+    // The payee is listed after creation and caught again at execution
+    registry.deny(&payee);
+    env.ledger().set_sequence_number(101);
+    assert_eq!(
+        s.client.try_execute_proposal(&s.admin, &id),
+        Err(Ok(VaultError::RecipientDenied))
+    );
+
+    // Clearing the registries disables screening
+    s.client
+        .set_screening_config(&s.admin, &Vec::new(&env), &true);
+    assert_eq!(s.client.get_screening_config(), None);
+    s.client.execute_proposal(&s.admin, &id);
+}
+
+#[test]
+fn test_batch_execution_skips_denied_recipients() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let registry_id = env.register(Registry, ());
+    let registry = RegistryClient::new(&env, &registry_id);
+    let payee = Address::generate(&env);
+    let listed = Address::generate(&env);
+    s.client
+        .set_screening_config(&s.admin, &soroban_sdk::vec![&env, registry_id], &true);
+
+    let paid = propose(&s, &payee).unwrap();
+    let blocked = propose(&s, &listed).unwrap();
+    s.client.approve_proposal(&s.admin, &paid);
+    s.client.approve_proposal(&s.admin, &blocked);
+
+    registry.deny(&listed);
+    env.ledger().set_sequence_number(101);
+    let (executed, failed) = s
+        .client
+        .batch_execute_proposals(&s.admin, &soroban_sdk::vec![&env, blocked, paid]);
+    assert_eq!(executed, soroban_sdk::vec![&env, paid]);
+    assert_eq!(failed, 1);
+    assert_eq!(
+        s.client.get_proposal(&blocked).status,
+        crate::types::ProposalStatus::Approved
+    );
+}
+
+#[test]
+fn test_unreachable_registry_fails_open_or_closed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let unreachable = Address::generate(&env);
+    let payee = Address::generate(&env);
+    let registries = soroban_sdk::vec![&env, unreachable.clone()];
+
+    assert_eq!(
+        s.client
+            .try_set_screening_config(&payee, &registries, &true),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    let mut too_many = Vec::new(&env);
+    for _ in 0..6 {
+        too_many.push_back(Address::generate(&env));
+    }
+    assert_eq!(
+        s.client
+            .try_set_screening_config(&s.admin, &too_many, &true),
+        Err(Ok(VaultError::TooManyScreeningRegistries))
+    );
+
+    s.client.set_screening_config(&s.admin, &registries, &true);
+    assert_eq!(propose(&s, &payee), Err(VaultError::ScreeningUnavailable));
+
+    s.client.set_screening_config(&s.admin, &registries, &false);
+    assert!(propose(&s, &payee).is_ok());
+    assert!(!s.client.get_screening_config().unwrap().fail_closed);
+}
+
+#[test]
+fn test_cache_is_per_ledger_and_fail_open_keeps_reachable_registries() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let registry_id = env.register(Registry, ());
+    let registry = RegistryClient::new(&env, &registry_id);
+    let unreachable = Address::generate(&env);
+    let sanctioned = Address::generate(&env);
+    let payee = Address::generate(&env);
+    registry.deny(&sanctioned);
+    let registries = soroban_sdk::vec![&env, unreachable.clone(), registry_id.clone()];
+
+    // Failing open skips only the registry that cannot be reached
+    s.client.set_screening_config(&s.admin, &registries, &false);
+    assert_eq!(propose(&s, &sanctioned), Err(VaultError::RecipientDenied));
+
+    let id = propose(&s, &payee).unwrap();
+    s.client
+        .create_stream(&s.admin, &payee, &s.token, &1, &100, &100);
+    assert_eq!(registry.queries(), 1);
+
+    // A cached answer is only good for the ledger it was fetched in
+    env.ledger().set_sequence_number(101);
+    s.client
+        .create_stream(&s.admin, &payee, &s.token, &1, &100, &100);
+    assert_eq!(registry.queries(), 2);
+
+    // Failing closed blocks the payout while a registry stays unreachable
+    s.client.approve_proposal(&s.admin, &id);
+    s.client.set_screening_config(&s.admin, &registries, &true);
+    assert_eq!(
+        s.client.try_execute_proposal(&s.admin, &id),
+        Err(Ok(VaultError::ScreeningUnavailable))
+    );
+
+    s.client.set_screening_config(
+        &s.admin,
+        &soroban_sdk::vec![&env, registry_id.clone()],
+        &true,
+    );
+    s.client.execute_proposal(&s.admin, &id);
+}
@@ -0,0 +1,351 @@
+//! Tests for periodic signer elections by governance-token holders.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, ElectionStatus, Priority, ProposalStatus, RetryConfig, ThresholdStrategy,
+    TimeWeightedConfig, VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+const TIMELOCK: u32 = 120_960;
+const NOMINATION: u64 = 100;
+const VOTING: u64 = 200;
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    signer_b: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer_b = Address::generate(env);
+    let signer_c = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let signers = Vec::from_array(env, [admin.clone(), signer_b.clone(), signer_c]);
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 2,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+    client.set_time_weighted_config(
+        &admin,
+        &TimeWeightedConfig {
+            enabled: true,
+            min_lock_duration: 1,
+            max_lock_duration: 1_000_000,
+            apply_decay: false,
+            early_unlock_penalty_bps: 0,
+        },
+    );
+
+    Setup {
+        client,
+        admin,
+        signer_b,
+        token,
+    }
+}
+
+/// A token holder with `amount` locked for `duration` ledgers (1x power).
+fn holder(s: &Setup<'_>, amount: i128, duration: u64) -> Address {
+    let env = &s.client.env;
+    let holder = Address::generate(env);
+    StellarAssetClient::new(env, &s.token).mint(&holder, &amount);
+    s.client.lock_tokens(&holder, &s.token, &amount, &duration);
+    holder
+}
+
+fn candidates(s: &Setup<'_>, election_id: u64, count: u32) -> Vec<Address> {
+    let env = &s.client.env;
+    let mut result = Vec::new(env);
+    for _ in 0..count {
+        let candidate = holder(s, 1, 10_000);
+        s.client.register_candidate(&candidate, &election_id);
+        result.push_back(candidate);
+    }
+    result
+}
+
+fn ballot(env: &Env, choices: &[&Address]) -> Vec<Address> {
+    let mut result = Vec::new(env);
+    for choice in choices {
+        result.push_back((*choice).clone());
+    }
+    result
+}
+
+#[test]
+fn test_election_installs_top_candidates_after_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let id = s
+        .client
+        .start_signer_election(&s.admin, &2, &NOMINATION, &VOTING);
+    let c = candidates(&s, id, 3);
+    let (x, y, z) = (c.get(0).unwrap(), c.get(1).unwrap(), c.get(2).unwrap());
+    let h1 = holder(&s, 300, 10_000);
+    let h2 = holder(&s, 200, 10_000);
+
+    env.ledger()
+        .set_sequence_number(100 + NOMINATION as u32 + 1);
+    s.client
+        .vote_in_election(&h1, &id, &ballot(&env, &[&x, &y]));
+    s.client
+        .vote_in_election(&h2, &id, &ballot(&env, &[&z, &y]));
+    let election = s.client.get_signer_election(&id);
+    assert_eq!(election.tallies, Vec::from_array(&env, [300i128, 500, 200]));
+
+    let voting_closed = 100 + (NOMINATION + VOTING) as u32 + 1;
+    env.ledger().set_sequence_number(voting_closed);
+    let elected = s.client.finalize_election(&id);
+    assert_eq!(elected, ballot(&env, &[&y, &x]));
+    let election = s.client.get_signer_election(&id);
+    assert_eq!(election.status, ElectionStatus::Elected);
+    // 2-of-3 scales to 2-of-2.
+    assert_eq!(election.new_threshold, 2);
+
+    assert_eq!(
+        s.client.try_apply_election_result(&id),
+        Err(Ok(VaultError::TimelockNotExpired))
+    );
+    env.ledger()
+        .set_sequence_number(voting_closed + TIMELOCK - 10);
+    // A proposal in flight across the handover.
+    let proposal_id = s.client.propose_transfer(
+        &s.admin,
+        &Address::generate(&env),
+        &s.token,
+        &500,
+        &Symbol::new(&env, "pay"),
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    s.client.approve_proposal(&s.signer_b, &proposal_id);
+
+    env.ledger().set_sequence_number(voting_closed + TIMELOCK);
+    s.client.apply_election_result(&id);
+
+    let config = s.client.get_config();
+    assert_eq!(config.signers, ballot(&env, &[&y, &x]));
+    assert_eq!(config.threshold, 2);
+    assert_eq!(
+        s.client.get_signer_election(&id).status,
+        ElectionStatus::Applied
+    );
+
+    // The outgoing signer's vote was withdrawn; the new signers decide.
+    let proposal = s.client.get_proposal(&proposal_id);
+    assert!(proposal.approvals.is_empty());
+    assert!(proposal.snapshot_signers.contains(&x) && proposal.snapshot_signers.contains(&y));
+    s.client.approve_proposal(&x, &proposal_id);
+    s.client.approve_proposal(&y, &proposal_id);
+    assert_eq!(
+        s.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Approved
+    );
+
+    // A new election can start once the previous one is applied.
+    s.client
+        .start_signer_election(&s.admin, &2, &NOMINATION, &VOTING);
+}
+
+#[test]
+fn test_election_phases_and_ballot_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client
+            .try_start_signer_election(&s.signer_b, &2, &NOMINATION, &VOTING),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    let id = s
+        .client
+        .start_signer_election(&s.admin, &2, &NOMINATION, &VOTING);
+    assert_eq!(
+        s.client
+            .try_start_signer_election(&s.admin, &2, &NOMINATION, &VOTING),
+        Err(Ok(VaultError::ElectionInProgress))
+    );
+
+    let c = candidates(&s, id, 3);
+    let (x, y, z) = (c.get(0).unwrap(), c.get(1).unwrap(), c.get(2).unwrap());
+    assert_eq!(
+        s.client.try_register_candidate(&x, &id),
+        Err(Ok(VaultError::AlreadyCandidate))
+    );
+    // Candidates must lock voting tokens until voting closes.
+    assert_eq!(
+        s.client
+            .try_register_candidate(&Address::generate(&env), &id),
+        Err(Ok(VaultError::NoLockedVotingPower))
+    );
+    assert_eq!(
+        s.client
+            .try_register_candidate(&holder(&s, 1, NOMINATION), &id),
+        Err(Ok(VaultError::NoLockedVotingPower))
+    );
+    let voter = holder(&s, 100, 10_000);
+    let short_lock = holder(&s, 100, NOMINATION);
+
+    // Ballots only open once nomination has closed.
+    assert_eq!(
+        s.client
+            .try_vote_in_election(&voter, &id, &ballot(&env, &[&x])),
+        Err(Ok(VaultError::ElectionPhaseClosed))
+    );
+    env.ledger()
+        .set_sequence_number(100 + NOMINATION as u32 + 1);
+    assert_eq!(
+        s.client
+            .try_register_candidate(&Address::generate(&env), &id),
+        Err(Ok(VaultError::ElectionPhaseClosed))
+    );
+
+    assert_eq!(
+        s.client
+            .try_vote_in_election(&Address::generate(&env), &id, &ballot(&env, &[&x])),
+        Err(Ok(VaultError::NoLockedVotingPower))
+    );
+    assert_eq!(
+        s.client
+            .try_vote_in_election(&short_lock, &id, &ballot(&env, &[&x])),
+        Err(Ok(VaultError::NoLockedVotingPower))
+    );
+    assert_eq!(
+        s.client
+            .try_vote_in_election(&voter, &id, &ballot(&env, &[&x, &y, &z])),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_vote_in_election(&voter, &id, &ballot(&env, &[&x, &Address::generate(&env)])),
+        Err(Ok(VaultError::NotACandidate))
+    );
+    s.client.vote_in_election(&voter, &id, &ballot(&env, &[&x]));
+    assert_eq!(
+        s.client
+            .try_vote_in_election(&voter, &id, &ballot(&env, &[&y])),
+        Err(Ok(VaultError::AlreadyVotedInElection))
+    );
+    assert_eq!(
+        s.client.try_finalize_election(&id),
+        Err(Ok(VaultError::ElectionPhaseClosed))
+    );
+}
+
+#[test]
+fn test_failed_and_stale_elections() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    // Too few candidates to fill the seats.
+    let id = s
+        .client
+        .start_signer_election(&s.admin, &3, &NOMINATION, &VOTING);
+    candidates(&s, id, 2);
+    let voting_closed = 100 + (NOMINATION + VOTING) as u32 + 1;
+    env.ledger().set_sequence_number(voting_closed);
+    assert!(s.client.finalize_election(&id).is_empty());
+    assert_eq!(
+        s.client.get_signer_election(&id).status,
+        ElectionStatus::Failed
+    );
+
+    // The signer set changing after tallying invalidates the result.
+    let start = voting_closed as u64;
+    let id = s
+        .client
+        .start_signer_election(&s.admin, &2, &NOMINATION, &VOTING);
+    candidates(&s, id, 2);
+    env.ledger()
+        .set_sequence_number((start + NOMINATION + VOTING + 1) as u32);
+    s.client.finalize_election(&id);
+
+    let mut signers = s.client.get_config().signers;
+    signers.push_back(Address::generate(&env));
+    s.client.update_config_signers(&s.admin, &signers);
+    env.ledger()
+        .set_sequence_number((start + NOMINATION + VOTING + 1) as u32 + TIMELOCK);
+    s.client.accept_admin_rotation(&s.admin);
+    assert!(!s.client.apply_election_result(&id));
+    assert_eq!(
+        s.client.get_signer_election(&id).status,
+        ElectionStatus::Failed
+    );
+
+    // A stale result frees the slot; an unwanted election can be cancelled.
+    let id = s
+        .client
+        .start_signer_election(&s.admin, &2, &NOMINATION, &VOTING);
+    assert_eq!(
+        s.client.try_cancel_signer_election(&s.signer_b, &id),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    s.client.cancel_signer_election(&s.admin, &id);
+    assert_eq!(
+        s.client.get_signer_election(&id).status,
+        ElectionStatus::Failed
+    );
+    assert_eq!(
+        s.client.try_cancel_signer_election(&s.admin, &id),
+        Err(Ok(VaultError::ElectionPhaseClosed))
+    );
+    s.client
+        .start_signer_election(&s.admin, &2, &NOMINATION, &VOTING);
+}
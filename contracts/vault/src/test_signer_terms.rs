@@ -0,0 +1,263 @@
+//! Tests for signer term limits and inactivity-based removal.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, InactivityPolicy, Priority, ProposalStatus, RetryConfig, ThresholdStrategy,
+    VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+const INACTIVE_AFTER: u64 = 1_000;
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    signers: Vec<Address>,
+    token: Address,
+}
+
+fn setup(env: &Env, signer_count: u32, threshold: u32, quorum_percentage: u32) -> Setup<'_> {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let mut signers = Vec::new(env);
+    for _ in 0..signer_count {
+        signers.push_back(Address::generate(env));
+    }
+
+    client.initialize(
+        &signers.get(0).unwrap(),
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers: signers.clone(),
+            threshold,
+            quorum: 0,
+            quorum_percentage,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    Setup {
+        client,
+        signers,
+        token,
+    }
+}
+
+fn propose(s: &Setup<'_>) -> u64 {
+    let env = &s.client.env;
+    s.client.propose_transfer(
+        &s.signers.get(0).unwrap(),
+        &Address::generate(env),
+        &s.token,
+        &100,
+        &Symbol::new(env, "pay"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    )
+}
+
+fn set_policy(s: &Setup<'_>, removal_threshold: u32, exclude_from_quorum: bool) {
+    s.client.set_inactivity_policy(
+        &s.signers.get(0).unwrap(),
+        &InactivityPolicy {
+            inactive_after_ledgers: INACTIVE_AFTER,
+            removal_threshold,
+            exclude_from_quorum,
+            activated_at: 0,
+        },
+    );
+}
+
+#[test]
+fn test_lapsed_term_blocks_voting_until_reconfirmed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 3, 2, 0);
+    let admin = s.signers.get(0).unwrap();
+    let signer = s.signers.get(1).unwrap();
+
+    s.client.set_signer_term(&admin, &signer, &500);
+    assert_eq!(s.client.get_signer_term(&signer).unwrap().expires_at, 600);
+    assert_eq!(
+        s.client.try_confirm_signer_term(&admin, &admin),
+        Err(Ok(VaultError::NoSignerTerm))
+    );
+
+    env.ledger().set_sequence_number(601);
+    assert!(!s.client.is_signer_active(&signer));
+    let id = propose(&s);
+    assert_eq!(
+        s.client.try_approve_proposal(&signer, &id),
+        Err(Ok(VaultError::SignerTermExpired))
+    );
+
+    s.client.confirm_signer_term(&admin, &signer);
+    assert_eq!(s.client.get_signer_term(&signer).unwrap().expires_at, 1_101);
+    s.client.approve_proposal(&signer, &id);
+
+    // Without a stored policy, removal needs the full vault threshold.
+    assert_eq!(s.client.get_inactivity_policy().removal_threshold, 2);
+    s.client.set_signer_term(&admin, &signer, &500);
+    env.ledger().set_sequence_number(1_102);
+    let removal = s.client.propose_signer_removal(&admin, &signer);
+    assert_eq!(
+        s.client.get_signer_removal(&removal).status,
+        ProposalStatus::Pending
+    );
+
+    // Clearing the term lifts the limit entirely.
+    s.client.set_signer_term(&admin, &signer, &0);
+    assert!(s.client.get_signer_term(&signer).is_none());
+}
+
+#[test]
+fn test_inactive_signer_removed_by_reduced_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 4, 3, 0);
+    let (admin, b, c, d) = (
+        s.signers.get(0).unwrap(),
+        s.signers.get(1).unwrap(),
+        s.signers.get(2).unwrap(),
+        s.signers.get(3).unwrap(),
+    );
+    assert_eq!(
+        s.client.try_set_inactivity_policy(
+            &admin,
+            &InactivityPolicy {
+                inactive_after_ledgers: INACTIVE_AFTER,
+                removal_threshold: 4,
+                exclude_from_quorum: false,
+                activated_at: 0,
+            }
+        ),
+        Err(Ok(VaultError::InvalidThresholdConfig))
+    );
+    set_policy(&s, 2, false);
+
+    env.ledger().set_sequence_number(600);
+    let id = propose(&s);
+    for signer in [&admin, &b, &c] {
+        s.client.approve_proposal(signer, &id);
+    }
+
+    // Inactivity counts from the policy's activation, not from genesis.
+    env.ledger()
+        .set_sequence_number(100 + INACTIVE_AFTER as u32);
+    assert!(s.client.is_signer_active(&d));
+    env.ledger().set_sequence_number(1_500);
+    assert!(!s.client.is_signer_active(&d));
+    assert_eq!(
+        s.client.try_propose_signer_removal(&admin, &c),
+        Err(Ok(VaultError::SignerStillActive))
+    );
+
+    let removal = s.client.propose_signer_removal(&admin, &d);
+    assert_eq!(
+        s.client.try_approve_signer_removal(&d, &removal),
+        Err(Ok(VaultError::NotASigner))
+    );
+    s.client.approve_signer_removal(&b, &removal);
+    assert_eq!(
+        s.client.get_signer_removal(&removal).status,
+        ProposalStatus::Executed
+    );
+
+    let config = s.client.get_config();
+    assert_eq!(config.signers.len(), 3);
+    assert!(!config.signers.contains(&d));
+    assert_eq!(config.threshold, 3);
+}
+
+#[test]
+fn test_removal_cannot_leave_vault_unreachable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 3, 3, 0);
+    let (admin, b) = (s.signers.get(0).unwrap(), s.signers.get(1).unwrap());
+    set_policy(&s, 1, false);
+
+    env.ledger().set_sequence_number(600);
+    let id = propose(&s);
+    s.client.approve_proposal(&admin, &id);
+
+    // Only the admin is still active; a 2-of-2 set with one active signer
+    // could never pass anything.
+    env.ledger().set_sequence_number(1_500);
+    assert_eq!(
+        s.client.try_propose_signer_removal(&admin, &b),
+        Err(Ok(VaultError::CannotRemoveSigner))
+    );
+    assert_eq!(s.client.get_config().signers.len(), 3);
+}
+
+#[test]
+fn test_inactive_signers_excluded_from_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 4, 2, 100);
+    let (admin, b, c) = (
+        s.signers.get(0).unwrap(),
+        s.signers.get(1).unwrap(),
+        s.signers.get(2).unwrap(),
+    );
+    set_policy(&s, 1, true);
+
+    env.ledger().set_sequence_number(600);
+    let warmup = propose(&s);
+    for signer in [&admin, &b, &c] {
+        s.client.abstain_proposal(signer, &warmup);
+    }
+
+    let id = propose(&s);
+    assert_eq!(s.client.get_quorum_status(&id).1, 4);
+
+    // The fourth signer has never voted and drops out of the denominator.
+    env.ledger().set_sequence_number(1_500);
+    assert_eq!(s.client.get_quorum_status(&id).1, 3);
+    s.client.approve_proposal(&admin, &id);
+    s.client.approve_proposal(&b, &id);
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Pending);
+    s.client.abstain_proposal(&c, &id);
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Approved);
+}
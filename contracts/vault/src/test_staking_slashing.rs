@@ -328,9 +328,11 @@ fn test_slash_event_carries_a_reason() {
         if !is_slash {
             return false;
         }
-        match <(Address, i128, i128, Symbol)>::try_from_val(&env, &data) {
-            Ok((_, slashed, returned, reason)) => {
-                slashed == 50 && returned == 50 && reason == Symbol::new(&env, "cancelled")
+        match crate::events::StakeSlashedEvent::try_from_val(&env, &data) {
+            Ok(event) => {
+                event.slashed == 50
+                    && event.returned == 50
+                    && event.reason == Symbol::new(&env, "cancelled")
             }
             Err(_) => false,
         }
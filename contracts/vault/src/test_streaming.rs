@@ -64,4 +64,12 @@ fn test_update_stream_rate_config() {
     let config = client.get_config();
     assert_eq!(config.stream_max_window_amount, 50_000i128);
     assert_eq!(config.burst_factor, 200u32);
+
+    // Burst factors outside 1x..=3x are rejected like in set_stream_burst_factor.
+    for factor in [99u32, 301u32] {
+        assert_eq!(
+            client.try_update_stream_rate_config(&admin, &50_000i128, &factor),
+            Err(Ok(VaultError::InvalidAmount))
+        );
+    }
 }
@@ -136,6 +136,11 @@ fn test_chain_traversal_multi_hop() {
         &0i128,
     );
 
+    // Each supersession audits the cancellation of the replaced proposal.
+    let last = client.get_audit_entry(&client.get_audit_entry_count());
+    assert_eq!(last.action, crate::types::AuditAction::CancelProposal);
+    assert_eq!(last.target, crate::types::AuditTarget::Proposal(b));
+
     // Direct child links.
     assert_eq!(client.get_superseded_by(&a), Some(b));
     assert_eq!(client.get_superseded_by(&b), Some(c));
@@ -0,0 +1,260 @@
+//! Tests for per-tag budget envelopes on the hierarchical tag taxonomy.
+#![cfg(test)]
+
+use crate::storage;
+use crate::types::{
+    BudgetOverrunPolicy, BudgetPeriod, ConditionLogic, Priority, ProposalStatus, RetryConfig,
+    TagBudget, ThresholdStrategy, VelocityConfig,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+/// 2024-02-15 00:00 UTC
+const FEB_15_2024: u64 = 19_768 * 86_400;
+/// 2024-03-01 00:00 UTC
+const MAR_01_2024: u64 = 19_783 * 86_400;
+
+fn setup(env: &Env) -> (VaultDAOClient<'_>, Address, Address, Address) {
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signer = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000_000);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    (client, admin, signer, token)
+}
+
+/// Create `eng` with child `backend`; returns (eng, backend).
+fn create_tags(env: &Env, client: &VaultDAOClient<'_>, admin: &Address) -> (u64, u64) {
+    let eng = client.create_tag(admin, &Symbol::new(env, "eng"), &None);
+    let backend = client.create_tag(admin, &Symbol::new(env, "backend"), &Some(eng));
+    (eng, backend)
+}
+
+fn try_propose_tagged(
+    client: &VaultDAOClient<'_>,
+    admin: &Address,
+    token: &Address,
+    amount: i128,
+    tag_id: u64,
+) -> Result<u64, VaultError> {
+    let env = &client.env;
+    let mut tags = Vec::new(env);
+    tags.push_back(tag_id);
+    client
+        .try_propose_transfer_with_tags(
+            admin,
+            &Address::generate(env),
+            token,
+            &amount,
+            &Symbol::new(env, "pay"),
+            &Priority::Normal,
+            &Vec::new(env),
+            &ConditionLogic::And,
+            &0i128,
+            &tags,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+fn budget(limit: i128, overrun_policy: BudgetOverrunPolicy) -> TagBudget {
+    TagBudget {
+        limit,
+        period: BudgetPeriod::Monthly,
+        overrun_policy,
+    }
+}
+
+#[test]
+fn test_budget_period_bounds() {
+    assert_eq!(
+        storage::budget_period_bounds(19_768, BudgetPeriod::Monthly),
+        (19_754, 19_783)
+    );
+    // 2024-11-20 falls in Q4 2024: 2024-10-01 .. 2025-01-01
+    assert_eq!(
+        storage::budget_period_bounds(20_047, BudgetPeriod::Quarterly),
+        (19_997, 20_089)
+    );
+}
+
+#[test]
+fn test_child_tag_rolls_up_into_parent_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(FEB_15_2024);
+    let (client, admin, _signer, token) = setup(&env);
+    let (eng, backend) = create_tags(&env, &client, &admin);
+    client.set_tag_budget(&admin, &eng, &budget(1_000, BudgetOverrunPolicy::Block));
+
+    let id = try_propose_tagged(&client, &admin, &token, 600, backend).unwrap();
+    let usage = client.get_tag_budget_usage(&eng);
+    assert_eq!(usage.used, 600);
+    assert_eq!(usage.remaining, 400);
+    assert_eq!(usage.period_start, 19_754 * 86_400);
+    assert_eq!(usage.period_end, MAR_01_2024);
+
+    assert_eq!(
+        try_propose_tagged(&client, &admin, &token, 500, backend),
+        Err(VaultError::TagBudgetExceeded)
+    );
+
+    client.cancel_proposal(&admin, &id, &Symbol::new(&env, "oops"));
+    assert_eq!(client.get_tag_budget_usage(&eng).used, 0);
+    try_propose_tagged(&client, &admin, &token, 1_000, eng).unwrap();
+}
+
+#[test]
+fn test_budget_resets_next_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(FEB_15_2024);
+    let (client, admin, _signer, token) = setup(&env);
+    let (eng, _backend) = create_tags(&env, &client, &admin);
+    client.set_tag_budget(&admin, &eng, &budget(1_000, BudgetOverrunPolicy::Block));
+
+    try_propose_tagged(&client, &admin, &token, 1_000, eng).unwrap();
+    env.ledger().set_timestamp(MAR_01_2024);
+    assert_eq!(client.get_tag_budget_usage(&eng).used, 0);
+    try_propose_tagged(&client, &admin, &token, 999, eng).unwrap();
+}
+
+#[test]
+fn test_overrun_requires_extra_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(FEB_15_2024);
+    let (client, admin, signer, token) = setup(&env);
+    let (eng, backend) = create_tags(&env, &client, &admin);
+    client.set_tag_budget(
+        &admin,
+        &eng,
+        &budget(1_000, BudgetOverrunPolicy::ExtraApprovals(1)),
+    );
+
+    let within = try_propose_tagged(&client, &admin, &token, 800, backend).unwrap();
+    assert_eq!(client.get_proposal_safeguards(&within).extra_approvals, 0);
+
+    let over = try_propose_tagged(&client, &admin, &token, 300, backend).unwrap();
+    assert_eq!(client.get_proposal_safeguards(&over).extra_approvals, 1);
+    assert_eq!(client.get_tag_budget_usage(&eng).used, 1_100);
+    assert_eq!(client.get_tag_budget_usage(&eng).remaining, 0);
+
+    client.approve_proposal(&admin, &over);
+    assert_eq!(client.get_proposal(&over).status, ProposalStatus::Pending);
+    client.approve_proposal(&signer, &over);
+    assert_eq!(client.get_proposal(&over).status, ProposalStatus::Approved);
+}
+
+#[test]
+fn test_assign_tags_reserves_pending_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(FEB_15_2024);
+    let (client, admin, _signer, token) = setup(&env);
+    let (eng, backend) = create_tags(&env, &client, &admin);
+    client.set_tag_budget(&admin, &eng, &budget(1_000, BudgetOverrunPolicy::Block));
+    client.set_tag_budget(&admin, &backend, &budget(500, BudgetOverrunPolicy::Block));
+
+    let id = client.propose_transfer(
+        &admin,
+        &Address::generate(&env),
+        &token,
+        &400,
+        &Symbol::new(&env, "pay"),
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    let mut tags = Vec::new(&env);
+    tags.push_back(backend);
+    client.assign_tags(&admin, &id, &tags);
+
+    // Reserved once in each envelope on the path.
+    assert_eq!(client.get_proposal_budget_reservations(&id).len(), 2);
+    assert_eq!(client.get_tag_budget_usage(&backend).used, 400);
+    assert_eq!(client.get_tag_budget_usage(&eng).used, 400);
+
+    // Re-assigning the parent tag does not double-reserve.
+    let mut parent = Vec::new(&env);
+    parent.push_back(eng);
+    client.assign_tags(&admin, &id, &parent);
+    assert_eq!(client.get_tag_budget_usage(&eng).used, 400);
+
+    let report = client.get_budget_usage_report();
+    assert_eq!(report.len(), 2);
+}
+
+#[test]
+fn test_set_tag_budget_requires_admin_and_tag() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, signer, _token) = setup(&env);
+    let (eng, _backend) = create_tags(&env, &client, &admin);
+
+    assert_eq!(
+        client.try_set_tag_budget(&signer, &eng, &budget(1, BudgetOverrunPolicy::Block)),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_tag_budget(&admin, &99, &budget(1, BudgetOverrunPolicy::Block)),
+        Err(Ok(VaultError::TagNotFound))
+    );
+    assert_eq!(
+        client.try_set_tag_budget(&admin, &eng, &budget(0, BudgetOverrunPolicy::Block)),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+}
@@ -0,0 +1,300 @@
+//! Tests for topic-scoped liquid delegation by tag or token.
+#![cfg(test)]
+
+use crate::types::{
+    ConditionLogic, DelegationScope, Priority, RetryConfig, ThresholdStrategy, VelocityConfig,
+    VoteChoice,
+};
+use crate::{InitConfig, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+struct Setup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    alice: Address,
+    bob: Address,
+    carol: Address,
+    dave: Address,
+    token: Address,
+    engineering: u64,
+    backend: u64,
+    grants: u64,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    let carol = Address::generate(env);
+    let dave = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &100_000);
+
+    let signers = Vec::from_array(
+        env,
+        [
+            admin.clone(),
+            alice.clone(),
+            bob.clone(),
+            carol.clone(),
+            dave.clone(),
+        ],
+    );
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            whitelist_mode: false,
+            grace_period_ledgers: 100,
+            vote_weight: crate::types::VoteWeight::Flat,
+            high_impact_threshold: 70,
+            admin_rotation_delay: 1440,
+            signers,
+            threshold: 4,
+            quorum: 0,
+            quorum_percentage: 0,
+            default_voting_deadline: 1_000,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 999_999_999,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+                per_token_limit: 0,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+            veto_addresses: Vec::new(env),
+            veto_window_ledgers: 0,
+            retry_config: RetryConfig {
+                max_retry_delay: 0,
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            proposal_id_prefix: 0,
+        },
+    );
+
+    let engineering = client.create_tag(&admin, &Symbol::new(env, "engineering"), &None);
+    let backend = client.create_tag(&admin, &Symbol::new(env, "backend"), &Some(engineering));
+    let grants = client.create_tag(&admin, &Symbol::new(env, "grants"), &None);
+
+    Setup {
+        client,
+        admin,
+        alice,
+        bob,
+        carol,
+        dave,
+        token,
+        engineering,
+        backend,
+        grants,
+    }
+}
+
+fn propose(s: &Setup<'_>, tags: &[u64]) -> u64 {
+    let env = &s.client.env;
+    let mut tag_ids = Vec::new(env);
+    for tag in tags {
+        tag_ids.push_back(*tag);
+    }
+    s.client.propose_transfer_with_tags(
+        &s.admin,
+        &Address::generate(env),
+        &s.token,
+        &500,
+        &Symbol::new(env, "pay"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+        &tag_ids,
+    )
+}
+
+#[test]
+fn test_votes_route_by_topic() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    s.client
+        .delegate_topic(&s.alice, &s.bob, &DelegationScope::Tag(s.engineering), &0);
+    s.client
+        .delegate_topic(&s.alice, &s.carol, &DelegationScope::Tag(s.grants), &0);
+    assert_eq!(s.client.get_topic_delegations(&s.alice).len(), 2);
+
+    // A descendant tag is covered by the delegation on its ancestor.
+    let backend = propose(&s, &[s.backend]);
+    s.client.approve_proposal(&s.bob, &backend);
+    let approvals = s.client.get_proposal(&backend).approvals;
+    assert!(approvals.contains(&s.alice) && approvals.contains(&s.bob));
+
+    let grant = propose(&s, &[s.grants]);
+    s.client.approve_proposal(&s.bob, &grant);
+    assert_eq!(s.client.get_proposal(&grant).approvals.len(), 1);
+    s.client.approve_proposal(&s.carol, &grant);
+    assert!(s.client.get_proposal(&grant).approvals.contains(&s.alice));
+
+    // Untagged proposals are not covered.
+    let other = propose(&s, &[]);
+    s.client.approve_proposal(&s.bob, &other);
+    assert_eq!(s.client.get_proposal(&other).approvals.len(), 1);
+}
+
+#[test]
+fn test_retagging_re_resolves_delegate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    s.client.delegate_topic(
+        &s.alice,
+        &s.carol,
+        &DelegationScope::Token(s.token.clone()),
+        &0,
+    );
+    s.client
+        .delegate_topic(&s.alice, &s.bob, &DelegationScope::Tag(s.engineering), &0);
+
+    let id = propose(&s, &[]);
+    assert_eq!(
+        s.client.get_vote_delegation_chain(&id, &s.alice),
+        Vec::from_array(&env, [s.carol.clone()])
+    );
+
+    // The more specific tag delegation wins once the proposal is tagged.
+    s.client
+        .assign_tags(&s.admin, &id, &Vec::from_array(&env, [s.backend]));
+    assert_eq!(
+        s.client.get_vote_delegation_chain(&id, &s.alice),
+        Vec::from_array(&env, [s.bob.clone()])
+    );
+    s.client.approve_proposal(&s.carol, &id);
+    assert!(!s.client.get_proposal(&id).approvals.contains(&s.alice));
+    s.client.approve_proposal(&s.bob, &id);
+    assert!(s.client.get_proposal(&id).approvals.contains(&s.alice));
+
+    s.client
+        .revoke_topic_delegation(&s.alice, &DelegationScope::Tag(s.engineering));
+    assert_eq!(
+        s.client.get_vote_delegation_chain(&id, &s.alice),
+        Vec::from_array(&env, [s.carol.clone()])
+    );
+}
+
+#[test]
+fn test_transitive_chain_is_bounded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let scope = DelegationScope::Tag(s.engineering);
+
+    s.client.delegate_topic(&s.alice, &s.bob, &scope, &0);
+    s.client.delegate_topic(&s.bob, &s.carol, &scope, &0);
+
+    let id = propose(&s, &[s.engineering]);
+    assert_eq!(
+        s.client.get_vote_delegation_chain(&id, &s.alice),
+        Vec::from_array(&env, [s.bob.clone(), s.carol.clone()])
+    );
+    s.client.approve_proposal(&s.carol, &id);
+    assert_eq!(s.client.get_proposal(&id).approvals.len(), 3);
+
+    assert_eq!(
+        s.client.try_delegate_topic(&s.dave, &s.alice, &scope, &0),
+        Err(Ok(VaultError::DelegationChainTooLong))
+    );
+    assert_eq!(
+        s.client.try_delegate_topic(&s.carol, &s.alice, &scope, &0),
+        Err(Ok(VaultError::CircularDelegation))
+    );
+}
+
+#[test]
+fn test_delegator_overrides_delegate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client
+        .delegate_topic(&s.alice, &s.bob, &DelegationScope::Tag(s.engineering), &0);
+
+    let id = propose(&s, &[s.engineering]);
+    s.client.approve_proposal(&s.bob, &id);
+    s.client.change_vote(&s.alice, &id, &VoteChoice::Reject);
+    assert_eq!(s.client.get_proposal_rejections(&id).len(), 1);
+
+    // The delegate changing their mind no longer moves Alice's vote.
+    s.client.change_vote(&s.bob, &id, &VoteChoice::Abstain);
+    let proposal = s.client.get_proposal(&id);
+    assert!(proposal.approvals.is_empty());
+    assert_eq!(proposal.abstentions.len(), 1);
+    assert!(s.client.get_proposal_rejections(&id).contains(&s.alice));
+
+    // Voting first keeps the delegate from voting on the delegator's behalf.
+    let second = propose(&s, &[s.engineering]);
+    s.client.abstain_proposal(&s.alice, &second);
+    s.client.approve_proposal(&s.bob, &second);
+    let proposal = s.client.get_proposal(&second);
+    assert_eq!(proposal.approvals, Vec::from_array(&env, [s.bob.clone()]));
+    assert_eq!(
+        proposal.abstentions,
+        Vec::from_array(&env, [s.alice.clone()])
+    );
+}
+
+#[test]
+fn test_topic_delegation_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client
+            .try_delegate_topic(&s.alice, &s.alice, &DelegationScope::Tag(s.grants), &0),
+        Err(Ok(VaultError::CircularDelegation))
+    );
+    assert_eq!(
+        s.client
+            .try_delegate_topic(&s.alice, &s.bob, &DelegationScope::Tag(99), &0),
+        Err(Ok(VaultError::TagNotFound))
+    );
+    assert_eq!(
+        s.client.try_delegate_topic(
+            &s.alice,
+            &Address::generate(&env),
+            &DelegationScope::Tag(s.grants),
+            &0
+        ),
+        Err(Ok(VaultError::NotASigner))
+    );
+    assert_eq!(
+        s.client
+            .try_revoke_topic_delegation(&s.alice, &DelegationScope::Tag(s.grants)),
+        Err(Ok(VaultError::ProposalNotFound))
+    );
+
+    // Expired delegations stop carrying votes.
+    s.client
+        .delegate_topic(&s.alice, &s.bob, &DelegationScope::Tag(s.grants), &150);
+    let id = propose(&s, &[s.grants]);
+    env.ledger().set_sequence_number(151);
+    s.client.approve_proposal(&s.bob, &id);
+    assert_eq!(s.client.get_proposal(&id).approvals.len(), 1);
+}
@@ -374,6 +374,30 @@ pub struct RoleAssignment {
     pub role: Role,
 }
 
+/// Change to admin authority that only takes effect after
+/// `Config.admin_rotation_delay`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdminRotationAction {
+    /// Make the address an Admin; accepted by that address
+    GrantAdmin(Address),
+    /// Demote an Admin to Member
+    RevokeAdmin(Address),
+    /// Replace the signer set
+    UpdateSigners(Vec<Address>),
+}
+
+/// Admin rotation waiting out its delay.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingAdminRotation {
+    pub action: AdminRotationAction,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    /// First ledger on which the rotation can be accepted
+    pub executable_at: u64,
+}
+
 /// Granular permissions for fine-grained access control
 #[contracttype]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -393,9 +417,169 @@ pub enum Permission {
     ManageSubscriptions = 11,
     ViewMetrics = 12,
     ManageRecovery = 13,
+    /// Resolve escrow and proposal disputes
+    ResolveDisputes = 14,
+    /// Cancel, retag or otherwise edit proposals created by others
+    ManageProposals = 15,
+    /// Act on streams, payments, escrows and subscriptions owned by others
+    ActOnBehalf = 16,
+}
+
+impl Permission {
+    /// Permissions that make their holder as powerful as an Admin. They are
+    /// only handed out through the delayed admin rotation, never by a grant,
+    /// delegation or custom role.
+    pub fn is_admin_equivalent(&self) -> bool {
+        matches!(
+            self,
+            Permission::ManageRoles | Permission::ManageConfig | Permission::ManageSigners
+        )
+    }
+}
+
+/// Limits on what a custom role's permissions apply to. Empty lists and a
+/// zero `max_amount` leave that dimension unrestricted.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PermissionScope {
+    /// Largest amount the role may act on (0 = any)
+    pub max_amount: i128,
+    /// Tokens the role may act on
+    pub tokens: Vec<Address>,
+    /// Hierarchical tag IDs; the target must carry one of them (or a child)
+    pub tag_ids: Vec<u64>,
+}
+
+impl PermissionScope {
+    pub fn is_unrestricted(&self) -> bool {
+        self.max_amount == 0 && self.tokens.is_empty() && self.tag_ids.is_empty()
+    }
+
+    /// Whether `target` lies within this scope.
+    pub fn allows(&self, target: &PermissionTarget) -> bool {
+        if self.max_amount > 0 && target.amount > self.max_amount {
+            return false;
+        }
+        if !self.tokens.is_empty() && !self.tokens.contains(&target.token) {
+            return false;
+        }
+        if !self.tag_ids.is_empty() && !self.tag_ids.iter().any(|t| target.tag_ids.contains(t)) {
+            return false;
+        }
+        true
+    }
+}
+
+/// What a permission is being exercised on, matched against a
+/// [`PermissionScope`]. `tag_ids` includes ancestors of the target's tags.
+#[derive(Clone, Debug)]
+pub struct PermissionTarget {
+    pub token: Address,
+    pub amount: i128,
+    pub tag_ids: Vec<u64>,
+    /// Proposal recipient, when the target pays someone
+    pub recipient: Option<Address>,
+    /// Whitelist label of `recipient`, if it has a whitelist entry
+    pub recipient_label: Option<Symbol>,
+    /// Proposal the permission is exercised on, once it exists
+    pub proposal_id: Option<u64>,
+}
+
+/// Where a resolved permission came from. A direct grant carries only the
+/// period cap needed to charge its usage.
+#[derive(Clone, Debug)]
+pub enum PermissionSource {
+    Role,
+    CustomRole,
+    Grant {
+        max_per_period: i128,
+        period_ledgers: u64,
+    },
+    Delegation,
+}
+
+/// Admin-defined role granting a set of permissions, optionally scoped.
+/// The built-in [`Role`]s act as fixed, unscoped presets.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct CustomRole {
+    pub name: Symbol,
+    pub permissions: Vec<Permission>,
+    pub scope: PermissionScope,
+    pub created_by: Address,
+    pub updated_at: u64,
+}
+
+/// Limits attached to a direct permission grant. Empty lists and zero caps
+/// leave that dimension unrestricted.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GrantConstraints {
+    /// Allowed tokens, per-proposal amount cap and allowed tags
+    pub scope: PermissionScope,
+    /// Total amount the grant may act on per period (0 = no cap)
+    pub max_per_period: i128,
+    /// Length of the `max_per_period` window in ledgers
+    pub period_ledgers: u64,
+    /// Recipients the grant may act on
+    pub recipients: Vec<Address>,
+    /// Whitelist labels whose recipients the grant may act on
+    pub whitelist_labels: Vec<Symbol>,
+}
+
+impl GrantConstraints {
+    pub fn unrestricted(env: &Env) -> Self {
+        GrantConstraints {
+            scope: PermissionScope {
+                max_amount: 0,
+                tokens: Vec::new(env),
+                tag_ids: Vec::new(env),
+            },
+            max_per_period: 0,
+            period_ledgers: 0,
+            recipients: Vec::new(env),
+            whitelist_labels: Vec::new(env),
+        }
+    }
+
+    pub fn is_unrestricted(&self) -> bool {
+        self.scope.is_unrestricted()
+            && self.max_per_period == 0
+            && self.recipients.is_empty()
+            && self.whitelist_labels.is_empty()
+    }
+
+    /// Whether `target` satisfies every constraint except the period cap,
+    /// which depends on recorded usage.
+    pub fn allows(&self, target: &PermissionTarget) -> bool {
+        if !self.scope.allows(target) {
+            return false;
+        }
+        if self.recipients.is_empty() && self.whitelist_labels.is_empty() {
+            return true;
+        }
+        let listed = target
+            .recipient
+            .as_ref()
+            .is_some_and(|r| self.recipients.contains(r));
+        let labelled = target
+            .recipient_label
+            .as_ref()
+            .is_some_and(|l| self.whitelist_labels.contains(l));
+        listed || labelled
+    }
+}
+
+/// Amount acted on through a period-capped grant in its current window.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GrantUsage {
+    pub period_start: u64,
+    pub used: i128,
 }
 
-/// Permission grant with optional expiry
+/// Permission grant with optional expiry. Constraints, if any, are stored
+/// separately and default to [`GrantConstraints::unrestricted`].
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct PermissionGrant {
@@ -428,6 +612,33 @@ pub struct Delegation {
     pub chain_depth: u32,
 }
 
+/// What a topic delegation covers.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DelegationScope {
+    /// Proposals tagged with this hierarchical tag or one of its descendants.
+    Tag(u64),
+    /// Proposals spending this token.
+    Token(Address),
+}
+
+/// Voting power delegated for a single topic.
+///
+/// Resolved against a proposal's current tags and token whenever a vote is
+/// cast, so retagging a proposal re-routes delegated votes automatically.
+/// A matching topic delegation takes precedence over the signer's general
+/// [`Delegation`].
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TopicDelegation {
+    pub delegator: Address,
+    pub delegate: Address,
+    pub scope: DelegationScope,
+    pub created_at: u64,
+    /// Ledger after which the delegation lapses (0 = no expiration)
+    pub expiry_ledger: u64,
+}
+
 /// Per-signer authority for unilateral treasury transfers.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -477,6 +688,7 @@ pub enum ProposalStatus {
 pub enum VoteChoice {
     Approve = 0,
     Abstain = 1,
+    Reject = 2,
 }
 
 /// Proposal priority level for queue ordering
@@ -535,8 +747,11 @@ pub enum ListMode {
 
 /// Proposal impact score — quantifies risk relative to treasury health
 /// Computed at proposal creation time and immutable thereafter.
+///
+/// The weights and complexity points below are the built-in defaults; an
+/// `ImpactScoreModel` can change them and enable the optional components.
 #[contracttype]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ImpactScore {
     /// Treasury impact in basis points: (amount / treasury_balance) * 10000
     /// 0-1000 = low impact, 1000-5000 = medium, 5000+ = high
@@ -553,6 +768,12 @@ pub struct ImpactScore {
     /// Total impact score: weighted average of the three components (0-100)
     /// Formula: (treasury_impact_bps / 100) * 0.4 + recipient_risk_score * 0.3 + complexity_score * 0.3
     pub total_score: u32,
+    /// USD value relative to the model's full scale, 0-100 (0 when unweighted)
+    pub usd_score: u32,
+    /// 100 for a never-paid recipient, falling to 0 at maturity (0 when unweighted)
+    pub recipient_age_score: u32,
+    /// Highest configured risk among the proposal's tags (0 when unweighted)
+    pub tag_risk_score: u32,
 }
 
 /// Transfer proposal
@@ -911,7 +1132,7 @@ pub struct Reputation {
     pub approvals_given: u32,
     /// Total abstentions recorded
     pub abstentions_given: u32,
-    /// Total governance votes cast (approvals + abstentions)
+    /// Total governance votes cast (approvals + abstentions + rejections)
     pub participation_count: u32,
     /// Ledger when the signer last cast a governance vote
     pub last_participation_ledger: u64,
@@ -1277,6 +1498,81 @@ pub enum AuditAction {
     UpdateThreshold = 9,
     AbstainProposal = 10,
     AmendProposal = 11,
+    RejectVote = 12,
+    CancelProposal = 13,
+    UpdateProposal = 14,
+    Delegate = 15,
+    UpdateConfig = 16,
+    RotateAdmin = 17,
+    UpdateSigners = 18,
+    UpdateList = 19,
+    GrantPermission = 20,
+    RevokePermission = 21,
+    CreateStream = 22,
+    UpdateStream = 23,
+    ClaimStream = 24,
+    CancelStream = 25,
+    SchedulePayment = 26,
+    UpdateRecurring = 27,
+    ExecuteRecurring = 28,
+    CreateEscrow = 29,
+    ReleaseEscrow = 30,
+    RaiseDispute = 31,
+    ResolveDispute = 32,
+    RegisterHook = 33,
+    RemoveHook = 34,
+    PauseVault = 35,
+    UnpauseVault = 36,
+    Recovery = 37,
+    WithdrawFunds = 38,
+    UpgradeContract = 39,
+    MergeVault = 40,
+    GuardianAction = 41,
+    Governance = 42,
+    Insurance = 43,
+    TokenLock = 44,
+    Subscription = 45,
+    FundingRound = 46,
+    ExternalTransfer = 47,
+    ManageTemplate = 48,
+    /// Housekeeping such as expiry sweeps, snapshots and checkpoints
+    Maintenance = 49,
+}
+
+/// The object an audited action was applied to.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuditTarget {
+    /// Vault-wide actions (configuration, pause, bulk operations)
+    None,
+    Proposal(u64),
+    Address(Address),
+    Token(Address),
+    Permission(Address, Permission),
+    Role(Symbol),
+    Stream(u64),
+    Escrow(u64),
+    RecurringPayment(u64),
+    Subscription(u64),
+    Vesting(u64),
+    FundingRound(u64),
+    InsuranceClaim(u64),
+    Dispute(u64),
+    Recovery(u64),
+    Merge(u64),
+    Bridge(BytesN<32>),
+    Capability(BytesN<32>),
+    Delegation(u64),
+    GovernanceProposal(u64),
+    Election(u64),
+    SignerRemoval(u64),
+    GuardianAction(u64),
+    Tag(u64),
+    Template(u64),
+    Comment(u64),
+    Batch(u64),
+    DeadLetter(u64),
+    Checkpoint(u64),
 }
 
 /// Audit trail entry with cryptographic verification
@@ -1289,14 +1585,14 @@ pub struct AuditEntry {
     pub action: AuditAction,
     /// Actor who performed the action
     pub actor: Address,
-    /// Target of the action (proposal ID, address, etc.)
-    pub target: u64,
+    /// Object the action was applied to
+    pub target: AuditTarget,
     /// Ledger timestamp
     pub timestamp: u64,
     /// Hash of previous entry (chain integrity)
-    pub prev_hash: u64,
-    /// Hash of this entry
-    pub hash: u64,
+    pub prev_hash: BytesN<32>,
+    /// SHA-256 over the entry's XDR with this field zeroed
+    pub hash: BytesN<32>,
 }
 // ============================================================================
 // Issue #1087: Audit Trail Compression with Selective Disclosure
@@ -1320,6 +1616,23 @@ pub struct AuditCheckpoint {
     pub merkle_root: BytesN<32>,
     /// Ledger at which this checkpoint was created
     pub created_at: u64,
+    /// Hash of entry `to_entry_id`, which the next entry chains from
+    pub head_hash: BytesN<32>,
+    /// Running hash over every checkpoint up to and including this one
+    pub accumulator: BytesN<32>,
+    /// Commits to a pre-SHA-256 (64-bit) chain rather than a batch of entries
+    pub legacy_bridge: bool,
+}
+
+/// One checkpoint's contribution to the accumulator, as carried in a
+/// consistency proof between two checkpoints.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditConsistencyStep {
+    pub checkpoint_id: u64,
+    pub to_entry_id: u64,
+    pub merkle_root: BytesN<32>,
+    pub head_hash: BytesN<32>,
 }
 
 /// Comment on a proposal
@@ -2354,6 +2667,61 @@ pub struct WhitelistEntry {
     pub approved_by: Vec<Address>,
 }
 
+// ============================================================================
+// Per-Recipient Cumulative Caps and First-Payment Cool-Off
+// ============================================================================
+
+/// Vault-wide per-recipient safeguards. Amounts are in raw token units and are
+/// summed across tokens, like `WhitelistEntry::max_amount`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecipientLimitConfig {
+    /// Cumulative amount a single recipient may receive within the window (0 = no cap).
+    /// Individual recipients can be given their own cap with `set_recipient_cap`.
+    pub default_cap: i128,
+    /// Length of the rolling window in days
+    pub window_days: u32,
+    /// Extra timelock in ledgers applied once a recipient's first payment is approved (0 = off)
+    pub first_payment_delay_ledgers: u64,
+    /// Extra approvals required on a recipient's first payment (0 = off)
+    pub first_payment_extra_approvals: u32,
+}
+
+impl Default for RecipientLimitConfig {
+    fn default() -> Self {
+        Self {
+            default_cap: 0,
+            window_days: 30,
+            first_payment_delay_ledgers: 0,
+            first_payment_extra_approvals: 0,
+        }
+    }
+}
+
+/// Requirements added to a single proposal on top of the vault threshold and timelock.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProposalSafeguards {
+    /// Approvals required in addition to the computed threshold
+    pub extra_approvals: u32,
+    /// Ledgers added to the timelock once the proposal is approved
+    pub extra_delay_ledgers: u64,
+}
+
+/// Cumulative-cap status of a single recipient.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecipientLimitStatus {
+    /// Effective cap for this recipient (0 = no cap)
+    pub cap: i128,
+    /// Amount received or reserved within the current window
+    pub received: i128,
+    /// Amount that can still be sent within the window (i128::MAX when uncapped)
+    pub remaining: i128,
+    /// Ledger of the first payment to this recipient (0 = never paid)
+    pub first_paid_ledger: u64,
+}
+
 // ============================================================================
 // Issue #1095: Voting Power Snapshot
 // ============================================================================
@@ -2474,6 +2842,12 @@ pub enum Capability {
     CreateProposal(i128),
     /// Allow executing a specific recurring payment
     ExecuteRecurring(u64),
+    /// Restrict streams and proposals to these recipients
+    Recipients(Vec<Address>),
+    /// Restrict streams and proposals to these tokens
+    Tokens(Vec<Address>),
+    /// Cap the total amount moved across all uses of the token
+    AmountBudget(i128),
 }
 
 /// Capability token granting scoped permissions to an external address
@@ -2492,6 +2866,8 @@ pub struct CapabilityToken {
     pub max_uses: u32,
     /// Number of times this token has been used
     pub uses_count: u32,
+    /// Cumulative amount consumed against any `AmountBudget`
+    pub amount_used: i128,
     /// Whether this token has been revoked
     pub revoked: bool,
 }
@@ -2514,6 +2890,207 @@ pub struct Tag {
     pub level: u32,
 }
 
+// ============================================================================
+// Tag Budget Envelopes
+// ============================================================================
+
+/// Calendar period a tag budget resets on (UTC).
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum BudgetPeriod {
+    Monthly = 0,
+    Quarterly = 1,
+}
+
+/// What happens when a proposal would take an envelope past its limit.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BudgetOverrunPolicy {
+    /// Reject the proposal (or tag assignment)
+    Block,
+    /// Allow it, but require this many approvals on top of the threshold
+    ExtraApprovals(u32),
+}
+
+/// Budget envelope attached to a hierarchical tag. Proposals tagged with the
+/// tag or any of its descendants draw from it.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TagBudget {
+    /// Maximum amount reserved per period (raw token units, summed across tokens)
+    pub limit: i128,
+    pub period: BudgetPeriod,
+    pub overrun_policy: BudgetOverrunPolicy,
+}
+
+/// A proposal's reservation against one envelope.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TagBudgetReservation {
+    pub tag_id: u64,
+    pub period: BudgetPeriod,
+    /// First day (days since epoch) of the period the reservation was made in
+    pub period_start_day: u64,
+    pub amount: i128,
+    /// Whether the reservation took the envelope past its limit
+    pub overrun: bool,
+}
+
+/// Current-period usage of a tag's envelope.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TagBudgetUsage {
+    pub tag_id: u64,
+    pub limit: i128,
+    pub period: BudgetPeriod,
+    /// Period start (unix seconds, inclusive)
+    pub period_start: u64,
+    /// Period end (unix seconds, exclusive)
+    pub period_end: u64,
+    /// Amount reserved by this tag and its descendants in the period
+    pub used: i128,
+    /// `limit - used`, floored at 0
+    pub remaining: i128,
+}
+
+// ============================================================================
+// Committed Liabilities Register
+// ============================================================================
+
+/// Category of an outstanding obligation held against the vault balance.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum LiabilityKind {
+    /// Unclaimed remainder of streams (`total_amount - claimed_amount`)
+    Stream = 0,
+    /// Unreleased escrow funds (`total_amount - released_amount`)
+    Escrow = 1,
+    /// Unclaimed vesting allocations
+    Vesting = 2,
+    /// Unreleased tranches of active funding rounds
+    FundingRound = 3,
+    /// Next installment of every recurring payment that has not been stopped
+    Recurring = 4,
+    /// Next renewal of every active or paused subscription the vault itself
+    /// pays for; subscriber-funded renewals are only forwarded
+    Subscription = 5,
+}
+
+/// How a proposal that would spend committed funds is handled.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SolvencyPolicy {
+    /// Reject the proposal with `InsufficientFreeBalance`
+    Block,
+    /// Allow it, but require this many approvals on top of the threshold
+    OverrideApprovals(u32),
+}
+
+/// Per-token view of the liabilities register.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiabilityReport {
+    pub token: Address,
+    /// Vault balance of the token
+    pub balance: i128,
+    pub streams: i128,
+    pub escrows: i128,
+    pub vesting: i128,
+    pub funding_rounds: i128,
+    pub recurring: i128,
+    pub subscriptions: i128,
+    /// Sum of all committed liabilities
+    pub total: i128,
+    /// `balance - total`; negative when the vault is over-committed
+    pub free_balance: i128,
+}
+
+// ============================================================================
+// Treasury Runway Forecast
+// ============================================================================
+
+/// Projected outflows of one token over one forecast period.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunwayPeriod {
+    pub index: u32,
+    /// First ledger of the period (inclusive)
+    pub start_ledger: u64,
+    /// Last ledger of the period (exclusive)
+    pub end_ledger: u64,
+    pub recurring: i128,
+    pub streams: i128,
+    pub subscriptions: i128,
+    pub vesting: i128,
+    /// Approved or scheduled proposals falling due in the period
+    pub proposals: i128,
+    /// Sum of all outflows above
+    pub outflow: i128,
+    /// Balance left at the end of the period
+    pub projected_balance: i128,
+}
+
+/// Runway projection for a single token.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenRunway {
+    pub token: Address,
+    /// Current vault balance
+    pub balance: i128,
+    pub periods: Vec<RunwayPeriod>,
+    /// First period whose projected balance is negative
+    pub first_negative_period: Option<u32>,
+}
+
+/// Multi-token runway projection returned by `get_runway_forecast`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunwayForecast {
+    pub start_ledger: u64,
+    pub period_ledgers: u64,
+    pub tokens: Vec<TokenRunway>,
+    /// Combined projected balance per period in USD (scaled by 10^7);
+    /// empty unless the forecast was valued through the oracle
+    pub usd_balances: Vec<i128>,
+    /// First period that goes negative: in USD terms when valued, otherwise
+    /// the earliest shortfall of any single token
+    pub first_negative_period: Option<u32>,
+}
+
+// ============================================================================
+// Optimistic Execution
+// ============================================================================
+
+/// Vault-wide settings for optimistic (objection-based) proposals.
+///
+/// An optimistic proposal is approved on creation and becomes executable once
+/// the veto window has passed, unless enough signers object first.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptimisticConfig {
+    pub enabled: bool,
+    /// Largest amount any proposer may propose optimistically
+    pub max_amount: i128,
+    /// Caps Junior and Senior proposers at this multiple of their tier limit
+    /// (0 = tiers do not cap; Principals are only capped by `max_amount`)
+    pub tier_cap_multiplier: u32,
+    /// Objections that escalate a proposal to the normal M-of-N vote
+    pub objection_threshold: u32,
+}
+
+/// Challenge state of an optimistic proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptimisticState {
+    /// Last ledger at which signers can object
+    pub challenge_ends: u64,
+    pub objections: Vec<Address>,
+    /// Set once objections moved the proposal to the normal vote
+    pub escalated: bool,
+}
+
 // ============================================================================
 // Issue #1085: Gas Cost Estimation Oracle
 // ============================================================================
@@ -2792,6 +3369,40 @@ pub struct TokenSpendingConfig {
     pub is_default: bool,
 }
 
+// ============================================================================
+// Rolling-Window Spending Limits
+// ============================================================================
+
+/// How daily and weekly spending limits are measured.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum SpendingWindowMode {
+    /// Fixed calendar buckets keyed by `timestamp / 86400` and `timestamp / 604800`.
+    Calendar = 0,
+    /// Sliding 24h / 7d windows summed over hourly sub-buckets, so a full limit
+    /// cannot be spent on both sides of a bucket boundary.
+    Rolling = 1,
+}
+
+/// Remaining daily/weekly spending capacity at the current ledger.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpendingHeadroom {
+    /// Window mode used to compute the figures below
+    pub mode: SpendingWindowMode,
+    pub daily_limit: i128,
+    /// Amount reserved in the current day (calendar) or last 24h (rolling)
+    pub daily_spent: i128,
+    /// `daily_limit - daily_spent`, floored at zero
+    pub daily_remaining: i128,
+    pub weekly_limit: i128,
+    /// Amount reserved in the current week (calendar) or last 7d (rolling)
+    pub weekly_spent: i128,
+    /// `weekly_limit - weekly_spent`, floored at zero
+    pub weekly_remaining: i128,
+}
+
 // ============================================================================
 // Emergency Pause / Circuit Breaker (#1084)
 // ============================================================================
@@ -2895,6 +3506,227 @@ pub struct GovernanceProposal {
     pub expires_at: u64,
 }
 
+/// A typed change to a single `Config` field.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigChange {
+    Threshold(u32),
+    Quorum(u32),
+    QuorumPercentage(u32),
+    SpendingLimit(i128),
+    DailyLimit(i128),
+    WeeklyLimit(i128),
+    TimelockThreshold(i128),
+    TimelockDelay(u64),
+    DefaultVotingDeadline(u64),
+    VetoWindow(u64),
+    ExecWindow(u64),
+    ApprovalTimeout(u64),
+    GracePeriod(u64),
+    VoteWeight(VoteWeight),
+    BurstFactor(u32),
+    HighImpactThreshold(u32),
+    RetryConfig(RetryConfig),
+    AdminRotationDelay(u64),
+}
+
+/// Old and new value of one field in a parameter-change proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigFieldDiff {
+    /// Field value when the change was proposed
+    pub old_value: ConfigChange,
+    pub new_value: ConfigChange,
+}
+
+/// Governance proposal that changes several `Config` fields atomically.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ParamChangeProposal {
+    pub id: u64,
+    pub proposer: soroban_sdk::Address,
+    pub changes: soroban_sdk::Vec<ConfigFieldDiff>,
+    pub approvals: soroban_sdk::Vec<soroban_sdk::Address>,
+    pub status: ProposalStatus,
+    pub created_at: u64,
+    pub expires_at: u64,
+    /// Mandatory delay after approval, set by the most sensitive field changed
+    pub timelock_ledgers: u64,
+    /// Ledger from which the approved change can be executed (0 until approved)
+    pub unlock_ledger: u64,
+}
+
+// ============================================================================
+// Signer Elections
+// ============================================================================
+
+/// Lifecycle of a signer election.
+///
+/// Nomination and voting phases of an `Open` election are derived from the
+/// current ledger rather than stored.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ElectionStatus {
+    /// Accepting candidates, then ballots
+    Open = 0,
+    /// Tallied; the new signer set is waiting out its timelock
+    Elected = 1,
+    /// The elected signer set replaced the previous one
+    Applied = 2,
+    /// Too few candidates to fill the seats, a stale result, or cancelled
+    Failed = 3,
+}
+
+/// Election of a new signer set by governance-token holders.
+///
+/// Holders vote with the power of their [`TokenLock`]; the `seats` candidates
+/// with the most power become the signer set once the timelock expires.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignerElection {
+    pub id: u64,
+    pub created_by: Address,
+    /// Number of signers to elect
+    pub seats: u32,
+    /// Last ledger on which candidates can register
+    pub nomination_end: u64,
+    /// Last ledger on which ballots can be cast
+    pub voting_end: u64,
+    pub candidates: Vec<Address>,
+    /// Locked voting power received by each candidate, parallel to `candidates`
+    pub tallies: Vec<i128>,
+    pub status: ElectionStatus,
+    /// Winning candidates, highest tally first (empty until tallied)
+    pub elected: Vec<Address>,
+    /// Threshold for the elected set, scaled from the current threshold
+    pub new_threshold: u32,
+    /// Signer set the result replaces; applying fails if it changed since tallying
+    pub incumbents: Vec<Address>,
+    /// Ledger from which the result can be applied (0 until tallied)
+    pub unlock_ledger: u64,
+}
+
+// ============================================================================
+// Signer Terms and Inactivity
+// ============================================================================
+
+/// Fixed-length term a signer must be re-confirmed for once it lapses.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignerTerm {
+    pub term_ledgers: u64,
+    /// Ledger of the last (re-)confirmation
+    pub confirmed_at: u64,
+    /// Last ledger on which the signer may vote without re-confirmation
+    pub expires_at: u64,
+}
+
+/// When signers count as inactive and what that means for the vault.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InactivityPolicy {
+    /// Ledgers without a vote after which a signer is inactive (0 = disabled)
+    pub inactive_after_ledgers: u64,
+    /// Approvals needed to remove an inactive signer (at most the vault threshold)
+    pub removal_threshold: u32,
+    /// Leave inactive and term-expired signers out of the quorum denominator
+    pub exclude_from_quorum: bool,
+    /// Ledger the policy took effect; inactivity is never counted from before it
+    pub activated_at: u64,
+}
+
+/// Proposal to remove an inactive or term-expired signer.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignerRemovalProposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub target: Address,
+    pub approvals: Vec<Address>,
+    pub status: ProposalStatus,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+// ============================================================================
+// Guardian Council
+// ============================================================================
+
+/// Guardian council with limited emergency powers, separate from the signers.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct GuardianCouncil {
+    pub guardians: Vec<Address>,
+    /// Guardian approvals needed for an action to take effect
+    pub threshold: u32,
+    /// Ledgers an action lasts unless ratified by the signers; also how long
+    /// a proposed action can gather guardian approvals
+    pub ratification_window: u64,
+}
+
+/// Part of the vault a guardian can pause on its own.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum VaultSubsystem {
+    /// Proposal execution
+    Proposals = 0,
+    /// Stream claims and triggered stream payments
+    Streams = 1,
+    /// Escrow releases and dispute resolution
+    Escrows = 2,
+    /// Recurring payment execution
+    RecurringPayments = 3,
+}
+
+/// Emergency action available to the guardian council. None of these move
+/// funds or change configuration.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GuardianAction {
+    PauseVault,
+    PauseSubsystem(VaultSubsystem),
+    /// Hold an approved-but-unexecuted proposal; cancelled once ratified
+    CancelProposal(u64),
+    FreezeStream(u64),
+    FreezeEscrow(u64),
+}
+
+/// Lifecycle of a guardian action.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum GuardianActionStatus {
+    /// Gathering guardian approvals
+    Pending = 0,
+    /// In effect, awaiting signer ratification
+    Active = 1,
+    /// Ratified by the signers; stays in effect until lifted
+    Ratified = 2,
+    /// Lapsed without ratification and was undone
+    Expired = 3,
+    /// Lifted by an admin after ratification
+    Lifted = 4,
+}
+
+/// A guardian action and its approval/ratification state.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct GuardianActionRecord {
+    pub id: u64,
+    pub action: GuardianAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub ratifications: Vec<Address>,
+    pub status: GuardianActionStatus,
+    pub created_at: u64,
+    /// Ledger the action took effect (0 while Pending)
+    pub activated_at: u64,
+    /// Last ledger on which the signers can ratify (0 while Pending)
+    pub ratify_by: u64,
+}
+
 // ============================================================================
 // Issue #1091: Proposal Lifecycle Hooks for Keeper Network Integration
 // ============================================================================
@@ -2915,6 +3747,8 @@ pub enum HookEventType {
     RecurringDue = 2,
     /// An escrow agreement has reached its release condition.
     EscrowReady = 3,
+    /// A pending or scheduled proposal has timed out and can be expired.
+    ProposalExpirable = 4,
 }
 
 /// Registration record for a keeper-network callback hook.
@@ -2936,3 +3770,345 @@ pub struct HookRegistration {
     /// Set to 0 to disable fee payment.
     pub max_fee: i128,
 }
+
+/// Record kinds whose open entries are tracked in an active-id index.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ActiveSet {
+    Proposal = 1,
+    Recurring = 2,
+    Stream = 3,
+    Subscription = 4,
+    Vesting = 5,
+    Escrow = 6,
+}
+
+/// A unit of maintenance work that any keeper can perform via `execute_due`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeeperWork {
+    /// Execute a scheduled proposal whose execution time has arrived.
+    ScheduledProposal(u64),
+    /// Execute a recurring payment that is due.
+    RecurringPayment(u64),
+    /// Retire an auto-complete stream the vault can no longer cover.
+    StreamAutoComplete(u64),
+    /// Expire a proposal past its approval timeout or execution window.
+    ExpireProposal(u64),
+    /// Refund an expired escrow or auto-resolve a timed-out dispute.
+    EscrowTimeout(u64),
+}
+
+/// Budget from which keepers are paid for work done through `execute_due`.
+///
+/// Each completed item earns `bounty_per_item`, capped by the `max_fee` of the
+/// keeper's hook registration for the matching `HookEventType`. Keepers without
+/// a registration do the work unpaid.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct KeeperBudget {
+    /// Token bounties are paid in, out of the vault balance.
+    pub token: Address,
+    /// Bounty per completed work item before the `max_fee` cap.
+    pub bounty_per_item: i128,
+    /// Amount still available for bounties.
+    pub remaining: i128,
+}
+
+/// Outcome of a single `execute_due` call.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeeperRun {
+    /// Work items completed.
+    pub executed: u32,
+    /// Work items that were due but returned an error.
+    pub failed: u32,
+    /// Total bounty transferred to the keeper.
+    pub bounty_paid: i128,
+}
+
+// ============================================================================
+// Policy Execution Hooks
+// ============================================================================
+
+/// Execution context passed to policy hooks.
+///
+/// Pre-hooks receive it via `pre_execute_policy(ctx) -> HookDecision` and
+/// post-hooks via `post_execute_policy(ctx)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookContext {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub executor: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub memo: Symbol,
+    pub priority: Priority,
+    /// Free-form proposal labels
+    pub tags: Vec<Symbol>,
+    /// Hierarchical tag IDs, including ancestors
+    pub tag_ids: Vec<u64>,
+    /// Approvals gathered so far
+    pub approvals: u32,
+    /// Ledger the proposal was created at
+    pub created_at: u64,
+}
+
+/// Verdict returned by a policy pre-hook.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HookDecision {
+    /// Let execution proceed
+    Allow,
+    /// Block execution with a hook-defined reason code
+    Deny(u32),
+    /// Require this many approvals beyond the proposal's threshold
+    RequireApprovals(u32),
+}
+
+/// A policy hook and the proposals it applies to.
+///
+/// Selectors combine with AND; an empty list or zero bound matches anything.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PolicyHook {
+    /// Contract implementing the hook interface
+    pub contract: Address,
+    /// Runs before the transfer (and may veto it) when true, after it otherwise
+    pub is_pre: bool,
+    /// Only run for these tokens
+    pub tokens: Vec<Address>,
+    /// Only run for proposals tagged with one of these tag IDs (or a descendant)
+    pub tag_ids: Vec<u64>,
+    /// Only run for amounts at or above this value
+    pub min_amount: i128,
+    /// Only run for amounts at or below this value (0 = no upper bound)
+    pub max_amount: i128,
+}
+
+// ============================================================================
+// Recipient Screening
+// ============================================================================
+
+/// External denylist registries consulted before any outgoing payment.
+///
+/// A registry implements `is_denied(address: Address) -> bool`. Answers are
+/// cached for the ledger they were taken in.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScreeningConfig {
+    /// Registry contracts; a denial from any of them blocks the payment
+    pub registries: Vec<Address>,
+    /// Block payments when a registry cannot be queried (otherwise skip it)
+    pub fail_closed: bool,
+}
+
+/// A registry answer cached for a single ledger.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScreeningResult {
+    pub ledger: u32,
+    pub denied: bool,
+}
+
+// ============================================================================
+// Payment Anomaly Detection
+// ============================================================================
+
+/// Executed-amount statistics for a recipient or token.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AmountStats {
+    /// Executed payments recorded
+    pub count: u32,
+    /// Sum of executed amounts
+    pub total: i128,
+    /// Largest executed amount
+    pub max: i128,
+}
+
+impl AmountStats {
+    pub fn mean(&self) -> i128 {
+        if self.count == 0 {
+            0
+        } else {
+            self.total / self.count as i128
+        }
+    }
+
+    pub fn record(&mut self, amount: i128) {
+        self.count = self.count.saturating_add(1);
+        self.total = self.total.saturating_add(amount);
+        self.max = self.max.max(amount);
+    }
+}
+
+/// When a proposal counts as anomalous and what it then requires.
+///
+/// Deviation checks compare the amount with both the recipient's and the
+/// token's history; a multiplier of 0 disables that check.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnomalyPolicy {
+    /// Flag payments to an address this vault has never paid
+    pub flag_new_recipient: bool,
+    /// Flag amounts above this multiple of the historic maximum
+    pub max_multiplier: u32,
+    /// Flag amounts above this multiple of the historic mean
+    pub mean_multiplier: u32,
+    /// Executed payments needed before the deviation checks apply
+    pub min_history: u32,
+    /// Approvals required in addition to the threshold
+    pub extra_approvals: u32,
+    /// Ledgers added to the timelock once approved
+    pub extra_delay_ledgers: u64,
+    /// A veto address must acknowledge the proposal before execution
+    pub require_veto_ack: bool,
+}
+
+/// Why a proposal was flagged as anomalous.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnomalyReason {
+    /// First payment to this recipient
+    NewRecipient,
+    /// Above the multiple of the recipient's largest payment
+    AboveRecipientMax,
+    /// Above the multiple of the recipient's mean payment
+    AboveRecipientMean,
+    /// Above the multiple of the token's largest payment
+    AboveTokenMax,
+    /// Above the multiple of the token's mean payment
+    AboveTokenMean,
+}
+
+/// Anomaly findings recorded for a proposal at creation.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnomalyAssessment {
+    pub reasons: Vec<AnomalyReason>,
+    pub veto_ack_required: bool,
+    /// Veto address that acknowledged the proposal
+    pub acknowledged_by: Option<Address>,
+}
+
+/// Risk summary for a proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProposalRiskReport {
+    pub proposal_id: u64,
+    /// Anomalies found at creation (empty when the payment looked normal)
+    pub reasons: Vec<AnomalyReason>,
+    /// Current history of the recipient
+    pub recipient_stats: AmountStats,
+    /// Current history of the token
+    pub token_stats: AmountStats,
+    /// Extra approvals and timelock from all sources
+    pub safeguards: ProposalSafeguards,
+    pub veto_ack_required: bool,
+    pub acknowledged_by: Option<Address>,
+    /// Impact score computed at creation (zeroed when the proposal was not scored)
+    pub impact: ImpactScore,
+}
+
+// ============================================================================
+// Impact Score Model
+// ============================================================================
+
+/// Complexity points awarded per proposal feature. The complexity component
+/// is their sum, capped at 100.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComplexityPoints {
+    pub per_condition: u32,
+    pub max_condition_points: u32,
+    pub per_dependency: u32,
+    pub max_dependency_points: u32,
+    pub scheduled: u32,
+    pub insurance_or_stake: u32,
+}
+
+impl Default for ComplexityPoints {
+    fn default() -> Self {
+        Self {
+            per_condition: 1,
+            max_condition_points: 20,
+            per_dependency: 10,
+            max_dependency_points: 30,
+            scheduled: 20,
+            insurance_or_stake: 30,
+        }
+    }
+}
+
+/// Requirements for proposals whose total impact score reaches `min_score`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImpactBand {
+    pub min_score: u32,
+    /// Approvals required in addition to the threshold
+    pub extra_approvals: u32,
+    /// Timelock delay multiplier in basis points (10_000 = unchanged)
+    pub timelock_multiplier_bps: u32,
+    /// Minimum insurance as basis points of the amount
+    pub insurance_bps: u32,
+}
+
+/// Admin-governed impact scoring.
+///
+/// Each component scores 0-100 and the total is their weighted sum. When a
+/// model is configured its bands replace the `high_impact_threshold` switch.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImpactScoreModel {
+    /// Component weights in percent; they must sum to 100
+    pub treasury_weight: u32,
+    pub recipient_weight: u32,
+    pub complexity_weight: u32,
+    pub usd_weight: u32,
+    pub recipient_age_weight: u32,
+    pub tag_risk_weight: u32,
+    pub complexity_points: ComplexityPoints,
+    /// USD value (oracle units) at which the USD component reaches 100
+    pub usd_full_scale: i128,
+    /// Ledgers after a recipient's first payment at which its age component reaches 0
+    pub recipient_maturity_ledgers: u64,
+    /// Risk (0-100) per tag ID; a proposal scores its riskiest tag
+    pub tag_risk: Map<u64, u32>,
+    /// Bands in ascending `min_score` order; the highest band reached applies
+    pub bands: Vec<ImpactBand>,
+}
+
+impl ImpactScoreModel {
+    /// The built-in formula: 40% treasury impact, 30% recipient risk and 30%
+    /// complexity, without bands.
+    pub fn legacy(env: &Env) -> Self {
+        Self {
+            treasury_weight: 40,
+            recipient_weight: 30,
+            complexity_weight: 30,
+            usd_weight: 0,
+            recipient_age_weight: 0,
+            tag_risk_weight: 0,
+            complexity_points: ComplexityPoints::default(),
+            usd_full_scale: 0,
+            recipient_maturity_ledgers: 0,
+            tag_risk: Map::new(env),
+            bands: Vec::new(env),
+        }
+    }
+
+    /// The highest band reached by `score`, if any.
+    pub fn band_for(&self, score: u32) -> Option<ImpactBand> {
+        let mut reached = None;
+        for band in self.bands.iter() {
+            if band.min_score > score {
+                break;
+            }
+            reached = Some(band);
+        }
+        reached
+    }
+}